@@ -18,9 +18,21 @@
 //!                                                    (Y / Enter)
 //!                                                        │
 //!                                                        ▼
-//!                                                    Deleting ──(done)──► Done
+//!                                                    Deleting ──(list empty)──► Done
 //! ```
 //!
+//! With `--interactive-sensitive-review`, confirming a deletion that
+//! includes sensitive entries goes through `SensitiveReview` on the way from
+//! `Confirming` to `Deleting`.
+//!
+//! Deleting is an in-place operation: removed directories drop out of the
+//! list and, as long as anything is left, the app returns to `List` so the
+//! user can keep cleaning up. `Done` is reached once the list is empty, or
+//! when the user quits from the list after deleting something. `x` /
+//! `Delete` on the list opens the same confirmation for just the highlighted
+//! entry, and `d` opens `Breakdown`, another overlay on the list that Esc
+//! closes again.
+//!
 //! Each variant corresponds to a different screen rendered by the `ui` module.
 //! The only valid transitions are the ones shown above — there is no way to
 //! go backwards past the `List` screen, for example.
@@ -30,25 +42,59 @@
 //! Scanning and deletion both run on their own threads and communicate back
 //! to the main thread via [`std::sync::mpsc`] channels. The event loop calls
 //! [`App::process_scan_messages`] and [`App::process_delete_messages`] on
-//! every tick to drain whatever messages have arrived since the last frame.
+//! every tick to handle the messages that have arrived since the last frame,
+//! a bounded batch at a time (see [`MESSAGES_PER_TICK`]).
 //! This keeps the UI responsive — it never blocks waiting for a thread.
 
-use std::{path::PathBuf, sync::mpsc::Receiver};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        mpsc::{self, Receiver, TryRecvError},
+    },
+    time::{Duration, Instant},
+};
 
-use ratatui::widgets::ListState;
+use ratatui::{
+    layout::{Position, Rect},
+    widgets::ListState,
+};
 
 use crate::{
-    deleter::{DeleteMsg, start_delete},
-    scanner::{ActiveScan, ScanMsg, start_scan},
+    cli::{Options, SensitivePolicy, now_secs},
+    config,
+    deleter::{
+        CAN_PURGE_TRASH, DeleteMode, DeleteMsg, DeleteOptions, start_delete, start_purge,
+        start_restore,
+    },
+    diskspace::{self, DiskSpace},
+    job, project,
+    scanner::{
+        ActiveScan, ScanMsg, SensitiveReason, location_reason, start_breakdown, start_measure,
+        start_scan,
+    },
+    ui::{
+        Theme, count_noun,
+        list::{CHECKBOX_COLUMNS, COMPACT_CHECKBOX_COLUMNS},
+        truncate_left,
+    },
 };
 
 // ─── Data types ───────────────────────────────────────────────────────────────
 
+/// Environment variable naming the default directory to scan when no path is
+/// given on the command line. Lets users who always clean the same projects
+/// folder set it once and just run `killnode`.
+pub const ROOT_ENV: &str = "KILLNODE_ROOT";
+
 /// A single `node_modules` directory discovered during a scan.
 ///
 /// Every entry in [`App::entries`] corresponds to one directory on disk.
-/// Entries are sorted by size (largest first) once the scan finishes, so the
-/// most impactful candidates appear at the top of the list.
+/// Entries are kept sorted by size (largest first) as the scan reports them,
+/// so the most impactful candidates appear at the top of the list.
 pub struct NodeModuleEntry {
     /// Absolute path to the `node_modules` directory.
     pub path: String,
@@ -57,11 +103,12 @@ pub struct NodeModuleEntry {
     /// Calculated once during scanning via a recursive walk.
     pub size: u64,
 
-    /// Whether this directory lives inside a location that is considered
-    /// unsafe to delete automatically (e.g. `~/.config`, AppData/Roaming).
-    /// Sensitive entries are shown with a warning indicator and cannot be
-    /// selected. See [`crate::scanner::is_sensitive_dir`] for the full rules.
-    pub sensitive: bool,
+    /// Why this directory lives inside a location that is considered unsafe
+    /// to delete automatically (e.g. `~/.config`, AppData/Roaming), or
+    /// `None` if it doesn't. Sensitive entries are shown with a warning
+    /// indicator and start out unselected. See
+    /// [`crate::scanner::sensitive_reason`] for the full rules.
+    pub sensitive: Option<SensitiveReason>,
 
     /// Whether the user has marked this entry for deletion.
     ///
@@ -73,6 +120,267 @@ pub struct NodeModuleEntry {
     /// Unix epoch. Used to display a human-friendly "X days ago" label.
     /// `None` if the filesystem didn't return valid metadata.
     pub last_modified: Option<u64>,
+
+    /// Whether this is a symlink to a directory rather than a real one.
+    /// Deleting it removes only the link, so its size is always 0.
+    pub symlink: bool,
+}
+
+impl NodeModuleEntry {
+    /// Builds an entry from what the scanner reports about a directory.
+    ///
+    /// Safe entries start out selected so the user can just hit Enter to
+    /// delete everything without manually selecting each one; sensitive ones
+    /// start out unselected so they are always opt-in.
+    pub fn new(
+        path: String,
+        size: u64,
+        sensitive: Option<SensitiveReason>,
+        last_modified: Option<u64>,
+        symlink: bool,
+    ) -> Self {
+        Self {
+            selected: sensitive.is_none(),
+            path,
+            size,
+            sensitive,
+            last_modified,
+            symlink,
+        }
+    }
+
+    /// Whether the path is on the user's always-keep list: sensitive for
+    /// [`SensitiveReason::Kept`] (see [`App::toggle_keep`]). Kept entries
+    /// start out unselected and no bulk action picks them up, but they can
+    /// still be selected by hand.
+    pub fn is_kept(&self) -> bool {
+        self.sensitive == Some(SensitiveReason::Kept)
+    }
+
+    /// A cleanup-priority score for [`SortMode::Smart`]: the size weighted
+    /// by how long the directory has gone untouched, so a big, old
+    /// `node_modules` outranks a big one that is still in use.
+    ///
+    /// Computed as bytes × √(age in days). The square root keeps age from
+    /// swamping size — a directory has to be four times as old to count
+    /// double. An entry whose age is unknown counts as brand new, so it is
+    /// never promoted on a guess.
+    pub fn staleness_score(&self, now_secs: u64) -> f64 {
+        let age_secs = self.last_modified.map_or(0, |t| now_secs.saturating_sub(t));
+        self.size as f64 * (age_secs as f64 / 86_400.0).sqrt()
+    }
+}
+
+/// Sorts entries largest-first, so the biggest space-wasters end up at the
+/// top of the list. Shared by the TUI and the headless [`crate::engine`] so
+/// both present results in the same order.
+pub fn sort_entries(entries: &mut [NodeModuleEntry]) {
+    sort_entries_by(entries, SortMode::Size, 0);
+}
+
+/// Sorts entries in the order `mode` asks for. `now_secs` (seconds since
+/// the Unix epoch) is the reference point for ages.
+///
+/// Entries that tie are put in path order, so the result doesn't depend on
+/// the order the scan happened to find them in.
+pub fn sort_entries_by(entries: &mut [NodeModuleEntry], mode: SortMode, now_secs: u64) {
+    entries.sort_by(|a, b| compare_entries(a, b, mode, now_secs));
+}
+
+/// Moves each project's entries next to each other, the projects in the
+/// order their first entry appears and each project's entries in the order
+/// they were in. `projects` maps entry paths to project directories; an
+/// entry missing from it is a project of its own.
+pub fn group_entries(entries: &mut [NodeModuleEntry], projects: &HashMap<String, String>) {
+    let project = |e: &NodeModuleEntry| projects.get(&e.path).unwrap_or(&e.path).clone();
+    let mut rank: HashMap<String, usize> = HashMap::new();
+    for entry in entries.iter() {
+        let next = rank.len();
+        rank.entry(project(entry)).or_insert(next);
+    }
+    entries.sort_by_cached_key(|e| rank[&project(e)]);
+}
+
+/// Inserts `entry` where it belongs in `entries`, which must already be
+/// sorted by `mode` — and, with `projects`, grouped by [`group_entries`].
+/// The result is what sorting and grouping the lot would give, without
+/// re-sorting anything.
+///
+/// Flat, that is just [`sorted_position`]. Grouped, the entry goes into its
+/// project's slot, found the same way within the project; the project then
+/// moves before the first other project whose leading entry sorts after its
+/// own, since a new leading entry can carry it up the list. An entry of a
+/// project not yet in the list starts a project of its own.
+pub fn insert_sorted(
+    entries: &mut Vec<NodeModuleEntry>,
+    entry: NodeModuleEntry,
+    projects: Option<&HashMap<String, String>>,
+    mode: SortMode,
+    now_secs: u64,
+) {
+    let Some(projects) = projects else {
+        let slot = sorted_position(entries, &entry, mode, now_secs);
+        entries.insert(slot, entry);
+        return;
+    };
+    let project = |e: &NodeModuleEntry| projects.get(&e.path).unwrap_or(&e.path).clone();
+
+    // ── Take the project out and place the entry in it ──
+    let own = project(&entry);
+    let mut group = match entries.iter().position(|e| project(e) == own) {
+        Some(start) => {
+            let len = entries[start..]
+                .iter()
+                .take_while(|e| project(e) == own)
+                .count();
+            entries.drain(start..start + len).collect()
+        }
+        None => Vec::new(),
+    };
+    let slot = sorted_position(&group, &entry, mode, now_secs);
+    group.insert(slot, entry);
+
+    // ── Put the project back among the others ──
+    let lead = &group[0];
+    let at = (0..entries.len())
+        .find(|&i| {
+            let starts_project = i == 0 || project(&entries[i - 1]) != project(&entries[i]);
+            starts_project
+                && compare_entries(&entries[i], lead, mode, now_secs) == Ordering::Greater
+        })
+        .unwrap_or(entries.len());
+    entries.splice(at..at, group);
+}
+
+/// Returns where `entry` belongs in `entries`, which must already be sorted
+/// by `mode`, found by binary search. Ties are broken by path as in
+/// [`sort_entries_by`], so inserting entries one at a time as the scan finds
+/// them ends in the same order as sorting them all at once.
+pub fn sorted_position(
+    entries: &[NodeModuleEntry],
+    entry: &NodeModuleEntry,
+    mode: SortMode,
+    now_secs: u64,
+) -> usize {
+    entries.partition_point(|e| compare_entries(e, entry, mode, now_secs) != Ordering::Greater)
+}
+
+/// Orders two entries the way `mode` lists them: [`Ordering::Less`] means
+/// `a` comes first. Entries `mode` can't tell apart go in path order.
+fn compare_entries(
+    a: &NodeModuleEntry,
+    b: &NodeModuleEntry,
+    mode: SortMode,
+    now_secs: u64,
+) -> Ordering {
+    // Known ages before unknown ones, whichever way they are sorted.
+    let by_age = |newest_first: bool| match (a.last_modified, b.last_modified) {
+        (Some(x), Some(y)) if newest_first => y.cmp(&x),
+        (Some(x), Some(y)) => x.cmp(&y),
+        (x, y) => y.is_some().cmp(&x.is_some()),
+    };
+    let by_mode = match mode {
+        SortMode::Size => b.size.cmp(&a.size),
+        SortMode::SizeAscending => a.size.cmp(&b.size),
+        SortMode::Newest => by_age(true),
+        SortMode::Oldest => by_age(false),
+        SortMode::Path => a.path.to_lowercase().cmp(&b.path.to_lowercase()),
+        SortMode::Smart => b
+            .staleness_score(now_secs)
+            .total_cmp(&a.staleness_score(now_secs)),
+    };
+    by_mode.then_with(|| a.path.cmp(&b.path))
+}
+
+/// Aggregate sizes across the listed entries, shown in the list's stats line
+/// (toggled with `i`). See [`App::size_stats`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SizeStats {
+    /// Mean size, rounded down.
+    pub average: u64,
+
+    /// Middle size; the mean of the two middle ones for an even count.
+    pub median: u64,
+
+    /// The biggest single entry.
+    pub largest: u64,
+}
+
+/// How the list is ordered. Cycled with `s` on the list screen, in the
+/// order listed here.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortMode {
+    /// Largest first — the order a scan finishes in.
+    #[default]
+    Size,
+
+    /// Smallest first.
+    SizeAscending,
+
+    /// Most recently modified first. Entries whose age is unknown go last.
+    Newest,
+
+    /// Least recently modified first. Entries whose age is unknown go last.
+    Oldest,
+
+    /// Alphabetically by path, ignoring case.
+    Path,
+
+    /// Best cleanup candidates first: big *and* long untouched. See
+    /// [`NodeModuleEntry::staleness_score`].
+    Smart,
+}
+
+impl SortMode {
+    /// Returns the mode after this one in the `s` cycle.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Size => Self::SizeAscending,
+            Self::SizeAscending => Self::Newest,
+            Self::Newest => Self::Oldest,
+            Self::Oldest => Self::Path,
+            Self::Path => Self::Smart,
+            Self::Smart => Self::Size,
+        }
+    }
+
+    /// A short name for the help bar.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Size => "largest",
+            Self::SizeAscending => "smallest",
+            Self::Newest => "newest",
+            Self::Oldest => "oldest",
+            Self::Path => "path",
+            Self::Smart => "smart",
+        }
+    }
+}
+
+/// One row of the list as drawn: an entry, or — while the list is grouped
+/// by project (see [`App::grouped`]) — a project's header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListRow {
+    /// A project's header, above its entries.
+    Group(ProjectGroup),
+
+    /// An entry, as an index into [`App::entries`].
+    Entry(usize),
+}
+
+/// A project in the grouped list, and the entries listed under it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectGroup {
+    /// The project directory. See [`crate::project`].
+    pub dir: String,
+
+    /// Indices into [`App::entries`] of the project's entries that the
+    /// [`App::list_filter`] lets through — including any hidden because the
+    /// group is collapsed.
+    pub entries: Vec<usize>,
+
+    /// Whether the entries are folded away under the header.
+    pub collapsed: bool,
 }
 
 /// Which screen is currently visible and accepting input.
@@ -96,27 +404,59 @@ pub enum Screen {
     /// to confirm before deletion begins. Pressing Esc returns to the list.
     Confirming,
 
+    /// With `--interactive-sensitive-review`, shown after [`Screen::Confirming`]
+    /// when the deletion includes sensitive entries. Lists each one with why
+    /// it is sensitive (see [`App::sensitive_review`]) and asks again.
+    SensitiveReview,
+
+    /// A few seconds' pause, over the list, before a deletion that includes
+    /// sensitive entries starts — see [`SENSITIVE_COUNTDOWN`]. Esc still
+    /// backs out; when it runs out, [`App::process_countdown`] starts the
+    /// delete.
+    Countdown,
+
+    /// An overlay on top of [`Screen::List`] breaking the highlighted entry
+    /// down into its largest direct children. Opened with `d`; see
+    /// [`App::open_breakdown`].
+    Breakdown,
+
+    /// An overlay on top of [`Screen::List`] with everything known about the
+    /// highlighted entry: full path, exact size, date, and why it is
+    /// sensitive. Opened with `I`, or Enter with nothing selected; see
+    /// [`App::open_detail`].
+    Detail,
+
     /// Deletion is running in the background. Shows a progress bar and the
-    /// path currently being removed. Input is blocked during this phase.
+    /// path currently being removed. q, Esc or Ctrl-C cancels the run: the
+    /// directory in hand is finished, the rest are reported as skipped, and
+    /// the run ends on [`Screen::Done`] (see [`App::cancel_running_delete`]).
+    /// A second Ctrl-C straight after quits without waiting; see
+    /// [`App::press_force_quit`]. Apart from `?` for help, other keys do
+    /// nothing.
     Deleting,
 
     /// Everything is finished (or nothing was found). Shows a summary of
-    /// how much space was freed and any errors that occurred.
+    /// how much space was freed over the whole session and any errors from
+    /// the last deletion run.
     Done,
 }
 
 /// All runtime state for the application.
 ///
-/// A single `App` instance is created in [`crate::run`] and lives for the
+/// A single `App` instance is created in `run` (in `main.rs`) and lives for the
 /// entire duration of the process. The UI reads from it on every frame, and
 /// the event loop writes to it in response to keypresses and thread messages.
 pub struct App {
     /// Which screen is currently being rendered.
     pub screen: Screen,
 
-    /// The root directory the user asked to scan. Taken from the first CLI
-    /// argument, or the current working directory if none was given.
-    pub scan_root: String,
+    /// The root directories the user asked to scan, all into one list. Taken
+    /// from the CLI arguments, or the current working directory if none were
+    /// given; never empty.
+    pub scan_roots: Vec<String>,
+
+    /// The command-line options the app was started with.
+    pub options: Options,
 
     /// All `node_modules` directories found so far, sorted largest-first once
     /// scanning completes. Populated incrementally by [`Self::process_scan_messages`].
@@ -127,17 +467,217 @@ pub struct App {
     /// separately.
     pub list_state: ListState,
 
+    /// How many rows the list had room for when it was last drawn. PageUp
+    /// and PageDown move by this much, so a page is what the user sees. 0
+    /// before the first draw.
+    pub list_page: usize,
+
+    /// Where the list's rows were last drawn, so a mouse click can be
+    /// matched to the row under it. Empty before the first draw.
+    pub list_area: Rect,
+
+    /// The row a range selection starts from, set by the first `v`. The next
+    /// `v` (or Space, or Enter) toggles everything between it and the cursor
+    /// and clears it. Follows its entry when the list is reordered, and is
+    /// cleared when the list shrinks. See [`Self::mark_range`].
+    pub anchor: Option<usize>,
+
+    /// Substring the list is narrowed to, matched case-insensitively
+    /// against paths. Empty means "show all". See [`Self::visible_indices`].
+    pub list_filter: String,
+
+    /// Whether keypresses on [`Screen::List`] are currently being typed into
+    /// [`Self::list_filter`] rather than treated as commands.
+    pub list_filter_editing: bool,
+
+    /// Whether the list shows paths relative to their scan root (see
+    /// [`Self::root_of`]) instead of in full. Toggled with `.` on the list
+    /// screen. Paths that don't live under any root (possible via symlinks)
+    /// are always shown in full.
+    pub relative_paths: bool,
+
+    /// The order the list is shown in. Cycled with `s`.
+    pub sort_mode: SortMode,
+
+    /// Whether ↑ on the first row goes to the last and ↓ on the last to the
+    /// first. On unless `--no-wrap` was given, which makes the cursor stop
+    /// at either end instead.
+    pub wrap_navigation: bool,
+
+    /// Whether the list is grouped by project, each under a header that can
+    /// be collapsed. Toggled with `g`; see [`Self::list_rows`].
+    pub grouped: bool,
+
+    /// Whether the list is drawn densely, for a narrow terminal or a split
+    /// pane: no MODIFIED column, and a one-character checkbox, so the path
+    /// gets the room. Toggled with `C`.
+    pub compact: bool,
+
+    /// The project each entry belongs to, by entry path. Only filled in
+    /// while [`Self::grouped`] is on, since finding a project means looking
+    /// around on disk.
+    pub projects: HashMap<String, String>,
+
+    /// Projects whose entries are folded away under their header.
+    pub collapsed: HashSet<String>,
+
+    /// The project whose header is highlighted. Only counts while no entry
+    /// is, so highlighting an entry in any way moves off the header; see
+    /// [`Self::highlighted_group`].
+    pub group_cursor: Option<String>,
+
+    /// Whether the list shows a line of [`SizeStats`] above the help bar.
+    /// Toggled with `i`.
+    pub show_stats: bool,
+
+    /// Whether the help bar shows how much the entries from the top of the
+    /// list down to the cursor add up to. Toggled with `c`; see
+    /// [`App::cumulative_size`].
+    pub show_cumulative: bool,
+
+    /// Total capacity, in bytes, of the filesystems holding
+    /// [`Self::scan_roots`], each counted once (see [`crate::diskspace`]).
+    /// Looked up once when a scan starts. Used as a sanity check: a selection
+    /// larger than the whole disk means sizes are being double-counted.
+    /// `None` if the OS couldn't report it.
+    pub disk_capacity: Option<u64>,
+
+    /// Space on the filesystems holding [`Self::scan_roots`], as of the
+    /// last look: when the app starts, when a scan starts, and after each
+    /// deletion run. Shown on the [`Screen::Welcome`] and [`Screen::Done`]
+    /// screens. `None` if the OS couldn't report it.
+    pub disk_space: Option<DiskSpace>,
+
+    /// Available space on those filesystems just before the session's first
+    /// deletion run, so the [`Screen::Done`] screen can show how much it
+    /// grew. `None` until something has been deleted.
+    pub disk_free_before: Option<u64>,
+
+    /// The colour palette every screen is drawn with, chosen by `--theme`.
+    pub theme: Theme,
+
     // ── Scanning ──────────────────────────────────────────────────────────────
     /// Handle to the active background scan, if one is in progress.
     /// `None` before the first scan starts or after it finishes.
     pub scan: Option<ActiveScan>,
 
+    /// The [`Self::ticker`] value when the current scan started. See
+    /// [`Self::scan_is_slow`].
+    pub scan_started: u64,
+
+    /// When the latest scan started, by the clock, for
+    /// [`Self::scan_duration`]. `None` before the first.
+    pub scan_started_at: Option<Instant>,
+
+    /// When the latest scan finished. Cleared when a new scan starts, so it
+    /// never pairs with another scan's start.
+    pub scan_finished_at: Option<Instant>,
+
+    /// How many directories the latest finished scan found, counting those
+    /// left out of the list by `--sensitive hide`, `--min-size` and
+    /// `--older-than`. Fixed when the scan ends, so deleting entries
+    /// afterwards doesn't change its rate.
+    pub scan_found: usize,
+
+    /// How many sensitive directories the scan found but left out of
+    /// [`Self::entries`] because of `--sensitive hide`. Shown in the list
+    /// title so hidden entries never go unnoticed.
+    pub hidden_sensitive: usize,
+
+    /// How many directories the scan found but left out of
+    /// [`Self::entries`] for being smaller than `--min-size`. Shown in the
+    /// list title, like [`Self::hidden_sensitive`].
+    pub hidden_small: usize,
+
+    /// How many directories the scan found but left out of
+    /// [`Self::entries`] for being modified more recently than
+    /// `--older-than` (see [`Options::too_recent`]). Shown in the list
+    /// title, like [`Self::hidden_sensitive`].
+    pub hidden_recent: usize,
+
+    /// How many entries have been hidden this session with `e` (see
+    /// [`Self::exclude_parent_of_selected`]). Shown in the list title.
+    pub excluded: usize,
+
+    /// Whether the last scan stopped at `--max-results` before walking the
+    /// whole tree, so the list may not show everything.
+    pub scan_truncated: bool,
+
+    /// Entries whose size is being re-measured with `R`, each paired with the
+    /// channel its background walk will report on. Drained by
+    /// [`Self::process_refresh_messages`].
+    pub refreshing: Vec<(String, Receiver<u64>)>,
+
+    // ── Breakdown ─────────────────────────────────────────────────────────────
+    /// The entry shown on [`Screen::Breakdown`].
+    pub breakdown_path: Option<String>,
+
+    /// Its direct children as `(name, bytes)`, largest first. `None` while
+    /// they are still being measured.
+    pub breakdown: Option<Vec<(String, u64)>>,
+
+    /// Receives the measured children from the background walk started by
+    /// [`Self::open_breakdown`]. Drained by
+    /// [`Self::process_breakdown_messages`].
+    pub breakdown_rx: Option<Receiver<Vec<(String, u64)>>>,
+
+    // ── Opening ───────────────────────────────────────────────────────────────
+    /// Receives whether the file manager [`Self::open_parent`] launched for
+    /// this directory could be started. Drained by
+    /// [`Self::process_open_messages`].
+    pub open_rx: Option<Receiver<(String, Result<(), String>)>>,
+
+    // ── Clipboard ─────────────────────────────────────────────────────────────
+    /// The system clipboard, opened by the first `y` and then held for the
+    /// rest of the session: on X11 and Wayland a copy only lasts as long as
+    /// the program that made it keeps its clipboard open.
+    pub clipboard: Option<arboard::Clipboard>,
+
+    // ── Profiles ──────────────────────────────────────────────────────────────
+    /// Where saved profiles are read from and written to: normally
+    /// [`config::dir`], or `None` if there is nowhere to keep them.
+    pub config_dir: Option<PathBuf>,
+
+    /// The always-keep list, normalized (see [`config::normalize_path`]).
+    /// Read from the config directory when a scan starts and handed to the
+    /// scanner, which reports what is on it as [`SensitiveReason::Kept`].
+    pub keep: Vec<String>,
+
+    /// A short note about the active profile — whether it was applied, how
+    /// many of its saved paths weren't found, or that it was just saved.
+    /// Shown in the list title. `None` when no profile is in use.
+    pub profile_note: Option<String>,
+
     // ── Deletion ──────────────────────────────────────────────────────────────
     /// Receiving end of the channel the background delete thread sends
     /// progress messages on. `None` when no deletion is in progress.
     pub delete_rx: Option<Receiver<DeleteMsg>>,
 
-    /// How many directories have been deleted so far in the current run.
+    /// The delete thread's stop flag, while a deletion is in progress. Set
+    /// by [`Self::cancel_running_delete`].
+    pub delete_stop: Option<Arc<AtomicBool>>,
+
+    /// Path of the single entry queued with `x` / `Delete`, bypassing the
+    /// selection. While set, the confirmation popup and [`Self::begin_delete`]
+    /// act on this one entry only. Cleared when the popup is cancelled or the
+    /// deletion finishes, so it is always `None` on the list screen.
+    pub delete_target: Option<String>,
+
+    /// What has been typed into the confirmation popup so far, when the
+    /// queued set is sensitive and a single Y isn't enough (see
+    /// [`App::needs_typed_confirm`]). Cleared whenever the popup closes.
+    pub confirm_typed: String,
+
+    /// Whether the last word submitted in the confirmation popup was not
+    /// [`CONFIRM_WORD`]. The popup then says so until typing starts again.
+    pub confirm_mismatch: bool,
+
+    /// When [`Screen::Countdown`] runs out. Timed by the clock rather than
+    /// the [`Self::ticker`], which runs faster while keys and mouse events
+    /// are arriving — a countdown mustn't be hurried along by fidgeting.
+    pub countdown_until: Option<Instant>,
+
+    /// How many directories have been processed so far in the current run.
     pub delete_done: usize,
 
     /// Total number of directories queued for deletion in the current run.
@@ -147,15 +687,125 @@ pub struct App {
     /// [`Screen::Deleting`] screen so the user knows what's happening.
     pub delete_current: String,
 
-    /// Total bytes freed across all successfully deleted directories.
+    /// Bytes freed so far in the current run, as of the latest
+    /// [`DeleteMsg::Progress`] or [`DeleteMsg::Finished`]. Shown on the [`Screen::Deleting`] screen;
+    /// the session total in [`Self::delete_freed`] only grows once the run
+    /// is over.
+    pub delete_run_freed: u64,
+
+    /// Whether the delete thread is waiting for another killnode process to
+    /// finish deleting before it starts. See [`DeleteMsg::Waiting`].
+    pub delete_waiting: bool,
+
+    /// Whether the run in progress is a retry of the last run's failures,
+    /// started with `r` on [`Screen::Done`]. A retry always ends back on
+    /// that screen, and adds its skipped paths to the ones already listed
+    /// there rather than replacing them.
+    pub delete_retrying: bool,
+
+    /// When the current run started removing things — after any wait for
+    /// the delete lock, which says nothing about how fast it will go. What
+    /// [`Self::delete_eta`] extrapolates from.
+    pub delete_started_at: Option<Instant>,
+
+    /// Total bytes freed across all successfully deleted directories, summed
+    /// over every deletion run this session.
     pub delete_freed: u64,
 
-    /// Paths that could not be deleted, along with the error message for each.
-    /// Displayed on the [`Screen::Done`] screen if non-empty.
-    pub delete_errors: Vec<String>,
+    /// How many directories have been successfully removed this session.
+    pub delete_removed: usize,
+
+    /// How many of [`Self::delete_removed`] were moved to the trash rather
+    /// than removed for good.
+    pub delete_trashed: usize,
+
+    /// The path that took longest to remove this session, and how long it
+    /// took, from the timings in [`DeleteMsg::Done`]. Failed removals count
+    /// too: a delete that fails after a minute was just as slow. Shown on
+    /// the [`Screen::Done`] screen, where it helps spot a network mount or
+    /// an antivirus scanner slowing everything down.
+    pub delete_slowest: Option<(String, Duration)>,
+
+    /// Paths deliberately left alone in the most recent run because of a
+    /// safety option such as `--exclude-git-repos-with-uncommitted-changes`,
+    /// along with the reason for each. Like failed entries they stay in the
+    /// list, and are listed separately from errors on the [`Screen::Done`]
+    /// screen.
+    pub delete_skipped: Vec<String>,
+
+    /// Paths that could not be deleted in the most recent run, each paired
+    /// with its error message. Those entries stay in the list so they can be
+    /// retried — from the list, or all at once with `r` on the
+    /// [`Screen::Done`] screen, which displays the messages.
+    pub delete_errors: Vec<(String, String)>,
+
+    // ── Trash ─────────────────────────────────────────────────────────────────
+    /// Paths moved to the trash with `--trash` this session and not yet
+    /// emptied from it. While this is non-empty, [`Self::delete_freed`] is
+    /// space that only comes back once the trash is emptied.
+    pub trashed: Vec<String>,
+
+    /// How many of [`Self::delete_freed`]'s bytes belong to [`Self::trashed`],
+    /// so restoring them can take exactly that back off.
+    pub trashed_size: u64,
+
+    /// Receives the result of emptying [`Self::trashed`] from the trash,
+    /// while that is running. See [`Self::empty_trash`].
+    pub purge_rx: Option<Receiver<Result<usize, String>>>,
+
+    /// Why emptying the trash failed last time, if it did.
+    pub purge_error: Option<String>,
+
+    /// Receives the result of putting [`Self::trashed`] back where it was,
+    /// while that is running. See [`Self::restore_trash`].
+    pub restore_rx: Option<Receiver<Result<usize, String>>>,
+
+    /// Why restoring from the trash failed last time, if it did.
+    pub restore_error: Option<String>,
+
+    /// How many directories were put back from the trash with `u` this
+    /// session. They no longer count towards [`Self::delete_removed`].
+    pub delete_restored: usize,
+
+    // ── Done screen ───────────────────────────────────────────────────────────
+    /// Scroll/selection state for the error list on [`Screen::Done`]. Indexes
+    /// into [`Self::visible_errors`], not [`Self::delete_errors`].
+    pub error_list_state: ListState,
+
+    /// Substring the error list is narrowed to. Empty means "show all".
+    pub error_filter: String,
+
+    /// Whether keypresses on [`Screen::Done`] are currently being typed into
+    /// [`Self::error_filter`] rather than treated as commands.
+    pub error_filter_editing: bool,
+
+    // ── Detach ────────────────────────────────────────────────────────────────
+    /// The report file of a deletion handed to a background process with
+    /// `--detach`. Once set, the TUI's work is done and the event loop exits.
+    pub detached: Option<PathBuf>,
+
+    // ── Force quit ────────────────────────────────────────────────────────────
+    /// Set by a first Ctrl-C / Ctrl-Q; a second one in a row quits at once,
+    /// even mid-delete. Any other key clears it. See [`Self::press_force_quit`].
+    pub force_quit_armed: bool,
+
+    // ── Help ──────────────────────────────────────────────────────────────────
+    /// Whether the `?` overlay listing every key for the current screen is
+    /// shown. Drawn over any screen; the next keypress closes it.
+    pub show_help: bool,
+
+    // ── Status line ───────────────────────────────────────────────────────────
+    /// A short, transient message explaining why a keypress did nothing, or
+    /// what it did. Shown in the bottom border of whatever screen is up
+    /// (see [`crate::ui::render_status`]) until [`Self::tick`] clears it.
+    /// Set with [`Self::set_status`].
+    pub status: Option<String>,
+
+    /// The [`Self::ticker`] value at which [`Self::status`] is cleared.
+    pub status_until: u64,
 
     // ── Animation ─────────────────────────────────────────────────────────────
-    /// Incremented by one on every event-loop iteration. Used to drive
+    /// Incremented by one on every event-loop iteration (see [`Self::tick`]). Used to drive
     /// spinner animations: `SPINNER[ticker / 2 % SPINNER.len()]` advances
     /// the frame roughly every 160 ms.
     pub ticker: u64,
@@ -166,38 +816,130 @@ pub struct App {
 impl App {
     /// Creates a fresh `App` ready to show the [`Screen::Welcome`] screen.
     ///
-    /// The scan root is determined once here and never changes:
-    /// - If the user passed a path as the first CLI argument, that is used.
-    /// - Otherwise the current working directory is used.
-    /// - If even `cwd` fails (rare, but possible on some systems), `.` is
-    ///   used as a last resort so the app always starts successfully.
-    pub fn new() -> Self {
-        let scan_root = std::env::args().nth(1).unwrap_or_else(|| {
-            std::env::current_dir()
-                .unwrap_or_else(|_| PathBuf::from("."))
-                .to_string_lossy()
-                .to_string()
-        });
+    /// The scan roots are determined once here, by [`Options::scan_roots`],
+    /// and never change.
+    pub fn new(options: Options) -> Self {
+        let scan_roots = options.scan_roots();
+        let disk_space = diskspace::of_roots(&scan_roots);
+
+        // Without colour, every theme comes out as the mono one.
+        let theme = if options.use_color(true) {
+            Theme::named(options.theme)
+        } else {
+            Theme::MONO
+        };
+
+        let sort_mode = options.sort;
+        let wrap_navigation = !options.no_wrap;
 
         Self {
             screen: Screen::Welcome,
-            scan_root,
+            scan_roots,
+            options,
             entries: Vec::new(),
             list_state: ListState::default(),
+            list_page: 0,
+            list_area: Rect::default(),
+            anchor: None,
+            list_filter: String::new(),
+            list_filter_editing: false,
+            relative_paths: false,
+            sort_mode,
+            wrap_navigation,
+            grouped: false,
+            compact: false,
+            projects: HashMap::new(),
+            collapsed: HashSet::new(),
+            group_cursor: None,
+            show_stats: false,
+            show_cumulative: false,
+            disk_capacity: None,
+            disk_space,
+            disk_free_before: None,
+            theme,
             scan: None,
+            hidden_sensitive: 0,
+            hidden_small: 0,
+            hidden_recent: 0,
+            excluded: 0,
+            scan_truncated: false,
+            refreshing: Vec::new(),
+            breakdown_path: None,
+            breakdown: None,
+            breakdown_rx: None,
+            config_dir: config::dir(),
+            keep: Vec::new(),
+            profile_note: None,
             delete_rx: None,
+            delete_stop: None,
+            delete_target: None,
+            confirm_typed: String::new(),
+            confirm_mismatch: false,
+            countdown_until: None,
             delete_done: 0,
             delete_total: 0,
             delete_current: String::new(),
+            delete_run_freed: 0,
+            delete_waiting: false,
+            delete_retrying: false,
+            delete_started_at: None,
             delete_freed: 0,
+            delete_removed: 0,
+            delete_trashed: 0,
+            delete_slowest: None,
+            delete_skipped: Vec::new(),
             delete_errors: Vec::new(),
+            trashed: Vec::new(),
+            trashed_size: 0,
+            purge_rx: None,
+            purge_error: None,
+            restore_rx: None,
+            restore_error: None,
+            delete_restored: 0,
+            error_list_state: ListState::default(),
+            error_filter: String::new(),
+            error_filter_editing: false,
+            detached: None,
+            force_quit_armed: false,
+            show_help: false,
+            status: None,
+            status_until: 0,
+            open_rx: None,
+            clipboard: None,
             ticker: 0,
+            scan_started: 0,
+            scan_started_at: None,
+            scan_finished_at: None,
+            scan_found: 0,
         }
     }
 }
 
 // ─── Scanning ─────────────────────────────────────────────────────────────────
 
+/// The most messages [`App::process_scan_messages`] and
+/// [`App::process_delete_messages`] handle in one tick. Inserting an entry
+/// in sorted order is cheap, but thousands at once would still stall the
+/// frame they arrive in; spread over a few ticks, nobody notices.
+pub const MESSAGES_PER_TICK: usize = 500;
+
+/// The word to type in the confirmation popup before deleting anything
+/// sensitive. See [`App::needs_typed_confirm`].
+pub const CONFIRM_WORD: &str = "delete";
+
+/// How long [`Screen::Countdown`] waits before deleting anything sensitive.
+/// Long enough to read what is about to happen and think better of it.
+pub const SENSITIVE_COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// How long a deletion runs before [`App::delete_eta`] offers an estimate.
+/// The first few directories say little about the rest: one huge tree, or a
+/// handful of tiny ones, would swing it wildly.
+pub const ETA_SETTLE: Duration = Duration::from_secs(2);
+
+/// How many ticks (~80 ms each) a scan runs before the scanning screen
+/// explains why it might be slow: about 10 seconds.
+pub const SLOW_SCAN_TICKS: u64 = 125;
+
 impl App {
     /// Clears any previous results and kicks off a new background scan.
     ///
@@ -206,19 +948,48 @@ impl App {
     /// Transitions immediately to [`Screen::Scanning`].
     pub fn begin_scan(&mut self) {
         self.entries.clear();
+        self.hidden_sensitive = 0;
+        self.hidden_small = 0;
+        self.hidden_recent = 0;
+        self.excluded = 0;
+        self.scan_truncated = false;
+        self.refreshing.clear();
         self.list_state = ListState::default();
-        self.scan = Some(start_scan(self.scan_root.clone()));
+        self.anchor = None;
+        self.disk_space = diskspace::of_roots(&self.scan_roots);
+        self.disk_capacity = self.disk_space.map(|d| d.total);
+        self.scan_started = self.ticker;
+        self.scan_started_at = Some(Instant::now());
+        self.scan_finished_at = None;
+        // An unreadable list keeps nothing rather than stopping the scan.
+        self.keep = self
+            .config_dir
+            .as_deref()
+            .and_then(|dir| config::load_keep(dir).ok())
+            .unwrap_or_default();
+        let mut scan_options = self.options.scan_options();
+        scan_options.keep = self.keep.clone();
+        self.scan = Some(start_scan(self.scan_roots.clone(), scan_options));
         self.screen = Screen::Scanning;
     }
 
-    /// Drains all messages that have arrived from the background scan thread
-    /// since the last call, without blocking.
+    /// Handles the messages that have arrived from the background scan thread
+    /// since the last call, without blocking — at most [`MESSAGES_PER_TICK`]
+    /// of them, so a burst from a fast scanner can't hold up a frame. Any
+    /// left over are handled on the next tick.
     ///
     /// This is called on every event-loop tick so results stream in as fast
-    /// as the scanner produces them. When a [`ScanMsg::Done`] is received:
+    /// as the scanner produces them. Sensitive entries are dropped or
+    /// pre-selected according to [`Options::sensitive`], and the initial
+    /// selection is narrowed down by [`Options::preselect`] and the
+    /// always-keep list ([`Self::keep`]). When a
+    /// [`ScanMsg::Done`] is received:
     ///
-    /// - Entries are sorted largest-first (so the biggest space-wasters are
-    ///   at the top of the list).
+    /// - Entries are already in order: each one is put straight into its
+    ///   sorted slot as it arrives (see [`Self::place_entry`]), so there is
+    ///   nothing left to sort.
+    /// - With `--profile`, the saved selection replaces the default one (see
+    ///   [`Self::apply_profile`]).
     /// - If at least one entry was found, transitions to [`Screen::List`] with
     ///   the first item selected.
     /// - If nothing was found, transitions straight to [`Screen::Done`] so the
@@ -231,7 +1002,7 @@ impl App {
             return;
         }
 
-        loop {
+        for _ in 0..MESSAGES_PER_TICK {
             let msg = self.scan.as_ref().unwrap().rx.try_recv();
             match msg {
                 Ok(ScanMsg::Found {
@@ -239,22 +1010,47 @@ impl App {
                     size,
                     sensitive,
                     last_modified,
+                    symlink,
                 }) => {
-                    self.entries.push(NodeModuleEntry {
-                        // Pre-select safe entries so the user can just hit Enter
-                        // to delete everything without manually selecting each one.
-                        selected: !sensitive,
-                        path,
-                        size,
-                        sensitive,
-                        last_modified,
-                    });
+                    // `--sensitive` decides whether a sensitive entry is
+                    // listed at all, and whether it starts out selected.
+                    let policy = self.options.sensitive;
+                    if sensitive.is_some() && policy == SensitivePolicy::Hide {
+                        self.hidden_sensitive += 1;
+                        continue;
+                    }
+                    if self.options.min_size.is_some_and(|min| size < min) {
+                        self.hidden_small += 1;
+                        continue;
+                    }
+                    if self.options.too_recent(last_modified, now_secs()) {
+                        self.hidden_recent += 1;
+                        continue;
+                    }
+                    let mut entry =
+                        NodeModuleEntry::new(path, size, sensitive, last_modified, symlink);
+                    entry.selected |= policy == SensitivePolicy::Include;
+                    // `--preselect` only narrows the default selection down.
+                    if let Some(rule) = &self.options.preselect {
+                        entry.selected &= rule.matches(&entry, now_secs());
+                    }
+                    // The always-keep list overrides all of that.
+                    entry.selected &= !entry.is_kept();
+                    self.place_entry(entry);
                 }
-                Ok(ScanMsg::Done) => {
+                Ok(ScanMsg::Done { truncated }) => {
                     if let Some(s) = &mut self.scan {
                         s.done = true;
                     }
-                    self.entries.sort_by(|a, b| b.size.cmp(&a.size));
+                    self.scan_truncated = truncated;
+                    self.scan_finished_at = Some(Instant::now());
+                    self.scan_found = self.entries.len()
+                        + self.hidden_sensitive
+                        + self.hidden_small
+                        + self.hidden_recent;
+                    if self.options.profile.is_some() {
+                        self.apply_profile();
+                    }
                     if self.entries.is_empty() {
                         self.screen = Screen::Done;
                     } else {
@@ -269,6 +1065,17 @@ impl App {
         }
     }
 
+    /// Returns the scan root `path` was found under: the innermost of
+    /// [`Self::scan_roots`] that holds it, in case one root lies inside
+    /// another. `None` for a path under none of them.
+    pub fn root_of(&self, path: &str) -> Option<&str> {
+        self.scan_roots
+            .iter()
+            .filter(|root| Path::new(path).starts_with(root))
+            .max_by_key(|root| Path::new(root).components().count())
+            .map(String::as_str)
+    }
+
     /// Returns the path the scanner is currently visiting, for display on the
     /// [`Screen::Scanning`] screen.
     ///
@@ -282,44 +1089,277 @@ impl App {
             .map(|g| g.clone())
             .unwrap_or_default()
     }
+
+    /// Returns whether the scan has been running for [`SLOW_SCAN_TICKS`] or
+    /// more, long enough that the user may wonder whether it has hung.
+    pub fn scan_is_slow(&self) -> bool {
+        self.ticker.wrapping_sub(self.scan_started) >= SLOW_SCAN_TICKS
+    }
+
+    /// Returns how long the latest scan took, once it has finished. While a
+    /// rescan is running this is `None`, rather than the old scan's time.
+    pub fn scan_duration(&self) -> Option<Duration> {
+        let (started, finished) = (self.scan_started_at?, self.scan_finished_at?);
+        Some(finished.saturating_duration_since(started))
+    }
+
+    /// Returns how many directories per second the latest scan found, or
+    /// `None` if it hasn't finished or took no measurable time.
+    pub fn scan_rate(&self) -> Option<f64> {
+        let secs = self.scan_duration()?.as_secs_f64();
+        (secs > 0.0).then(|| self.scan_found as f64 / secs)
+    }
+
+    /// Re-measures the highlighted entry's size on a background thread.
+    ///
+    /// Useful in a long session when a project's `node_modules` has changed
+    /// since the scan. The list stays interactive while the walk runs; the
+    /// new size is filled in by [`Self::process_refresh_messages`].
+    ///
+    /// Does nothing for symlinks (always 0 bytes), under `--no-size`, or if
+    /// the entry is already being refreshed.
+    pub fn refresh_selected_size(&mut self) {
+        if !self.sizes_known() {
+            return;
+        }
+        let Some(entry) = self.list_state.selected().and_then(|i| self.entries.get(i)) else {
+            return;
+        };
+        if entry.symlink || self.is_refreshing(&entry.path) {
+            return;
+        }
+        let path = entry.path.clone();
+        self.refreshing
+            .push((path.clone(), start_measure(path, self.options.disk_usage)));
+    }
+
+    /// Applies any sizes that background refreshes have finished measuring.
+    ///
+    /// An entry whose size changed is moved to its new sorted slot, and the
+    /// cursor moves with whatever entry it was on, so the list stays in order
+    /// without the highlight landing on a different row. Totals are derived
+    /// from [`Self::entries`], so they pick up the new size on the next
+    /// frame. A refresh whose entry has since been deleted is dropped.
+    pub fn process_refresh_messages(&mut self) {
+        let mut measured = Vec::new();
+        self.refreshing.retain(|(path, rx)| match rx.try_recv() {
+            Ok(size) => {
+                measured.push((path.clone(), size));
+                false
+            }
+            Err(TryRecvError::Empty) => true,
+            Err(TryRecvError::Disconnected) => false,
+        });
+
+        let mode = self.sort_mode;
+        let projects = self.grouped.then(|| std::mem::take(&mut self.projects));
+        for (path, size) in measured {
+            self.keeping_cursor(|entries| {
+                if let Some(i) = entries.iter().position(|e| e.path == path) {
+                    let mut entry = entries.remove(i);
+                    entry.size = size;
+                    insert_sorted(entries, entry, projects.as_ref(), mode, now_secs());
+                }
+            });
+        }
+        if let Some(projects) = projects {
+            self.projects = projects;
+        }
+    }
+
+    /// Inserts `entry` into its sorted slot under the current [`SortMode`],
+    /// using [`insert_sorted`] rather than re-sorting the whole list. While
+    /// the list is grouped, the entry's project is looked up first so the
+    /// slot can be found inside it.
+    fn place_entry(&mut self, entry: NodeModuleEntry) {
+        if self.grouped {
+            self.look_up_project(&entry.path);
+        }
+        let mode = self.sort_mode;
+        let projects = self.grouped.then(|| std::mem::take(&mut self.projects));
+        self.keeping_cursor(|entries| {
+            insert_sorted(entries, entry, projects.as_ref(), mode, now_secs());
+        });
+        if let Some(projects) = projects {
+            self.projects = projects;
+        }
+    }
+
+    /// Runs `reorder` on [`Self::entries`], then puts the cursor and any
+    /// range [`Self::anchor`] back on the entries they were on, wherever
+    /// those ended up. That matters when an entry moves because its size
+    /// changed: the user is probably looking at it.
+    fn keeping_cursor(&mut self, reorder: impl FnOnce(&mut Vec<NodeModuleEntry>)) {
+        let path_at =
+            |i: Option<usize>| i.and_then(|i| self.entries.get(i)).map(|e| e.path.clone());
+        let cursor_path = path_at(self.list_state.selected());
+        let anchor_path = path_at(self.anchor);
+
+        reorder(&mut self.entries);
+
+        let index_of =
+            |path: Option<String>| path.and_then(|p| self.entries.iter().position(|e| e.path == p));
+        if cursor_path.is_some() {
+            self.list_state.select(index_of(cursor_path));
+        }
+        self.anchor = index_of(anchor_path);
+    }
+
+    /// Returns `true` while `path`'s size is being re-measured.
+    pub fn is_refreshing(&self, path: &str) -> bool {
+        self.refreshing.iter().any(|(p, _)| p == path)
+    }
 }
 
 // ─── List navigation & selection ──────────────────────────────────────────────
 
 impl App {
     /// Moves the highlighted row up by one, wrapping around to the bottom of
-    /// the list if the cursor is already at the top.
+    /// the list if the cursor is already at the top — or staying there,
+    /// without [`Self::wrap_navigation`].
     pub fn navigate_up(&mut self) {
-        let len = self.entries.len();
-        if len == 0 {
-            return;
-        }
-        let i = match self.list_state.selected() {
-            Some(i) => (i + len - 1) % len,
-            None => len - 1,
-        };
-        self.list_state.select(Some(i));
+        self.step_visible(false);
     }
 
     /// Moves the highlighted row down by one, wrapping around to the top of
-    /// the list if the cursor is already at the bottom.
+    /// the list if the cursor is already at the bottom — or staying there,
+    /// without [`Self::wrap_navigation`].
     pub fn navigate_down(&mut self) {
-        let len = self.entries.len();
-        if len == 0 {
+        self.step_visible(true);
+    }
+
+    /// Moves the highlighted row up by one page ([`Self::list_page`] rows),
+    /// stopping at the top rather than wrapping.
+    pub fn page_up(&mut self) {
+        let page = self.list_page.max(1);
+        self.jump_visible(|row, _| row.saturating_sub(page));
+    }
+
+    /// Moves the highlighted row down by one page, stopping at the bottom
+    /// rather than wrapping.
+    pub fn page_down(&mut self) {
+        let page = self.list_page.max(1);
+        self.jump_visible(|row, len| (row + page).min(len - 1));
+    }
+
+    /// Moves the highlighted row up by one for the mouse wheel, stopping at
+    /// the top rather than wrapping: a wheel spun too far shouldn't jump to
+    /// the other end.
+    pub fn scroll_up(&mut self) {
+        self.jump_visible(|row, _| row.saturating_sub(1));
+    }
+
+    /// Moves the highlighted row down by one for the mouse wheel, stopping at
+    /// the bottom.
+    pub fn scroll_down(&mut self) {
+        self.jump_visible(|row, len| (row + 1).min(len - 1));
+    }
+
+    /// Handles a left click at `column`, `row` (terminal cells) on the list
+    /// screen. A click on a row highlights it; a click on the row already
+    /// highlighted, or on any row's checkbox, toggles it as Space would —
+    /// finishing a range if one is marked. Clicks outside the rows, or below
+    /// the last one, do nothing.
+    pub fn click_list(&mut self, column: u16, row: u16) {
+        let area = self.list_area;
+        if !area.contains(Position::new(column, row)) {
             return;
         }
-        let i = match self.list_state.selected() {
-            Some(i) => (i + 1) % len,
-            None => 0,
+        let view_row = self.list_state.offset() + (row - area.y) as usize;
+        let rows = self.list_rows();
+        let Some(clicked) = rows.get(view_row) else {
+            return;
         };
-        self.list_state.select(Some(i));
+        let checkbox_columns = if self.compact {
+            COMPACT_CHECKBOX_COLUMNS
+        } else {
+            CHECKBOX_COLUMNS
+        };
+        let on_checkbox = column < area.x + checkbox_columns;
+        let again = self.cursor_row(&rows) == Some(view_row);
+        self.highlight_row(clicked);
+        if on_checkbox || again {
+            self.toggle_selected();
+        }
+    }
+
+    /// Highlights the first row (Home).
+    pub fn jump_to_start(&mut self) {
+        self.jump_visible(|_, _| 0);
+    }
+
+    /// Highlights the last row (End).
+    pub fn jump_to_end(&mut self) {
+        self.jump_visible(|_, len| len - 1);
+    }
+
+    /// Moves the cursor to the row of the current view that `to` picks,
+    /// given the cursor's row and how many rows there are (at least one).
+    /// Does nothing when the view is empty.
+    fn jump_visible(&mut self, to: impl FnOnce(usize, usize) -> usize) {
+        let rows = self.list_rows();
+        if rows.is_empty() {
+            return;
+        }
+        let row = self.cursor_row(&rows).unwrap_or(0);
+        self.highlight_row(&rows[to(row, rows.len())]);
+    }
+
+    /// Moves the cursor one row through the current view, skipping entries
+    /// the [`Self::list_filter`] hides, and wrapping at the ends if
+    /// [`Self::wrap_navigation`] is on.
+    fn step_visible(&mut self, forward: bool) {
+        let rows = self.list_rows();
+        let mut view = ListState::default().with_selected(self.cursor_row(&rows));
+        step(&mut view, rows.len(), forward, self.wrap_navigation);
+        if let Some(row) = view.selected() {
+            self.highlight_row(&rows[row]);
+        }
+    }
+
+    /// Returns which of `rows` (from [`Self::list_rows`]) is highlighted.
+    pub fn cursor_row(&self, rows: &[ListRow]) -> Option<usize> {
+        let group = self.highlighted_group();
+        let selected = self.list_state.selected();
+        rows.iter().position(|row| match row {
+            ListRow::Group(g) => group == Some(g.dir.as_str()),
+            ListRow::Entry(i) => selected == Some(*i),
+        })
+    }
+
+    /// Highlights `row`. A header is highlighted by highlighting no entry,
+    /// which leaves the scroll position alone, unlike
+    /// [`ListState::select`]`(None)`.
+    fn highlight_row(&mut self, row: &ListRow) {
+        match row {
+            ListRow::Group(g) => {
+                self.group_cursor = Some(g.dir.clone());
+                *self.list_state.selected_mut() = None;
+            }
+            ListRow::Entry(i) => {
+                self.group_cursor = None;
+                self.list_state.select(Some(*i));
+            }
+        }
     }
 
     /// Flips the selection state of the currently highlighted entry.
     ///
     /// Sensitive entries can be selected, but they are never pre-selected
     /// automatically — the user must explicitly choose to include them.
+    ///
+    /// While a range is marked (see [`Self::mark_range`]), this finishes the
+    /// range instead. On a project's header, it toggles the whole project
+    /// with [`Self::toggle_group`].
     pub fn toggle_selected(&mut self) {
+        if self.anchor.is_some() {
+            self.mark_range();
+            return;
+        }
+        if let Some(dir) = self.highlighted_group().map(str::to_string) {
+            self.toggle_group(&dir);
+            return;
+        }
         if let Some(i) = self.list_state.selected()
             && let Some(entry) = self.entries.get_mut(i)
         {
@@ -327,6 +1367,55 @@ impl App {
         }
     }
 
+    /// Handles `v`: the first press drops an [`Self::anchor`] on the
+    /// highlighted row, and the second toggles every row from the anchor to
+    /// the cursor via [`Self::toggle_range`] and lifts it again.
+    pub fn mark_range(&mut self) {
+        let Some(current) = self.list_state.selected() else {
+            return;
+        };
+        match self.anchor.take() {
+            Some(anchor) => self.toggle_range(anchor, current),
+            None => self.anchor = Some(current),
+        }
+    }
+
+    /// Returns whether entry `i` lies in the range being marked: between the
+    /// [`Self::anchor`] and the highlighted row, inclusive, so the list can
+    /// show what a second `v` would toggle. With the cursor on a project
+    /// header, only the anchor itself.
+    pub fn in_marked_range(&self, i: usize) -> bool {
+        let Some(anchor) = self.anchor else {
+            return false;
+        };
+        let current = self.list_state.selected().unwrap_or(anchor);
+        (anchor.min(current)..=anchor.max(current)).contains(&i)
+    }
+
+    /// Toggles every entry between rows `anchor` and `current`, inclusive,
+    /// in either order. Rows the [`Self::list_filter`] hides are left alone.
+    ///
+    /// Uses the same smart rule as [`Self::toggle_all`] so the whole block
+    /// ends up in one state: if any entry in it is unselected, all of them
+    /// are selected; otherwise all are deselected. Sensitive entries are
+    /// included, just as toggling them one at a time would — the
+    /// confirmation popup still warns about them. Entries on the always-keep
+    /// list are not: like [`Self::toggle_all_force`], a range never reaches
+    /// them.
+    pub fn toggle_range(&mut self, anchor: usize, current: usize) {
+        // Rows past the end (the list may have shrunk) are simply left out.
+        let (start, end) = (anchor.min(current), anchor.max(current));
+        let range: Vec<usize> = self
+            .visible_indices()
+            .into_iter()
+            .filter(|&i| (start..=end).contains(&i) && !self.entries[i].is_kept())
+            .collect();
+        let any_unselected = range.iter().any(|&i| !self.entries[i].selected);
+        for i in range {
+            self.entries[i].selected = any_unselected;
+        }
+    }
+
     /// Smartly toggles all non-sensitive entries at once.
     ///
     /// The rule is: *if even one safe entry is currently unselected, select
@@ -336,10 +1425,18 @@ impl App {
     ///
     /// Sensitive entries are never touched by this method. Use
     /// [`Self::toggle_all_force`] to include them.
+    ///
+    /// Only entries in the current view (see [`Self::visible_indices`]) are
+    /// considered, so a bulk toggle never reaches rows the user can't see.
     pub fn toggle_all(&mut self) {
-        let any_unselected = self.entries.iter().any(|e| !e.sensitive && !e.selected);
-        for entry in &mut self.entries {
-            if !entry.sensitive {
+        let visible = self.visible_indices();
+        let any_unselected = visible.iter().any(|&i| {
+            let e = &self.entries[i];
+            e.sensitive.is_none() && !e.selected
+        });
+        for i in visible {
+            let entry = &mut self.entries[i];
+            if entry.sensitive.is_none() {
                 entry.selected = any_unselected;
             }
         }
@@ -353,77 +1450,477 @@ impl App {
     ///
     /// Because this selects sensitive directories, the confirmation popup
     /// will show a warning before deletion proceeds.
+    ///
+    /// Entries on the always-keep list are still left alone: pinning one is
+    /// a promise that no bulk action will pick it up. It can be selected
+    /// with Space, one at a time.
+    ///
+    /// Like [`Self::toggle_all`], this is limited to the current view.
     pub fn toggle_all_force(&mut self) {
-        let any_unselected = self.entries.iter().any(|e| !e.selected);
-        for entry in &mut self.entries {
-            entry.selected = any_unselected;
+        let visible: Vec<usize> = self
+            .visible_indices()
+            .into_iter()
+            .filter(|&i| !self.entries[i].is_kept())
+            .collect();
+        let any_unselected = visible.iter().any(|&i| !self.entries[i].selected);
+        for i in visible {
+            self.entries[i].selected = any_unselected;
         }
     }
 
-    /// Returns how many entries are currently selected for deletion.
-    pub fn selected_count(&self) -> usize {
-        self.entries.iter().filter(|e| e.selected).count()
+    /// Returns the indices into [`Self::entries`] of every entry in the
+    /// current view, in display order: those whose path contains
+    /// [`Self::list_filter`], ignoring case, and that aren't folded away in
+    /// a collapsed project.
+    ///
+    /// Navigation, bulk selection and ranges all go through here, so they
+    /// only ever reach rows the user can see. The selected count and size
+    /// still cover every entry, shown or not.
+    pub fn visible_indices(&self) -> Vec<usize> {
+        if !self.grouped {
+            return self.matching_indices();
+        }
+        self.list_rows()
+            .into_iter()
+            .filter_map(|row| match row {
+                ListRow::Entry(i) => Some(i),
+                ListRow::Group(_) => None,
+            })
+            .collect()
     }
 
-    /// Returns the combined size (in bytes) of all selected entries.
-    /// Shown in the help bar and the confirmation popup as the amount of
-    /// space that will be freed.
-    pub fn selected_size(&self) -> u64 {
+    /// Returns the indices into [`Self::entries`] of every entry whose path
+    /// contains [`Self::list_filter`], ignoring case, in display order.
+    fn matching_indices(&self) -> Vec<usize> {
+        let needle = self.list_filter.to_lowercase();
         self.entries
             .iter()
-            .filter(|e| e.selected)
-            .map(|e| e.size)
-            .sum()
+            .enumerate()
+            .filter(|(_, e)| needle.is_empty() || e.path.to_lowercase().contains(&needle))
+            .map(|(i, _)| i)
+            .collect()
     }
 
-    /// Returns the combined size (in bytes) of *all* discovered entries,
-    /// regardless of selection state. Shown in the list screen title.
-    pub fn total_size(&self) -> u64 {
-        self.entries.iter().map(|e| e.size).sum()
+    /// Switches the list between absolute paths and paths relative to the
+    /// scan root.
+    pub fn toggle_relative_paths(&mut self) {
+        self.relative_paths = !self.relative_paths;
+    }
+
+    /// Handles `C`: switches the list between its normal and
+    /// [compact](Self::compact) layout.
+    pub fn toggle_compact(&mut self) {
+        self.compact = !self.compact;
+    }
+
+    /// Handles `s`: switches to the next [`SortMode`] and re-sorts the list.
+    pub fn cycle_sort(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.resort();
+    }
+
+    /// Re-sorts the list under the current [`Self::sort_mode`], keeping the
+    /// cursor (and any range anchor) on the entry it was on. While the list
+    /// is grouped, each project's entries are then gathered together by
+    /// [`group_entries`].
+    pub fn resort(&mut self) {
+        let mode = self.sort_mode;
+        let projects = self.grouped.then(|| std::mem::take(&mut self.projects));
+        self.keeping_cursor(|entries| {
+            sort_entries_by(entries, mode, now_secs());
+            if let Some(projects) = &projects {
+                group_entries(entries, projects);
+            }
+        });
+        if let Some(projects) = projects {
+            self.projects = projects;
+        }
+    }
+
+    /// Returns how many entries are currently selected for deletion.
+    pub fn selected_count(&self) -> usize {
+        self.entries.iter().filter(|e| e.selected).count()
     }
 
-    /// Collects the paths of all selected entries into a `Vec`.
-    /// Used by [`Self::begin_delete`] to hand the list off to the background
-    /// thread — after which the selection state on the entries themselves no
-    /// longer matters.
-    fn selected_paths(&self) -> Vec<String> {
+    /// Returns the combined size (in bytes) of all selected entries.
+    /// Shown in the help bar and the confirmation popup as the amount of
+    /// space that will be freed.
+    pub fn selected_size(&self) -> u64 {
         self.entries
             .iter()
             .filter(|e| e.selected)
-            .map(|e| e.path.clone())
-            .collect()
+            .map(|e| e.size)
+            .sum()
+    }
+
+    /// Returns the entries the next deletion will act on: the single
+    /// [`Self::delete_target`] if one is set, otherwise every selected entry.
+    /// The confirmation popup summarises exactly this set.
+    pub fn queued_entries(&self) -> Vec<&NodeModuleEntry> {
+        match &self.delete_target {
+            Some(target) => self.entries.iter().filter(|e| &e.path == target).collect(),
+            None => self.entries.iter().filter(|e| e.selected).collect(),
+        }
+    }
+
+    /// How many of [`Self::queued_entries`] are sensitive. Alongside
+    /// [`Self::selected_safe_count`], this splits the confirmation popup's
+    /// count when the selection mixes both.
+    pub fn selected_sensitive_count(&self) -> usize {
+        self.queued_entries()
+            .iter()
+            .filter(|e| e.sensitive.is_some())
+            .count()
+    }
+
+    /// How many of [`Self::queued_entries`] are not sensitive.
+    pub fn selected_safe_count(&self) -> usize {
+        self.queued_entries()
+            .iter()
+            .filter(|e| e.sensitive.is_none())
+            .count()
+    }
+
+    /// Returns `true` if the queued total is larger than the disk it lives
+    /// on, which can only happen when the same bytes are counted more than
+    /// once (hardlinks shared between `node_modules`, or symlinks into
+    /// another tree). The confirmation popup warns rather than letting the
+    /// user trust an impossible "freeing ~2 TB" figure.
+    pub fn queued_size_implausible(&self) -> bool {
+        let queued: u64 = self.queued_entries().iter().map(|e| e.size).sum();
+        self.disk_capacity.is_some_and(|capacity| queued > capacity)
+    }
+
+    /// Returns `false` when the app was started with `--no-size`, in which
+    /// case every size is 0 and the UI hides sizes rather than showing zeros.
+    pub fn sizes_known(&self) -> bool {
+        !self.options.no_size
+    }
+
+    /// Returns the combined size (in bytes) of *all* discovered entries,
+    /// regardless of selection state. Shown in the list screen title.
+    pub fn total_size(&self) -> u64 {
+        self.entries.iter().map(|e| e.size).sum()
+    }
+
+    /// Returns average, median and largest size over every listed entry, or
+    /// `None` if the list is empty.
+    ///
+    /// Sizes are sorted afresh rather than read off the list, because the
+    /// list isn't necessarily in size order (see [`SortMode`]).
+    pub fn size_stats(&self) -> Option<SizeStats> {
+        let mut sizes: Vec<u64> = self.entries.iter().map(|e| e.size).collect();
+        if sizes.is_empty() {
+            return None;
+        }
+        sizes.sort_unstable();
+        let n = sizes.len();
+        let median = if n % 2 == 1 {
+            sizes[n / 2]
+        } else {
+            sizes[n / 2 - 1].midpoint(sizes[n / 2])
+        };
+        Some(SizeStats {
+            average: self.total_size() / n as u64,
+            median,
+            largest: sizes[n - 1],
+        })
+    }
+
+    /// Shows or hides the stats line. Does nothing under `--no-size`, where
+    /// there are no sizes to summarise.
+    pub fn toggle_stats(&mut self) {
+        if self.sizes_known() {
+            self.show_stats = !self.show_stats;
+        }
+    }
+
+    /// Returns how many entries there are from the top of the list down to
+    /// and including the cursor, and their combined size — what deleting
+    /// "everything down to here" would free. With the list sorted by size,
+    /// moving the cursor until this reaches a target is the quickest way to
+    /// pick a cutoff. `None` with no cursor.
+    pub fn cumulative_size(&self) -> Option<(usize, u64)> {
+        let current = self
+            .list_state
+            .selected()
+            .filter(|&i| i < self.entries.len())?;
+        let prefix: Vec<usize> = self
+            .visible_indices()
+            .into_iter()
+            .take_while(|&i| i <= current)
+            .collect();
+        Some((
+            prefix.len(),
+            prefix.iter().map(|&i| self.entries[i].size).sum(),
+        ))
+    }
+
+    /// Shows or hides the [`Self::cumulative_size`] preview. Does nothing
+    /// under `--no-size`, like [`Self::toggle_stats`].
+    pub fn toggle_cumulative(&mut self) {
+        if self.sizes_known() {
+            self.show_cumulative = !self.show_cumulative;
+        }
     }
 }
 
 // ─── Deletion ─────────────────────────────────────────────────────────────────
 
 impl App {
-    /// Collects the selected paths and hands them to a background delete
-    /// thread, then transitions to [`Screen::Deleting`].
+    /// Handles Enter on the list: opens the confirmation popup for the
+    /// selection. With nothing selected it opens [`Screen::Detail`] for the
+    /// highlighted entry instead, or — on an empty list — explains how to
+    /// select something in the status line, so the key never seems to do
+    /// nothing.
+    ///
+    /// With `--yes` the popup is skipped and the deletion starts straight
+    /// away — unless a sensitive entry is selected, whose warning is never
+    /// skipped.
+    pub fn request_delete(&mut self) {
+        if self.selected_count() == 0 {
+            self.open_detail();
+            if !matches!(self.screen, Screen::Detail) {
+                self.set_status("Select at least one directory with Space");
+            }
+            return;
+        }
+        // `--yes` never skips a warning the popup would give; kept entries
+        // count as sensitive here too.
+        let sensitive = self.queued_entries().iter().any(|e| e.sensitive.is_some());
+        if self.options.yes && !sensitive {
+            self.begin_delete();
+        } else {
+            self.screen = Screen::Confirming;
+        }
+    }
+
+    /// Queues just the highlighted entry for deletion, leaving the selection
+    /// untouched.
+    ///
+    /// Normally this opens the confirmation popup for that one entry. With
+    /// `skip_confirm` (the shifted key) a non-sensitive entry is deleted
+    /// straight away; a sensitive or always-kept one still goes through the
+    /// popup so its warning can't be skipped by accident.
+    pub fn begin_single_delete(&mut self, skip_confirm: bool) {
+        let Some(entry) = self.list_state.selected().and_then(|i| self.entries.get(i)) else {
+            return;
+        };
+        let sensitive = entry.sensitive.is_some();
+        self.delete_target = Some(entry.path.clone());
+        if skip_confirm && !sensitive {
+            self.begin_delete();
+        } else {
+            self.screen = Screen::Confirming;
+        }
+    }
+
+    /// Handles Y / Enter in the confirmation popup. Starts the deletion, or
+    /// with `--interactive-sensitive-review` opens [`Screen::SensitiveReview`]
+    /// first if anything queued is sensitive.
+    pub fn confirm_delete(&mut self) {
+        if self.options.sensitive_review
+            && self.queued_entries().iter().any(|e| e.sensitive.is_some())
+        {
+            self.screen = Screen::SensitiveReview;
+        } else {
+            self.begin_delete_after_countdown();
+        }
+    }
+
+    /// Starts the deletion — after [`Screen::Countdown`] if anything queued
+    /// is sensitive, or straight away otherwise. Handles Y on
+    /// [`Screen::SensitiveReview`] too.
+    pub fn begin_delete_after_countdown(&mut self) {
+        if self.queued_entries().iter().any(|e| e.sensitive.is_some()) {
+            self.countdown_until = Some(Instant::now() + SENSITIVE_COUNTDOWN);
+            self.screen = Screen::Countdown;
+        } else {
+            self.begin_delete();
+        }
+    }
+
+    /// Returns how long is left on [`Screen::Countdown`], if it is showing.
+    pub fn countdown_remaining(&self) -> Option<Duration> {
+        self.countdown_until
+            .filter(|_| matches!(self.screen, Screen::Countdown))
+            .map(|until| until.saturating_duration_since(Instant::now()))
+    }
+
+    /// Starts the deletion once [`Screen::Countdown`] has run out. Called
+    /// once per event-loop iteration.
+    pub fn process_countdown(&mut self) {
+        if self.countdown_remaining() == Some(Duration::ZERO) {
+            self.countdown_until = None;
+            self.begin_delete();
+        }
+    }
+
+    /// Whether the confirmation popup wants [`CONFIRM_WORD`] typed out
+    /// rather than a single Y: when anything queued is sensitive. A yellow
+    /// warning is easy to press straight past by habit; a word is not.
+    pub fn needs_typed_confirm(&self) -> bool {
+        self.queued_entries().iter().any(|e| e.sensitive.is_some())
+    }
+
+    /// Appends `c` to the word being typed in the confirmation popup.
+    pub fn push_confirm_typed(&mut self, c: char) {
+        self.confirm_mismatch = false;
+        self.confirm_typed.push(c);
+    }
+
+    /// Removes the last character of the word being typed in the
+    /// confirmation popup.
+    pub fn pop_confirm_typed(&mut self) {
+        self.confirm_mismatch = false;
+        self.confirm_typed.pop();
+    }
+
+    /// Handles Enter in the confirmation popup while a word is wanted: goes
+    /// on as [`Self::confirm_delete`] does if it is [`CONFIRM_WORD`], in any
+    /// case and ignoring surrounding spaces. Anything else is cleared and
+    /// flagged in [`Self::confirm_mismatch`].
+    pub fn submit_confirm_typed(&mut self) {
+        if self.confirm_typed.trim().eq_ignore_ascii_case(CONFIRM_WORD) {
+            self.confirm_typed.clear();
+            self.confirm_delete();
+        } else {
+            self.confirm_typed.clear();
+            self.confirm_mismatch = true;
+        }
+    }
+
+    /// Handles T in the confirmation popup: switches between removing
+    /// directories and moving them to the trash. The choice sticks for the
+    /// rest of the session, like `--trash` itself.
+    pub fn toggle_delete_mode(&mut self) {
+        self.options.delete_mode = self.options.delete_mode.toggled();
+    }
+
+    /// Returns each queued sensitive entry's path with why it is sensitive
+    /// (see [`SensitiveReason::description`]), for
+    /// [`Screen::SensitiveReview`].
+    pub fn sensitive_review(&self) -> Vec<(&str, &'static str)> {
+        self.queued_entries()
+            .into_iter()
+            .filter_map(|e| Some((e.path.as_str(), e.sensitive?.description())))
+            .collect()
+    }
+
+    /// Handles `S` on [`Screen::SensitiveReview`]: deselects the sensitive
+    /// entries and deletes only the rest. If that leaves nothing to delete,
+    /// returns to the list and says so instead.
+    pub fn skip_sensitive(&mut self) {
+        let single_sensitive = self.delete_target.as_ref().is_some_and(|t| {
+            self.entries
+                .iter()
+                .any(|e| &e.path == t && e.sensitive.is_some())
+        });
+        if single_sensitive {
+            self.delete_target = None;
+        }
+        for entry in self.entries.iter_mut().filter(|e| e.sensitive.is_some()) {
+            entry.selected = false;
+        }
+        if single_sensitive || self.queued_entries().is_empty() {
+            self.cancel_delete();
+            self.set_status("Nothing left to delete without the sensitive directories");
+            return;
+        }
+        self.begin_delete();
+    }
+
+    /// Closes the confirmation popup without deleting anything and drops any
+    /// single-entry target, returning to [`Screen::List`].
+    pub fn cancel_delete(&mut self) {
+        self.delete_target = None;
+        self.countdown_until = None;
+        self.confirm_typed.clear();
+        self.confirm_mismatch = false;
+        self.screen = Screen::List;
+    }
+
+    /// Collects the queued paths (see [`Self::queued_entries`]) and hands
+    /// them to a background delete thread, then transitions to
+    /// [`Screen::Deleting`].
+    ///
+    /// The per-run counters are reset here so each run's progress starts from
+    /// zero. The session totals ([`Self::delete_freed`] and
+    /// [`Self::delete_removed`]) keep accumulating across runs.
     ///
-    /// All deletion counters are reset here so a fresh run always starts from
-    /// zero, even if the user somehow triggered deletion twice (which the UI
-    /// prevents, but defensive resets are cheap).
+    /// With `--detach` the paths go to a background process instead (see
+    /// [`crate::job`]) and [`Self::detached`] is set so the app can exit. If
+    /// that process can't be started, nothing is deleted and the status line
+    /// says why.
     pub fn begin_delete(&mut self) {
-        let paths = self.selected_paths();
+        // After this point the selection state on the entries no longer
+        // matters — the thread works from its own copy of the paths.
+        //
+        // The sizes the scan measured are what a successful delete frees,
+        // so the thread needn't walk each tree again.
+        let paths: Vec<(String, Option<u64>)> = self
+            .queued_entries()
+            .iter()
+            .map(|e| (e.path.clone(), Some(e.size)))
+            .collect();
+        if self.options.detach {
+            let paths: Vec<String> = paths.into_iter().map(|(p, _)| p).collect();
+            let started = job::write_plan(&job::jobs_dir(), &paths).and_then(|plan| {
+                job::spawn_worker(&plan, &self.options, &self.scan_roots)?;
+                Ok(job::report_path(&plan))
+            });
+            match started {
+                Ok(report) => self.detached = Some(report),
+                Err(e) => {
+                    self.cancel_delete();
+                    self.set_status(format!("Couldn't start background delete: {e}"));
+                }
+            }
+            return;
+        }
+        self.delete_skipped.clear();
+        self.delete_retrying = false;
+        self.start_run(paths);
+    }
+
+    /// Starts the delete thread on `paths` and moves to
+    /// [`Screen::Deleting`], for [`Self::begin_delete`] and
+    /// [`Self::retry_failed`]. The last run's errors and their filter are
+    /// cleared; what happens to its skipped paths is up to the caller.
+    fn start_run(&mut self, paths: Vec<(String, Option<u64>)>) {
         self.delete_total = paths.len();
         self.delete_done = 0;
         self.delete_current = String::new();
-        self.delete_freed = 0;
+        self.delete_run_freed = 0;
+        self.delete_waiting = false;
+        self.delete_started_at = Some(Instant::now());
         self.delete_errors.clear();
-        self.delete_rx = Some(start_delete(paths));
+        self.error_list_state = ListState::default();
+        self.error_filter.clear();
+        self.error_filter_editing = false;
+        if self.disk_free_before.is_none() {
+            self.disk_space = diskspace::of_roots(&self.scan_roots);
+            self.disk_free_before = self.disk_space.map(|d| d.available);
+        }
+        let (rx, stop) = start_delete(paths, self.delete_options());
+        self.delete_rx = Some(rx);
+        self.delete_stop = Some(stop);
         self.screen = Screen::Deleting;
     }
 
-    /// Drains all messages that have arrived from the background delete thread
-    /// since the last call, without blocking.
+    /// Handles the messages that have arrived from the background delete
+    /// thread since the last call, without blocking — at most
+    /// [`MESSAGES_PER_TICK`] of them, like [`Self::process_scan_messages`].
     ///
-    /// Each [`DeleteMsg::Progress`] message advances the progress counter and
-    /// updates the "currently removing" path shown on screen.
+    /// Each [`DeleteMsg::Progress`] message updates the "currently removing"
+    /// path shown on screen, and each [`DeleteMsg::Finished`] advances the
+    /// progress counter. Both carry the bytes freed so far.
     ///
-    /// When [`DeleteMsg::Done`] arrives the final freed-bytes total and any
-    /// error messages are recorded, and the screen transitions to
-    /// [`Screen::Done`].
+    /// When [`DeleteMsg::Done`] arrives the freed bytes are added to the
+    /// session total, any error messages are recorded, and every directory
+    /// that was actually removed is dropped from the list. The user lands
+    /// back on [`Screen::List`] to carry on, or on [`Screen::Done`] if the
+    /// list is now empty. Entries that failed to delete stay in the list.
     ///
     /// Does nothing if no deletion is currently in progress.
     pub fn process_delete_messages(&mut self) {
@@ -431,18 +1928,66 @@ impl App {
             return;
         }
 
-        loop {
+        for _ in 0..MESSAGES_PER_TICK {
             let msg = self.delete_rx.as_ref().unwrap().try_recv();
             match msg {
-                Ok(DeleteMsg::Progress(path)) => {
+                Ok(DeleteMsg::Waiting) => self.delete_waiting = true,
+                Ok(DeleteMsg::Progress { path, freed }) => {
+                    if self.delete_waiting {
+                        self.delete_started_at = Some(Instant::now());
+                    }
+                    self.delete_waiting = false;
                     self.delete_current = path;
+                    self.delete_run_freed = freed;
+                }
+                Ok(DeleteMsg::Finished { freed }) => {
+                    self.delete_run_freed = freed;
                     self.delete_done += 1;
                 }
-                Ok(DeleteMsg::Done { freed, errors }) => {
-                    self.delete_freed = freed;
+                Ok(DeleteMsg::Done {
+                    freed,
+                    deleted,
+                    skipped,
+                    errors,
+                    timings,
+                }) => {
+                    self.delete_freed += freed;
+                    self.disk_space = diskspace::of_roots(&self.scan_roots);
+                    let slowest = timings.into_iter().max_by_key(|(_, took)| *took);
+                    if let Some(slowest) = slowest.filter(|(_, took)| {
+                        self.delete_slowest.as_ref().is_none_or(|(_, s)| took > s)
+                    }) {
+                        self.delete_slowest = Some(slowest);
+                    }
+                    self.delete_removed += deleted.len();
+                    if self.options.delete_mode == DeleteMode::Trash {
+                        self.delete_trashed += deleted.len();
+                        self.trashed.extend(deleted.iter().cloned());
+                        self.trashed_size += freed;
+                    }
+                    if self.delete_retrying {
+                        self.delete_skipped.extend(skipped);
+                    } else {
+                        self.delete_skipped = skipped;
+                    }
                     self.delete_errors = errors;
+                    self.reset_error_cursor();
+                    // A cancelled run always ends on the summary, so what
+                    // was left untouched is listed there. So does a retry,
+                    // which was started from it.
+                    let cancelled = self.delete_cancelled() || self.delete_retrying;
+                    self.delete_retrying = false;
                     self.delete_rx = None;
-                    self.screen = Screen::Done;
+                    self.delete_stop = None;
+                    self.delete_target = None;
+                    for path in &deleted {
+                        self.remove_entry(path);
+                    }
+                    self.screen = if self.entries.is_empty() || cancelled {
+                        Screen::Done
+                    } else {
+                        Screen::List
+                    };
                     break;
                 }
                 // Channel is empty (or disconnected) — nothing more to do this tick.
@@ -450,4 +1995,941 @@ impl App {
             }
         }
     }
+
+    /// Handles a quit keypress on the list screen. Returns `true` if the app
+    /// should exit right away.
+    ///
+    /// If nothing has been deleted (or attempted) this session there is
+    /// nothing to summarise, so the app just exits. Otherwise it moves to
+    /// [`Screen::Done`] so the user sees the session's totals and any errors
+    /// before leaving.
+    pub fn quit_from_list(&mut self) -> bool {
+        if !self.has_delete_history() {
+            return true;
+        }
+        self.screen = Screen::Done;
+        false
+    }
+
+    /// Returns `true` if any deletion has been attempted this session — some
+    /// directory was removed (or trashed and restored again), or the last run
+    /// left skipped or failed paths behind. This is what makes the
+    /// [`Screen::Done`] summary worth showing.
+    pub fn has_delete_history(&self) -> bool {
+        self.delete_removed > 0
+            || self.delete_restored > 0
+            || !self.delete_skipped.is_empty()
+            || !self.delete_errors.is_empty()
+    }
+
+    /// Handles q / Esc / Ctrl-C on [`Screen::Deleting`]: asks the delete
+    /// thread to stop once it has finished the directory in hand. The rest
+    /// are reported as skipped, and the run ends on [`Screen::Done`].
+    pub fn cancel_running_delete(&mut self) {
+        if let Some(stop) = &self.delete_stop {
+            stop.store(true, AtomicOrdering::Relaxed);
+        }
+    }
+
+    /// Roughly how long the running deletion has left, assuming the
+    /// directories still to go take as long on average as the ones done:
+    /// `elapsed / done * (total - done)`.
+    ///
+    /// `None` until there is something to go on — at least one directory
+    /// finished and [`ETA_SETTLE`] passed — and once nothing is left, or the
+    /// run is waiting for the lock or has been cancelled.
+    pub fn delete_eta(&self) -> Option<Duration> {
+        let elapsed = self.delete_started_at?.elapsed();
+        let (done, total) = (self.delete_done, self.delete_total);
+        if done == 0
+            || done >= total
+            || elapsed < ETA_SETTLE
+            || self.delete_waiting
+            || self.delete_cancelled()
+        {
+            return None;
+        }
+        Some(elapsed.div_f64(done as f64).mul_f64((total - done) as f64))
+    }
+
+    /// Whether the running deletion has been asked to stop.
+    pub fn delete_cancelled(&self) -> bool {
+        self.delete_stop
+            .as_ref()
+            .is_some_and(|stop| stop.load(AtomicOrdering::Relaxed))
+    }
+
+    /// Builds the [`DeleteOptions`] for a deletion run from the command-line
+    /// options.
+    fn delete_options(&self) -> DeleteOptions {
+        self.options.delete_options(&self.scan_roots)
+    }
+
+    /// Drops the entry at `path` from the list and keeps the cursor on the
+    /// same row it was on. If the highlighted entry itself is removed, the
+    /// cursor lands on the one that slid into its place (or the new last row
+    /// if it was at the bottom).
+    fn remove_entry(&mut self, path: &str) {
+        let Some(i) = self.entries.iter().position(|e| e.path == path) else {
+            return;
+        };
+        self.entries.remove(i);
+        self.anchor = None;
+        let selected = self
+            .list_state
+            .selected()
+            .filter(|_| !self.entries.is_empty())
+            .map(|sel| if i < sel { sel - 1 } else { sel })
+            .map(|sel| sel.min(self.entries.len() - 1));
+        self.list_state.select(selected);
+    }
+}
+
+// ─── List filter ──────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `/` on the list: starts typing into [`Self::list_filter`].
+    pub fn begin_list_filter(&mut self) {
+        self.list_filter_editing = true;
+    }
+
+    /// Handles Enter while typing: keeps the filter and goes back to
+    /// treating keys as commands.
+    pub fn end_list_filter(&mut self) {
+        self.list_filter_editing = false;
+    }
+
+    /// Empties the filter and stops typing into it, so every entry shows
+    /// again.
+    pub fn clear_list_filter(&mut self) {
+        self.list_filter.clear();
+        self.list_filter_editing = false;
+        self.refilter_cursor();
+    }
+
+    /// Appends `c` to the filter.
+    pub fn push_list_filter(&mut self, c: char) {
+        self.list_filter.push(c);
+        self.refilter_cursor();
+    }
+
+    /// Removes the last character of the filter.
+    pub fn pop_list_filter(&mut self) {
+        self.list_filter.pop();
+        self.refilter_cursor();
+    }
+
+    /// Keeps the cursor on its row if that is still shown, and otherwise
+    /// moves it to the first row that is.
+    fn refilter_cursor(&mut self) {
+        let rows = self.list_rows();
+        if self.cursor_row(&rows).is_none() {
+            match rows.first() {
+                Some(first) => self.highlight_row(first),
+                None => self.list_state.select(None),
+            }
+        }
+    }
+}
+
+// ─── Grouping ─────────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `g`: switches between the flat list and one grouped by
+    /// project. Turning grouping on looks up every entry's project; turning
+    /// it off moves the cursor from a header to the project's first entry.
+    pub fn toggle_grouping(&mut self) {
+        self.grouped = !self.grouped;
+        if self.grouped {
+            let paths: Vec<String> = self.entries.iter().map(|e| e.path.clone()).collect();
+            for path in paths {
+                self.look_up_project(&path);
+            }
+        } else if let Some(dir) = self.group_cursor.take()
+            && self.list_state.selected().is_none()
+        {
+            let first = self
+                .entries
+                .iter()
+                .position(|e| self.project_of(&e.path) == dir);
+            self.list_state.select(first);
+        }
+        self.resort();
+        self.refilter_cursor();
+    }
+
+    /// Returns the project `path` was grouped under: itself, if its project
+    /// hasn't been looked up.
+    pub fn project_of<'a>(&'a self, path: &'a str) -> &'a str {
+        self.projects.get(path).map_or(path, String::as_str)
+    }
+
+    /// Finds and remembers the project `path` belongs to, unless that is
+    /// already known.
+    fn look_up_project(&mut self, path: &str) {
+        if self.projects.contains_key(path) {
+            return;
+        }
+        let dir = project::project_dir(path, self.root_of(path));
+        self.projects.insert(path.to_string(), dir);
+    }
+
+    /// Returns the rows the list draws, top to bottom. Flat, that is just
+    /// the [`Self::visible_indices`]; grouped, each project's header comes
+    /// before its entries, which are left out while it is collapsed.
+    pub fn list_rows(&self) -> Vec<ListRow> {
+        let matching = self.matching_indices();
+        if !self.grouped {
+            return matching.into_iter().map(ListRow::Entry).collect();
+        }
+        // `resort` keeps each project's entries together, so a project ends
+        // where the next one starts.
+        let mut groups: Vec<ProjectGroup> = Vec::new();
+        for i in matching {
+            let dir = self.project_of(&self.entries[i].path);
+            match groups.last_mut() {
+                Some(group) if group.dir == dir => group.entries.push(i),
+                _ => groups.push(ProjectGroup {
+                    dir: dir.to_string(),
+                    entries: vec![i],
+                    collapsed: self.collapsed.contains(dir),
+                }),
+            }
+        }
+        let mut rows = Vec::new();
+        for group in groups {
+            let shown = if group.collapsed {
+                Vec::new()
+            } else {
+                group.entries.clone()
+            };
+            rows.push(ListRow::Group(group));
+            rows.extend(shown.into_iter().map(ListRow::Entry));
+        }
+        rows
+    }
+
+    /// Returns the project whose header is highlighted, if one is.
+    pub fn highlighted_group(&self) -> Option<&str> {
+        self.group_cursor
+            .as_deref()
+            .filter(|_| self.grouped && self.list_state.selected().is_none())
+    }
+
+    /// Toggles every entry of project `dir` the filter lets through, folded
+    /// away or not, by the same rule as [`Self::toggle_range`]: if any is
+    /// unselected, all are selected, and otherwise all are deselected.
+    /// Entries on the always-keep list are left alone.
+    pub fn toggle_group(&mut self, dir: &str) {
+        let members: Vec<usize> = self
+            .list_rows()
+            .into_iter()
+            .find_map(|row| match row {
+                ListRow::Group(g) if g.dir == dir => Some(g.entries),
+                _ => None,
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&i| !self.entries[i].is_kept())
+            .collect();
+        let any_unselected = members.iter().any(|&i| !self.entries[i].selected);
+        for i in members {
+            self.entries[i].selected = any_unselected;
+        }
+    }
+
+    /// Handles ← / `h` while grouped: folds the highlighted project — or the
+    /// one the highlighted entry is in — away under its header, and
+    /// highlights the header.
+    pub fn collapse_group(&mut self) {
+        if !self.grouped {
+            return;
+        }
+        let dir = match self.highlighted_group() {
+            Some(dir) => dir.to_string(),
+            None => match self.list_state.selected().and_then(|i| self.entries.get(i)) {
+                Some(entry) => self.project_of(&entry.path).to_string(),
+                None => return,
+            },
+        };
+        self.collapsed.insert(dir.clone());
+        self.group_cursor = Some(dir);
+        *self.list_state.selected_mut() = None;
+    }
+
+    /// Handles → / `l` on a collapsed project's header: shows its entries
+    /// again.
+    pub fn expand_group(&mut self) {
+        if let Some(dir) = self.highlighted_group().map(str::to_string) {
+            self.collapsed.remove(&dir);
+        }
+    }
+}
+
+// ─── Exclusion ────────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `e`: hides the highlighted entry and every other entry under
+    /// the same folder from the list for the rest of the session. Nothing
+    /// is deleted, and a new scan brings them back.
+    ///
+    /// The folder is the one that holds the entry's project — for
+    /// `/work/legacy/app/node_modules`, that's `/work/legacy` — so every
+    /// project next to it goes too. When that folder isn't inside the entry's
+    /// scan root, only the project itself is hidden, so one keypress can never
+    /// empty the whole list. Neither can it if every entry is under the
+    /// folder anyway: the status line says so and nothing changes.
+    pub fn exclude_parent_of_selected(&mut self) {
+        let Some(current) = self.list_state.selected() else {
+            return;
+        };
+        let Some(entry) = self.entries.get(current) else {
+            return;
+        };
+        let project = Path::new(&entry.path).parent().unwrap_or(Path::new("/"));
+        let root = self.root_of(&entry.path).map(Path::new);
+        let folder = match (project.parent(), root) {
+            (Some(p), Some(root)) if p.starts_with(root) && p != root => p,
+            _ => project,
+        }
+        .to_path_buf();
+
+        let under = |e: &NodeModuleEntry| Path::new(&e.path).starts_with(&folder);
+        let count = self.entries.iter().filter(|e| under(e)).count();
+        if count == self.entries.len() {
+            self.set_status("Every entry is under that folder; nothing was hidden");
+            return;
+        }
+
+        // The cursor goes to the first row below it that is still shown —
+        // whatever came after the hidden entries — or, with none left below,
+        // to the last row. Rows the filter or a folded project leaves out
+        // don't count, as in the navigation helpers.
+        let rows = self.list_rows();
+        let next = self.cursor_row(&rows).and_then(|row| {
+            rows[row..].iter().find_map(|row| match row {
+                ListRow::Entry(i) if !under(&self.entries[*i]) => {
+                    Some(self.entries[*i].path.clone())
+                }
+                _ => None,
+            })
+        });
+
+        self.entries.retain(|e| !under(e));
+        self.excluded += count;
+        self.anchor = None;
+        let rows = self.list_rows();
+        let landing = match next {
+            Some(path) => self
+                .entries
+                .iter()
+                .position(|e| e.path == path)
+                .map(ListRow::Entry),
+            None => rows.last().cloned(),
+        };
+        match landing {
+            Some(row) => self.highlight_row(&row),
+            None => self.list_state.select(None),
+        }
+        self.set_status(format!(
+            "Hid {} under {}",
+            count_noun(count, "entry", "entries"),
+            folder.display()
+        ));
+    }
+}
+
+// ─── Breakdown ────────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `d`: opens [`Screen::Breakdown`] for the highlighted entry and
+    /// starts measuring its direct children in the background.
+    ///
+    /// The measurement runs only now, on demand, so it never slows the scan.
+    /// Does nothing for a symlink, which has no contents of its own.
+    pub fn open_breakdown(&mut self) {
+        let Some(entry) = self.list_state.selected().and_then(|i| self.entries.get(i)) else {
+            return;
+        };
+        if entry.symlink {
+            return;
+        }
+        self.breakdown_path = Some(entry.path.clone());
+        self.breakdown = None;
+        self.breakdown_rx = Some(start_breakdown(entry.path.clone(), self.options.disk_usage));
+        self.screen = Screen::Breakdown;
+    }
+
+    /// Picks up the breakdown once its background walk has finished.
+    pub fn process_breakdown_messages(&mut self) {
+        if let Some(rx) = &self.breakdown_rx {
+            match rx.try_recv() {
+                Ok(children) => {
+                    self.breakdown = Some(children);
+                    self.breakdown_rx = None;
+                }
+                Err(TryRecvError::Empty) => {}
+                Err(TryRecvError::Disconnected) => {
+                    self.breakdown = Some(Vec::new());
+                    self.breakdown_rx = None;
+                }
+            }
+        }
+    }
+
+    /// Closes [`Screen::Breakdown`] and returns to the list. A walk still
+    /// running is abandoned.
+    pub fn close_breakdown(&mut self) {
+        self.breakdown_path = None;
+        self.breakdown = None;
+        self.breakdown_rx = None;
+        self.screen = Screen::List;
+    }
+}
+
+// ─── Detail ───────────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `I`: opens [`Screen::Detail`] for the highlighted entry. Does
+    /// nothing if no entry is highlighted.
+    pub fn open_detail(&mut self) {
+        if self.detail_entry().is_some() {
+            self.screen = Screen::Detail;
+        }
+    }
+
+    /// The entry [`Screen::Detail`] describes: the highlighted one.
+    pub fn detail_entry(&self) -> Option<&NodeModuleEntry> {
+        self.list_state.selected().and_then(|i| self.entries.get(i))
+    }
+
+    /// Closes [`Screen::Detail`] and returns to the list.
+    pub fn close_detail(&mut self) {
+        self.screen = Screen::List;
+    }
+}
+
+// ─── Opening ──────────────────────────────────────────────────────────────────
+
+impl App {
+    /// Returns the directory holding the highlighted entry — its project,
+    /// for a `node_modules` — which `o` opens. `None` with a project header
+    /// or nothing highlighted.
+    pub fn highlighted_parent(&self) -> Option<String> {
+        let entry = self.entries.get(self.list_state.selected()?)?;
+        let parent = Path::new(&entry.path).parent()?;
+        Some(parent.to_string_lossy().to_string())
+    }
+
+    /// Handles `o`: opens [`Self::highlighted_parent`] in the system's file
+    /// manager, to look the project over before deleting its modules.
+    ///
+    /// The launch happens on a background thread, since on some systems it
+    /// waits for the launcher to hand over. Its outcome arrives through
+    /// [`Self::open_rx`] and is shown in the status line.
+    pub fn open_parent(&mut self) {
+        let Some(dir) = self.highlighted_parent() else {
+            return;
+        };
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let result = opener::open(&dir).map_err(|e| e.to_string());
+            let _ = tx.send((dir, result));
+        });
+        self.open_rx = Some(rx);
+    }
+
+    /// Shows how the launch [`Self::open_parent`] started went, once it is
+    /// known. Called once per event-loop iteration.
+    pub fn process_open_messages(&mut self) {
+        let Some(rx) = &self.open_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok((dir, Ok(()))) => self.set_status(format!("Opened {dir}")),
+            Ok((dir, Err(e))) => self.set_status(format!("Couldn't open {dir}: {e}")),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.set_status("Couldn't open the file manager");
+            }
+        }
+        self.open_rx = None;
+    }
+}
+
+// ─── Clipboard ────────────────────────────────────────────────────────────────
+
+impl App {
+    /// What `y` would copy: the highlighted entry's full path on the list,
+    /// or the highlighted error on the done screen.
+    pub fn copy_target(&self) -> Option<String> {
+        match self.screen {
+            Screen::List => {
+                let entry = self.entries.get(self.list_state.selected()?)?;
+                Some(entry.path.clone())
+            }
+            Screen::Done => {
+                let errors = self.visible_errors();
+                let i = self.error_list_state.selected().unwrap_or(0);
+                errors.get(i).map(|e| e.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Handles `y`: copies [`Self::copy_target`] to the system clipboard and
+    /// says so in the status line.
+    ///
+    /// Without a clipboard to talk to (over SSH, or with no display server)
+    /// the status line says why instead.
+    pub fn copy_highlighted(&mut self) {
+        let Some(text) = self.copy_target() else {
+            return;
+        };
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    self.set_status(format!("Couldn't reach the clipboard: {e}"));
+                    return;
+                }
+            }
+        }
+        let Some(clipboard) = self.clipboard.as_mut() else {
+            return;
+        };
+        match clipboard.set_text(text) {
+            Ok(()) => self.set_status("Copied to clipboard"),
+            Err(e) => self.set_status(format!("Couldn't copy to the clipboard: {e}")),
+        }
+    }
+}
+
+// ─── Profiles ─────────────────────────────────────────────────────────────────
+
+impl App {
+    /// The profile `P` saves to and a scan restores from: the one named by
+    /// `--profile`, or `default`.
+    pub fn profile_name(&self) -> &str {
+        self.options.profile.as_deref().unwrap_or("default")
+    }
+
+    /// Replaces the current selection with the saved profile: exactly the
+    /// entries that match one of its lines are selected, sensitive or not —
+    /// they were saved on purpose. Lines that match nothing are counted in
+    /// [`Self::profile_note`] and otherwise ignored.
+    ///
+    /// A profile that hasn't been saved yet leaves the selection alone.
+    pub fn apply_profile(&mut self) {
+        let name = self.profile_name().to_string();
+        let Some(dir) = &self.config_dir else {
+            self.profile_note = Some(format!("profile {name}: no config directory"));
+            return;
+        };
+        let patterns = match config::load_profile(dir, &name) {
+            Ok(patterns) => patterns,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.profile_note = Some(format!("profile {name}: new, P saves it"));
+                return;
+            }
+            Err(e) => {
+                self.profile_note = Some(format!("profile {name}: unreadable ({e})"));
+                return;
+            }
+        };
+
+        for entry in &mut self.entries {
+            entry.selected = patterns
+                .iter()
+                .any(|p| config::pattern_matches(p, &entry.path));
+        }
+        let missing = patterns
+            .iter()
+            .filter(|p| {
+                !self
+                    .entries
+                    .iter()
+                    .any(|e| config::pattern_matches(p, &e.path))
+            })
+            .count();
+        self.profile_note = Some(if missing == 0 {
+            format!("profile {name}")
+        } else {
+            format!("profile {name}: {missing} saved not found")
+        });
+    }
+
+    /// Handles `P`: saves the paths of every selected entry as the current
+    /// profile, replacing what it held. The outcome is shown in
+    /// [`Self::profile_note`].
+    pub fn save_profile(&mut self) {
+        let name = self.profile_name().to_string();
+        let paths: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|e| e.selected)
+            .map(|e| e.path.clone())
+            .collect();
+        let result = match &self.config_dir {
+            Some(dir) => config::save_profile(dir, &name, &paths).map_err(|e| e.to_string()),
+            None => Err("no config directory".to_string()),
+        };
+        self.profile_note = Some(match result {
+            Ok(()) => format!("profile {name}: saved {}", paths.len()),
+            Err(e) => format!("profile {name}: not saved ({e})"),
+        });
+    }
+}
+
+// ─── Always keep ──────────────────────────────────────────────────────────────
+
+impl App {
+    /// Handles `p` (or `K`): adds the highlighted entry to the always-keep
+    /// list, or takes it off again, and saves the list straight away.
+    ///
+    /// A newly kept entry becomes sensitive for [`SensitiveReason::Kept`]
+    /// and is deselected; one taken off the list goes back to whatever its
+    /// location makes it (see [`location_reason`]). The outcome is shown in
+    /// the status line.
+    pub fn toggle_keep(&mut self) {
+        let Some(i) = self
+            .list_state
+            .selected()
+            .filter(|&i| i < self.entries.len())
+        else {
+            return;
+        };
+        let Some(dir) = self.config_dir.clone() else {
+            self.set_status("Nowhere to save the keep list: no config directory");
+            return;
+        };
+        let path = config::normalize_path(&self.entries[i].path);
+        let kept = !self.keep.contains(&path);
+        let mut keep = self.keep.clone();
+        if kept {
+            keep.push(path);
+        } else {
+            keep.retain(|p| *p != path);
+        }
+        if let Err(e) = config::save_keep(&dir, &keep) {
+            self.set_status(format!("Keep list not saved: {e}"));
+            return;
+        }
+        self.keep = keep;
+
+        let entry = &mut self.entries[i];
+        if kept {
+            entry.sensitive = Some(SensitiveReason::Kept);
+            entry.selected = false;
+        } else {
+            entry.sensitive = location_reason(Path::new(&entry.path));
+        }
+        let shown = truncate_left(&entry.path, 50);
+        self.set_status(if kept {
+            format!("Always keeping {shown}")
+        } else {
+            format!("No longer always keeping {shown}")
+        });
+    }
+}
+
+// ─── Status line ──────────────────────────────────────────────────────────────
+
+/// How many ticks (~80 ms each) a status message stays up: about 3 seconds.
+pub const STATUS_TICKS: u64 = 40;
+
+impl App {
+    /// Shows `message` in the status line for [`STATUS_TICKS`], replacing
+    /// any message already there.
+    pub fn set_status(&mut self, message: impl Into<String>) {
+        self.status = Some(message.into());
+        self.status_until = self.ticker.wrapping_add(STATUS_TICKS);
+    }
+
+    /// Advances the animation [`Self::ticker`] by one and clears a status
+    /// message whose time is up. Called once per event-loop iteration.
+    pub fn tick(&mut self) {
+        self.ticker = self.ticker.wrapping_add(1);
+        if self.status.is_some() && self.ticker == self.status_until {
+            self.status = None;
+        }
+    }
+}
+
+// ─── Done screen ──────────────────────────────────────────────────────────────
+
+impl App {
+    /// Returns the errors from the last run that match [`Self::error_filter`]
+    /// (case-insensitively), in their original order.
+    pub fn visible_errors(&self) -> Vec<&str> {
+        let needle = self.error_filter.to_lowercase();
+        self.delete_errors
+            .iter()
+            .map(|(_, message)| message.as_str())
+            .filter(|e| e.to_lowercase().contains(&needle))
+            .collect()
+    }
+
+    /// Handles `r` on the done screen: deletes the paths that failed in the
+    /// last run again, with nothing else queued — permission errors are
+    /// often only a file that was briefly locked.
+    ///
+    /// The run goes through [`Screen::Deleting`] as usual and comes back
+    /// here, its results merged into the session's: what it frees and
+    /// removes is added to the totals, its skipped paths join the ones
+    /// already listed, and only the paths that failed again stay as errors.
+    /// Sizes come from the list, where failed entries stay.
+    pub fn retry_failed(&mut self) {
+        if self.delete_errors.is_empty() || self.delete_rx.is_some() {
+            return;
+        }
+        let paths: Vec<(String, Option<u64>)> = self
+            .delete_errors
+            .iter()
+            .map(|(path, _)| {
+                let size = self
+                    .entries
+                    .iter()
+                    .find(|e| &e.path == path)
+                    .map(|e| e.size);
+                (path.clone(), size)
+            })
+            .collect();
+        self.delete_retrying = true;
+        self.start_run(paths);
+    }
+
+    /// Whether `e` on the done screen would empty [`Self::trashed`] from the
+    /// trash: something is there, nothing is emptying it already, and the
+    /// system supports it.
+    pub fn can_empty_trash(&self) -> bool {
+        CAN_PURGE_TRASH
+            && !self.trashed.is_empty()
+            && self.purge_rx.is_none()
+            && self.restore_rx.is_none()
+    }
+
+    /// Whether `u` on the done screen would put [`Self::trashed`] back where
+    /// it was. The same conditions as [`Self::can_empty_trash`]: the two
+    /// can't both run at once, and need the same support from the system.
+    pub fn can_restore_trash(&self) -> bool {
+        self.can_empty_trash()
+    }
+
+    /// Starts permanently removing everything this session moved to the
+    /// trash — and nothing else in it — on a background thread. Picked up
+    /// by [`Self::process_purge_messages`].
+    pub fn empty_trash(&mut self) {
+        if !self.can_empty_trash() {
+            return;
+        }
+        self.purge_error = None;
+        self.purge_rx = Some(start_purge(self.trashed.clone()));
+    }
+
+    /// Picks up the result of [`Self::empty_trash`] once it has finished.
+    /// On success the trashed paths are forgotten, so the done screen
+    /// reports their space as freed.
+    pub fn process_purge_messages(&mut self) {
+        let Some(rx) = &self.purge_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(Ok(_)) => {
+                self.trashed.clear();
+                self.trashed_size = 0;
+            }
+            Ok(Err(e)) => self.purge_error = Some(e),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.purge_error = Some("the purge stopped unexpectedly".to_string());
+            }
+        }
+        self.purge_rx = None;
+    }
+
+    /// Starts moving everything this session moved to the trash back where
+    /// it was, on a background thread. Picked up by
+    /// [`Self::process_restore_messages`].
+    pub fn restore_trash(&mut self) {
+        if !self.can_restore_trash() {
+            return;
+        }
+        self.restore_error = None;
+        self.restore_rx = Some(start_restore(self.trashed.clone()));
+    }
+
+    /// Picks up the result of [`Self::restore_trash`] once it has finished.
+    /// On success the restored directories are taken back off the session's
+    /// totals — they were never really removed — and counted in
+    /// [`Self::delete_restored`] instead. They don't come back into the
+    /// list, which is usually empty by now.
+    pub fn process_restore_messages(&mut self) {
+        let Some(rx) = &self.restore_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(Ok(_)) => {
+                let count = self.trashed.len();
+                self.delete_restored += count;
+                self.delete_removed = self.delete_removed.saturating_sub(count);
+                self.delete_trashed = self.delete_trashed.saturating_sub(count);
+                self.delete_freed = self.delete_freed.saturating_sub(self.trashed_size);
+                self.trashed.clear();
+                self.trashed_size = 0;
+                self.disk_space = diskspace::of_roots(&self.scan_roots);
+            }
+            Ok(Err(e)) => self.restore_error = Some(e),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.restore_error = Some("the restore stopped unexpectedly".to_string());
+            }
+        }
+        self.restore_rx = None;
+    }
+
+    /// Scrolls the error list up by one row, wrapping to the bottom.
+    pub fn errors_up(&mut self) {
+        let len = self.visible_errors().len();
+        step(&mut self.error_list_state, len, false, true);
+    }
+
+    /// Scrolls the error list down by one row, wrapping to the top.
+    pub fn errors_down(&mut self) {
+        let len = self.visible_errors().len();
+        step(&mut self.error_list_state, len, true, true);
+    }
+
+    /// Starts typing into the error filter.
+    pub fn begin_error_filter(&mut self) {
+        self.error_filter_editing = true;
+    }
+
+    /// Stops typing into the error filter, keeping whatever was typed.
+    pub fn end_error_filter(&mut self) {
+        self.error_filter_editing = false;
+    }
+
+    /// Stops typing into the error filter and empties it, so every error is
+    /// shown again.
+    pub fn clear_error_filter(&mut self) {
+        self.error_filter_editing = false;
+        self.error_filter.clear();
+        self.reset_error_cursor();
+    }
+
+    /// Appends `c` to the error filter.
+    pub fn push_error_filter(&mut self, c: char) {
+        self.error_filter.push(c);
+        self.reset_error_cursor();
+    }
+
+    /// Removes the last character of the error filter.
+    pub fn pop_error_filter(&mut self) {
+        self.error_filter.pop();
+        self.reset_error_cursor();
+    }
+
+    /// Moves the error cursor back to the first match after the filter
+    /// changes, since the old position may no longer exist.
+    fn reset_error_cursor(&mut self) {
+        let first = (!self.visible_errors().is_empty()).then_some(0);
+        self.error_list_state.select(first);
+    }
+}
+
+// ─── Navigation ───────────────────────────────────────────────────────────────
+
+impl App {
+    /// Goes back one step from the current screen — what Esc does everywhere.
+    /// Returns `true` if there is nowhere further back to go and the app
+    /// should quit.
+    ///
+    /// | From | Back to |
+    /// |------|---------|
+    /// | [`Screen::Welcome`] | quit |
+    /// | [`Screen::Scanning`] | [`Screen::Welcome`], abandoning the scan |
+    /// | [`Screen::List`] | no marked range if one is marked, then the unfiltered list if a filter is set, otherwise [`Screen::Welcome`], ready to scan again |
+    /// | [`Screen::Confirming`] | [`Screen::List`], nothing deleted |
+    /// | [`Screen::SensitiveReview`] | [`Screen::List`], nothing deleted |
+    /// | [`Screen::Countdown`] | [`Screen::List`], nothing deleted |
+    /// | [`Screen::Breakdown`] | [`Screen::List`] |
+    /// | [`Screen::Detail`] | [`Screen::List`] |
+    /// | [`Screen::Deleting`] | nowhere yet — the delete is [cancelled](Self::cancel_running_delete) and ends on `Done` |
+    /// | [`Screen::Done`] | the unfiltered error list if a filter is set, otherwise quit |
+    ///
+    /// Quitting outright is what `q` is for; Esc only quits from the first
+    /// and last screens, so pressing it once too often never loses work.
+    /// The emergency exit is [`Self::press_force_quit`].
+    pub fn go_back(&mut self) -> bool {
+        match self.screen {
+            Screen::Welcome => return true,
+            Screen::Scanning => {
+                // Dropping the handle sets its stop flag, so the detached
+                // scan thread stops walking once the directory in hand is
+                // done, rather than racing a scan started from Welcome.
+                self.scan = None;
+                self.entries.clear();
+                self.screen = Screen::Welcome;
+            }
+            // A marked range is dropped before anything else.
+            Screen::List if self.anchor.is_some() => self.anchor = None,
+            Screen::List if self.list_filter_editing || !self.list_filter.is_empty() => {
+                self.clear_list_filter();
+            }
+            Screen::List => self.screen = Screen::Welcome,
+            Screen::Confirming | Screen::SensitiveReview | Screen::Countdown => {
+                self.cancel_delete()
+            }
+            Screen::Breakdown => self.close_breakdown(),
+            Screen::Detail => self.close_detail(),
+            Screen::Deleting => self.cancel_running_delete(),
+            Screen::Done => {
+                if !self.error_filter_editing && self.error_filter.is_empty() {
+                    return true;
+                }
+                self.clear_error_filter();
+            }
+        }
+        false
+    }
+
+    /// Handles Ctrl-C / Ctrl-Q, the emergency exit. Returns `true` if the app
+    /// should quit now.
+    ///
+    /// The first press only arms it (and the UI says so); a second press
+    /// straight after quits from any screen — including
+    /// [`Screen::Deleting`], so a delete stuck on a hung network filesystem
+    /// can't leave the app unkillable. There the first press also
+    /// [cancels](Self::cancel_running_delete) the delete, and the second
+    /// abandons the thread wherever it has got to. Needing two presses keeps
+    /// a stray Ctrl-C from doing that by accident.
+    pub fn press_force_quit(&mut self) -> bool {
+        if self.force_quit_armed {
+            return true;
+        }
+        if matches!(self.screen, Screen::Deleting) {
+            self.cancel_running_delete();
+        }
+        self.force_quit_armed = true;
+        false
+    }
+}
+
+/// Moves `state` one row forwards or backwards through a list of `len`
+/// rows, wrapping at either end if `wrap` is set and stopping there if not.
+/// Does nothing for an empty list.
+fn step(state: &mut ListState, len: usize, forward: bool, wrap: bool) {
+    if len == 0 {
+        return;
+    }
+    let i = match (state.selected(), forward) {
+        (Some(i), true) if wrap => (i + 1) % len,
+        (Some(i), false) if wrap => (i + len - 1) % len,
+        (Some(i), true) => (i + 1).min(len - 1),
+        (Some(i), false) => i.saturating_sub(1),
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+    state.select(Some(i));
 }
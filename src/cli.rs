@@ -0,0 +1,808 @@
+//! # Command-line parsing
+//!
+//! killnode's flags are few and simple, so they are parsed by hand rather
+//! than pulling in an argument-parsing crate. [`parse`] turns the raw
+//! arguments into a [`Command`]; the binary decides what to do with it.
+//!
+//! Parsing is strict: unknown flags and stray extra arguments are errors, so
+//! a typo can't silently turn into "scan a directory called `--exlcude`".
+//!
+//! [`Options`] also knows how to turn itself into the settings the scan and
+//! delete threads take, so the TUI and the headless modes agree on what each
+//! flag means.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    app::{NodeModuleEntry, ROOT_ENV, SortMode},
+    config,
+    deleter::{DeleteMode, DeleteOptions, lock_path},
+    du::DuUnit,
+    preselect::{Preselect, parse_age, parse_size},
+    scanner::{ScanOptions, resolve_root},
+};
+
+// ─── Help text ────────────────────────────────────────────────────────────────
+
+/// The text printed by `--help`.
+pub const HELP: &str = "\
+killnode — find and delete node_modules directories
+
+USAGE:
+    killnode [OPTIONS] [DIRECTORY]...
+
+ARGS:
+    [DIRECTORY]...  Directories to scan, all into one list (defaults to
+                    $KILLNODE_ROOT, then the current directory)
+
+OPTIONS:
+        --apply <FILE>
+                     Don't start the TUI; delete every path in FILE (a --json
+                     export) and print a one-line summary. Exits with 1 if
+                     any deletion failed
+        --color <auto|always|never>
+                     When to use colour. auto (the default) uses it unless
+                     NO_COLOR is set or output isn't a terminal; never draws
+                     the TUI with the mono theme
+        --no-color   Same as --color never
+        --detach     When a deletion is confirmed, finish it in a background
+                     process and exit at once. It writes a report when done
+        --disk-usage Measure the space files take up on disk, as du and df
+                     do, rather than their length
+        --du-format  Don't start the TUI; scan and print a `du`-style line
+                     (size, tab, path) for each node_modules
+        --du-unit <B|K|M|G>
+                     Unit for --du-format sizes, in powers of 1024 like du
+                     (default K)
+        --exclude <PATTERN>
+                     Never scan anything whose full absolute path matches
+                     glob PATTERN (* and ? stay within one component, **
+                     spans several, [abc] and {a,b} as usual). May be
+                     repeated
+        --exclude-git-repos-with-uncommitted-changes
+                     Never delete a node_modules whose git repository has
+                     uncommitted changes to tracked files
+        --exclude-sensitive
+                     Same as --sensitive hide
+        --force      With --apply, delete sensitive paths too rather than
+                     leaving them alone
+        --ignore-file <FILE>
+                     Never scan directories matching a glob in FILE, one
+                     per line (# starts a comment); one without a / matches
+                     the directory's name at any depth. May be repeated
+        --include-sensitive
+                     Same as --sensitive include. Deleting them still needs
+                     the usual confirmation
+        --jobs <N>   Delete up to N directories at once, each on its own
+                     thread (default 1)
+        --json <FILE>
+                     Don't start the TUI; scan and write what was found to
+                     FILE as a JSON array (see README)
+        --no-size    Don't measure directory sizes; just list locations.
+                     Much faster on large trees
+        --no-wrap    Stop at the top and bottom of the list instead of
+                     wrapping around to the other end
+        --interactive-sensitive-review
+                     Before deleting sensitive directories, list each one
+                     with why it is sensitive and ask again
+        --max-depth <N>
+                     Look at most N directory levels below the root; any
+                     node_modules deeper than that is skipped entirely
+        --max-results <N>
+                     Stop scanning after finding N node_modules directories
+                     to list; ones --min-size, --older-than or --sensitive
+                     hide leave out don't count
+        --min-size <SIZE>
+                     Leave node_modules smaller than SIZE out of the list,
+                     e.g. 50MB or 1GB
+        --older-than <AGE>
+                     Only list node_modules last modified more than AGE ago,
+                     e.g. 30d, 6mo or 1y. Those whose age is unknown are
+                     left out too, unless --include-undated is given
+        --include-undated
+                     With --older-than, still list node_modules whose age
+                     can't be read
+        --one-file-system
+                     Don't scan into directories on other filesystems, such
+                     as network mounts or external drives (like du -x).
+                     Unix only; ignored elsewhere
+        --preselect <RULE>
+                     Only pre-select entries matching RULE, e.g.
+                     'age>90d and size>200MB' (see README)
+        --profile <NAME>
+                     Select what profile NAME saved (press P in the list to
+                     save the current selection to it)
+        --progress-json
+                     Don't start the TUI; scan (or, with --apply, delete) and
+                     print one JSON object per line for each event instead
+                     (see README)
+        --prune-empty-parents
+                     After deleting a node_modules, also remove parent
+                     directories left empty (never the scan root itself)
+        --run-plan <FILE>
+                     Don't start the TUI; delete the paths listed in FILE
+                     (a plan written by --detach) and write a report
+        --root-marker <NAME>
+                     Only list node_modules inside a project, meaning a
+                     directory above them contains NAME (e.g. .git)
+        --sensitive <auto|hide|include>
+                     How to treat directories in sensitive locations:
+                     auto     list them, unselected (default)
+                     hide     leave them out of the list entirely
+                     include  list them, selected like any other
+        --sort <size|name|age|smart>[-asc|-desc]
+                     How to order the list at first: size largest first,
+                     name A to Z, age oldest first (default: size). s on the
+                     list still cycles through the orders
+        --stats-only Don't start the TUI; scan and print only how many
+                     node_modules there are and their total size
+        --targets <NAMES>
+                     Comma-separated directory names to look for instead of
+                     node_modules, e.g. node_modules,target,.venv
+        --theme <default|high-contrast|mono>
+                     Colour palette. high-contrast uses bright colours and
+                     never dims text; mono uses no colour at all
+        --trash      Move directories to the system trash instead of deleting
+                     them. The done screen can empty them from it afterwards
+    -y, --yes        Delete the selection as soon as Enter is pressed, without
+                     asking to confirm. Still asks if anything sensitive is
+                     selected
+    -h, --help       Print this help message
+    -V, --version    Print version information
+
+ENVIRONMENT:
+    KILLNODE_ROOT    Directory to scan when none is given on the command line
+    NO_COLOR         If set to anything, use no colour (unless --color always)
+";
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// What the user asked for on the command line.
+pub enum Command {
+    /// Start the TUI with these options. Boxed, since there are a lot of
+    /// them and the other variants are empty.
+    Run(Box<Options>),
+
+    /// Print [`HELP`] and exit.
+    Help,
+
+    /// Print the version and exit.
+    Version,
+}
+
+/// How directories in sensitive locations (see
+/// [`crate::scanner::ScanMsg::Found`]) are treated. Set by `--sensitive`, or
+/// its shorthands `--exclude-sensitive` and `--include-sensitive`; the last
+/// one given wins.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SensitivePolicy {
+    /// List them, but leave them unselected so deleting one is always a
+    /// deliberate choice.
+    #[default]
+    Auto,
+
+    /// Leave them out of the list entirely.
+    Hide,
+
+    /// List them pre-selected, exactly like any other entry. The warning
+    /// marker and confirmation notice still appear, and so does the typed
+    /// confirmation a sensitive deletion asks for.
+    Include,
+}
+
+/// When to use colour. Set by `--color` (or `--no-color`, meaning
+/// [`ColorChoice::Never`]); [`Options::use_color`] turns it into a yes or
+/// no.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Use colour when writing to a terminal and `NO_COLOR` isn't set.
+    #[default]
+    Auto,
+
+    /// Always use colour, even if `NO_COLOR` is set.
+    Always,
+
+    /// Never use colour.
+    Never,
+}
+
+/// Which built-in colour palette to draw with. Set by `--theme`; see
+/// [`crate::ui::Theme`] for what each one looks like.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeName {
+    #[default]
+    Default,
+    HighContrast,
+    Mono,
+}
+
+/// Options that shape a normal run.
+#[derive(Clone, Default)]
+pub struct Options {
+    /// The directories to scan, exactly as given and in order. Empty if no
+    /// positional argument was passed; see [`Options::scan_roots`] for the
+    /// fallbacks.
+    pub roots: Vec<String>,
+
+    /// Skip (rather than delete) any `node_modules` whose enclosing git
+    /// repository has uncommitted changes to tracked files.
+    /// Set by `--exclude-git-repos-with-uncommitted-changes`.
+    pub exclude_dirty_git: bool,
+
+    /// Skip measuring directory sizes, both while scanning and before
+    /// deleting. Set by `--no-size`.
+    pub no_size: bool,
+
+    /// Stop the list's cursor at the top and bottom instead of wrapping
+    /// around to the other end. Set by `--no-wrap`.
+    pub no_wrap: bool,
+
+    /// Measure sizes by the disk space files take up rather than their
+    /// length. Set by `--disk-usage`.
+    pub disk_usage: bool,
+
+    /// Whether sensitive directories are listed and pre-selected.
+    pub sensitive: SensitivePolicy,
+
+    /// Show [`crate::app::Screen::SensitiveReview`] before deleting any
+    /// sensitive entry. Set by `--interactive-sensitive-review`.
+    pub sensitive_review: bool,
+
+    /// Stop the scan after this many directories have been found. Set by
+    /// `--max-results`; `None` means no limit.
+    pub max_results: Option<usize>,
+
+    /// Look at most this many directory levels below each scan root. Set by
+    /// `--max-depth`; `None` means no limit.
+    pub max_depth: Option<usize>,
+
+    /// Don't scan into directories on another filesystem than the root's.
+    /// Set by `--one-file-system`.
+    pub one_file_system: bool,
+
+    /// Delete this many directories at once. Set by `--jobs`; `None` means
+    /// one at a time.
+    pub jobs: Option<usize>,
+
+    /// Leave directories smaller than this many bytes out of the list. Set
+    /// by `--min-size`; `None` lists everything.
+    pub min_size: Option<u64>,
+
+    /// Leave directories modified less than this many seconds ago out of
+    /// the list. Set by `--older-than`; `None` lists everything.
+    pub older_than: Option<u64>,
+
+    /// Under [`Self::older_than`], still list directories whose modification
+    /// time is unknown, rather than leaving them out as not provably stale.
+    /// Set by `--include-undated`.
+    pub include_undated: bool,
+
+    /// The order the list starts in. Set by `--sort`; `s` on the list
+    /// screen still cycles it from there.
+    pub sort: SortMode,
+
+    /// The colour palette to draw with.
+    pub theme: ThemeName,
+
+    /// When to use colour at all. Set by `--color`.
+    pub color: ColorChoice,
+
+    /// Only list `node_modules` with an entry of this name somewhere above
+    /// them. Set by `--root-marker`.
+    pub root_marker: Option<String>,
+
+    /// The directory names to look for instead of just `node_modules`. Set
+    /// by `--targets`; empty means the default.
+    pub targets: Vec<String>,
+
+    /// Globs for directories the scan never enters, read from every
+    /// `--ignore-file` given. See [`crate::config::ignore_set`].
+    pub ignore: Vec<String>,
+
+    /// Globs for paths the scan never enters, one per `--exclude`, matched
+    /// against the full absolute path. See [`crate::config::exclude_set`].
+    pub exclude: Vec<String>,
+
+    /// Remove parent directories a deletion leaves empty, up to (but not
+    /// including) the scan root. Set by `--prune-empty-parents`.
+    pub prune_empty_parents: bool,
+
+    /// Narrows down which entries start out selected. Set by
+    /// `--preselect`; see [`crate::preselect`].
+    pub preselect: Option<Preselect>,
+
+    /// The saved selection to restore after a scan and to save to with `P`.
+    /// Set by `--profile`; see [`crate::config`].
+    pub profile: Option<String>,
+
+    /// Hand confirmed deletions to a background process and exit instead of
+    /// waiting for them. Set by `--detach`; see [`crate::job`].
+    pub detach: bool,
+
+    /// Run headlessly, deleting the paths in this plan file. Set by
+    /// `--run-plan`, which is how a `--detach` worker is started.
+    pub run_plan: Option<String>,
+
+    /// Whether deleted directories are removed or moved to the system
+    /// trash. [`DeleteMode::Trash`] with `--trash`; the confirmation popup
+    /// can switch it for the rest of the session.
+    pub delete_mode: DeleteMode,
+
+    /// Skip the confirmation popup when Enter is pressed on the list,
+    /// unless something sensitive is queued. Set by `--yes`.
+    pub yes: bool,
+
+    /// Run headlessly, printing a `du`-style line per entry instead of
+    /// starting the TUI. Set by `--du-format`; see [`crate::du`].
+    pub du_format: bool,
+
+    /// The unit `--du-format` sizes are printed in. Set by `--du-unit`.
+    pub du_unit: DuUnit,
+
+    /// Run headlessly, printing only the count and total size of what was
+    /// found. Set by `--stats-only`; see [`crate::stats`].
+    pub stats_only: bool,
+
+    /// Run headlessly, writing what was found to this file as JSON. Set by
+    /// `--json`; see [`crate::export`].
+    pub json: Option<String>,
+
+    /// Run headlessly, streaming progress events to stdout as JSON lines
+    /// instead of starting the TUI. Set by `--progress-json`. With `apply` it
+    /// streams the deletion rather than a scan.
+    pub progress_json: bool,
+
+    /// Run headlessly, deleting the paths in this `--json` export. Set by
+    /// `--apply`; see [Applying an export](crate::export#applying-an-export).
+    pub apply: Option<String>,
+
+    /// Under [`Self::apply`], delete sensitive paths as well. Set by
+    /// `--force`.
+    pub force: bool,
+}
+
+// ─── Conversions ──────────────────────────────────────────────────────────────
+
+impl Options {
+    /// Returns the absolute paths of the directories to scan, never empty:
+    ///
+    /// - If the user passed paths on the command line, those are used, with
+    ///   repeats dropped.
+    /// - Otherwise, if [`ROOT_ENV`] is set to a non-empty value, that is used.
+    ///   Either way the paths are resolved with
+    ///   [`crate::scanner::resolve_root`], so the welcome screen shows exactly
+    ///   what will be walked and two spellings of one directory count as a
+    ///   repeat.
+    /// - Otherwise the current working directory is used.
+    /// - If even `cwd` fails (rare, but possible on some systems), `.` is
+    ///   used as a last resort so killnode always starts successfully.
+    pub fn scan_roots(&self) -> Vec<String> {
+        let given = if self.roots.is_empty() {
+            std::env::var(ROOT_ENV)
+                .ok()
+                .filter(|v| !v.is_empty())
+                .into_iter()
+                .collect()
+        } else {
+            self.roots.clone()
+        };
+        let mut roots: Vec<String> = Vec::new();
+        for arg in given {
+            let root = resolve_root(&arg).to_string_lossy().to_string();
+            if !roots.contains(&root) {
+                roots.push(root);
+            }
+        }
+        if roots.is_empty() {
+            roots.push(
+                std::env::current_dir()
+                    .unwrap_or_else(|_| PathBuf::from("."))
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+        roots
+    }
+
+    /// Returns whether output should be coloured, for output that goes to a
+    /// terminal if `is_terminal`. Every output path asks this rather than
+    /// deciding for itself; see [`color_enabled`] for the rules.
+    ///
+    /// The TUI always passes `true`, since it only ever draws to a terminal.
+    pub fn use_color(&self, is_terminal: bool) -> bool {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        color_enabled(self.color, no_color, is_terminal)
+    }
+
+    /// Returns the settings a scan runs with under these options.
+    ///
+    /// [`ScanOptions::keep`] is left empty: the always-keep list lives in
+    /// the config directory, not on the command line, so whoever starts the
+    /// scan fills it in ([`crate::app::App::begin_scan`],
+    /// [`crate::engine::Engine::from_options`]).
+    ///
+    /// The `--exclude` and `--ignore-file` globs are compiled here, once per
+    /// scan. [`parse`] has already rejected any that don't compile, so an
+    /// error can't happen for parsed options; should one slip through, that
+    /// set matches nothing.
+    pub fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            skip_sizes: self.no_size,
+            disk_usage: self.disk_usage,
+            max_results: self.max_results,
+            hide_sensitive: self.sensitive == SensitivePolicy::Hide,
+            min_size: self.min_size,
+            older_than: self.older_than,
+            include_undated: self.include_undated,
+            max_depth: self.max_depth,
+            one_file_system: self.one_file_system,
+            root_marker: self.root_marker.clone(),
+            ignore: config::ignore_set(&self.ignore).unwrap_or_default(),
+            exclude: config::exclude_set(&self.exclude).unwrap_or_default(),
+            targets: self.targets.clone(),
+            keep: Vec::new(),
+        }
+    }
+
+    /// Returns the settings a deletion runs with under these options.
+    /// `scan_roots` are where `--prune-empty-parents` stops. Every deletion
+    /// takes the shared [`lock_path`] lock.
+    pub fn delete_options(&self, scan_roots: &[String]) -> DeleteOptions {
+        DeleteOptions {
+            skip_dirty_git: self.exclude_dirty_git,
+            skip_sizes: self.no_size,
+            disk_usage: self.disk_usage,
+            prune_empty_parents: if self.prune_empty_parents {
+                scan_roots.iter().map(PathBuf::from).collect()
+            } else {
+                Vec::new()
+            },
+            lock: Some(lock_path()),
+            mode: self.delete_mode,
+            jobs: self.jobs.unwrap_or(1),
+        }
+    }
+
+    /// Returns whether `--older-than` leaves out a directory last modified
+    /// at `last_modified` (seconds since the Unix epoch), as of `now_secs`.
+    ///
+    /// A directory whose modification time is unknown can't be shown to be
+    /// stale, so it is left out too — unless `--include-undated` is given.
+    pub fn too_recent(&self, last_modified: Option<u64>, now_secs: u64) -> bool {
+        let Some(min_age) = self.older_than else {
+            return false;
+        };
+        match last_modified {
+            Some(t) => now_secs.saturating_sub(t) < min_age,
+            None => !self.include_undated,
+        }
+    }
+
+    /// Returns whether a headless run reports `entry`, as of `now_secs`:
+    /// whether it survives `--sensitive hide`, `--min-size` and
+    /// `--older-than` (see [`Options::too_recent`]), as it would have to
+    /// to show in the list.
+    ///
+    /// Every headless mode filters with this, whether it collects the whole
+    /// scan first or streams each entry as it is found, so they all agree
+    /// with each other on what was found. What it leaves out is neither
+    /// reported nor counted, not even towards `--max-results` (see
+    /// [`ScanOptions::lists`], the scanner's side of the same test).
+    pub fn lists(&self, entry: &NodeModuleEntry, now_secs: u64) -> bool {
+        !(self.sensitive == SensitivePolicy::Hide && entry.sensitive.is_some())
+            && self.min_size.is_none_or(|min| entry.size >= min)
+            && !self.too_recent(entry.last_modified, now_secs)
+    }
+}
+
+/// Returns the current time in seconds since the Unix epoch, as
+/// [`Options::too_recent`] and [`Options::lists`] take it, or 0 if the
+/// clock is set before it.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Decides whether to use colour under `choice`, given whether the
+/// `NO_COLOR` environment variable is set (to a non-empty value, as
+/// <https://no-color.org> asks) and whether output goes to a terminal.
+///
+/// `always` and `never` mean exactly that. `auto` uses colour only on a
+/// terminal, and only if `NO_COLOR` isn't set.
+pub fn color_enabled(choice: ColorChoice, no_color: bool, is_terminal: bool) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => is_terminal && !no_color,
+    }
+}
+
+// ─── Parsing ──────────────────────────────────────────────────────────────────
+
+/// Options that are followed by a value.
+const VALUE_OPTIONS: &[&str] = &[
+    "--apply",
+    "--color",
+    "--du-unit",
+    "--exclude",
+    "--ignore-file",
+    "--jobs",
+    "--json",
+    "--max-depth",
+    "--max-results",
+    "--min-size",
+    "--older-than",
+    "--preselect",
+    "--profile",
+    "--root-marker",
+    "--run-plan",
+    "--sensitive",
+    "--sort",
+    "--targets",
+    "--theme",
+];
+
+/// Parses `args` (without the program name) into a [`Command`].
+///
+/// `--help` and `--version` stop parsing as soon as they are seen, so
+/// anything after them is ignored. Everything after a bare `--` is treated
+/// as positional, which is how to scan a directory whose name starts with `-`.
+/// Options that take a value accept it either as the next argument
+/// (`--sensitive hide`) or after an `=` (`--sensitive=hide`).
+///
+/// Returns a message suitable for printing to stderr if the arguments don't
+/// make sense.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut positional_only = false;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if !positional_only && arg.starts_with('-') && arg != "-" {
+            // Split `--flag=value` so both spellings share one match arm.
+            // Only options that take a value are split, so `--no-size=1`
+            // is still rejected as unknown.
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if VALUE_OPTIONS.contains(&flag) => {
+                    (flag, Some(value.to_string()))
+                }
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("option '{flag}' needs a value"))
+            };
+
+            match flag {
+                "-h" | "--help" => return Ok(Command::Help),
+                "-V" | "--version" => return Ok(Command::Version),
+                "--" => positional_only = true,
+                "--exclude-git-repos-with-uncommitted-changes" => {
+                    options.exclude_dirty_git = true;
+                }
+                "--detach" => options.detach = true,
+                "--exclude-sensitive" => options.sensitive = SensitivePolicy::Hide,
+                "--include-sensitive" => options.sensitive = SensitivePolicy::Include,
+                "--du-format" => options.du_format = true,
+                "--force" => options.force = true,
+                "--include-undated" => options.include_undated = true,
+                "--interactive-sensitive-review" => options.sensitive_review = true,
+                "--no-color" => options.color = ColorChoice::Never,
+                "--no-size" => options.no_size = true,
+                "--one-file-system" => options.one_file_system = true,
+                "--no-wrap" => options.no_wrap = true,
+                "--disk-usage" => options.disk_usage = true,
+                "--progress-json" => options.progress_json = true,
+                "--prune-empty-parents" => options.prune_empty_parents = true,
+                "--stats-only" => options.stats_only = true,
+                "--trash" => options.delete_mode = DeleteMode::Trash,
+                "-y" | "--yes" => options.yes = true,
+                "--apply" => options.apply = Some(value()?),
+                "--color" => options.color = parse_color(&value()?)?,
+                "--du-unit" => options.du_unit = parse_du_unit(&value()?)?,
+                "--exclude" => options.exclude.push(config::ignore_pattern(value()?)),
+                "--ignore-file" => options.ignore.extend(load_ignore_file(&value()?)?),
+                "--jobs" => options.jobs = Some(parse_positive("--jobs", &value()?)?),
+                "--json" => options.json = Some(value()?),
+                "--max-depth" => {
+                    options.max_depth = Some(parse_positive("--max-depth", &value()?)?)
+                }
+                "--max-results" => {
+                    options.max_results = Some(parse_positive("--max-results", &value()?)?)
+                }
+                "--min-size" => options.min_size = Some(parse_min_size(&value()?)?),
+                "--older-than" => options.older_than = Some(parse_older_than(&value()?)?),
+                "--preselect" => options.preselect = Some(parse_preselect(&value()?)?),
+                "--profile" => options.profile = Some(parse_name("--profile", value()?)?),
+                "--root-marker" => {
+                    options.root_marker = Some(parse_name("--root-marker", value()?)?)
+                }
+                "--run-plan" => options.run_plan = Some(value()?),
+                "--sensitive" => options.sensitive = parse_sensitive(&value()?)?,
+                "--sort" => options.sort = parse_sort(&value()?)?,
+                "--targets" => options.targets = parse_targets(&value()?)?,
+                "--theme" => options.theme = parse_theme(&value()?)?,
+                _ => return Err(format!("unknown option '{arg}'")),
+            }
+            continue;
+        }
+
+        options.roots.push(arg);
+    }
+
+    // Without sizes every entry would measure 0 and be left out.
+    if options.no_size && options.min_size.is_some() {
+        return Err(
+            "'--min-size' can't be used with '--no-size', which skips measuring".to_string(),
+        );
+    }
+
+    if options.include_undated && options.older_than.is_none() {
+        return Err("'--include-undated' only makes sense with '--older-than'".to_string());
+    }
+
+    // Compiled for real by `Options::scan_options`; checked here so a typo
+    // is reported before anything runs.
+    config::exclude_set(&options.exclude).map_err(|e| format!("'--exclude': {e}"))?;
+    config::ignore_set(&options.ignore).map_err(|e| format!("'--ignore-file': {e}"))?;
+
+    if options.force && options.apply.is_none() {
+        return Err("'--force' only makes sense with '--apply'".to_string());
+    }
+
+    // Each of these runs without the TUI and does one job, so two of them
+    // can't both be honoured. `--progress-json` with `--apply` is the one
+    // pair that goes together: it streams the deletion instead of a scan.
+    let headless: Vec<&str> = [
+        (options.apply.is_some(), "--apply"),
+        (options.du_format, "--du-format"),
+        (options.json.is_some(), "--json"),
+        (
+            options.progress_json && options.apply.is_none(),
+            "--progress-json",
+        ),
+        (options.run_plan.is_some(), "--run-plan"),
+        (options.stats_only, "--stats-only"),
+    ]
+    .into_iter()
+    .filter_map(|(given, flag)| given.then_some(flag))
+    .collect();
+    if let [first, second, ..] = headless[..] {
+        return Err(format!("'{first}' can't be used with '{second}'"));
+    }
+
+    Ok(Command::Run(Box::new(options)))
+}
+
+/// Parses the value of `--color`.
+fn parse_color(value: &str) -> Result<ColorChoice, String> {
+    match value {
+        "auto" => Ok(ColorChoice::Auto),
+        "always" => Ok(ColorChoice::Always),
+        "never" => Ok(ColorChoice::Never),
+        _ => Err(format!(
+            "invalid value '{value}' for '--color' (expected auto, always or never)"
+        )),
+    }
+}
+
+/// Parses the value of `--du-unit`. Lowercase letters are accepted too.
+fn parse_du_unit(value: &str) -> Result<DuUnit, String> {
+    match value {
+        "B" | "b" => Ok(DuUnit::Bytes),
+        "K" | "k" => Ok(DuUnit::Kibibytes),
+        "M" | "m" => Ok(DuUnit::Mebibytes),
+        "G" | "g" => Ok(DuUnit::Gibibytes),
+        _ => Err(format!(
+            "invalid value '{value}' for '--du-unit' (expected B, K, M or G)"
+        )),
+    }
+}
+
+/// Reads the patterns in the `--ignore-file` at `path`. The file is read
+/// here rather than when the scan starts, so a missing or unreadable file
+/// stops killnode before anything else happens.
+fn load_ignore_file(path: &str) -> Result<Vec<String>, String> {
+    config::load_ignore_file(Path::new(path))
+        .map_err(|e| format!("can't read ignore file '{path}' for '--ignore-file': {e}"))
+}
+
+/// Parses the value of `--preselect`.
+fn parse_preselect(value: &str) -> Result<Preselect, String> {
+    Preselect::parse(value).map_err(|e| format!("invalid rule '{value}' for '--preselect': {e}"))
+}
+
+/// Parses the value of `--root-marker` or `--profile`: a single file or
+/// directory name, which is what both end up being used as.
+fn parse_name(flag: &str, value: String) -> Result<String, String> {
+    if value.is_empty() || value.contains(['/', '\\']) || value == "." || value == ".." {
+        return Err(format!(
+            "invalid value '{value}' for '{flag}' (expected a file or directory name)"
+        ));
+    }
+    Ok(value)
+}
+
+/// Parses the value of `--targets`: a comma-separated list of directory
+/// names, each checked like [`parse_name`].
+fn parse_targets(value: &str) -> Result<Vec<String>, String> {
+    value
+        .split(',')
+        .map(|name| parse_name("--targets", name.trim().to_string()))
+        .collect()
+}
+
+/// Parses the value of `--sensitive`.
+fn parse_sensitive(value: &str) -> Result<SensitivePolicy, String> {
+    match value {
+        "auto" => Ok(SensitivePolicy::Auto),
+        "hide" => Ok(SensitivePolicy::Hide),
+        "include" => Ok(SensitivePolicy::Include),
+        _ => Err(format!(
+            "invalid value '{value}' for '--sensitive' (expected auto, hide or include)"
+        )),
+    }
+}
+
+/// Parses the value of `flag`, which must be a positive integer.
+fn parse_positive(flag: &str, value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!(
+            "invalid value '{value}' for '{flag}' (expected a positive number)"
+        )),
+    }
+}
+
+/// Parses the value of `--min-size`: a number with an optional decimal
+/// unit, in any case.
+fn parse_min_size(value: &str) -> Result<u64, String> {
+    parse_size(value).ok_or_else(|| {
+        format!("invalid value '{value}' for '--min-size' (expected a size such as 50MB or 1GB)")
+    })
+}
+
+/// Parses the value of `--older-than`: a number with an age unit, in any
+/// case.
+fn parse_older_than(value: &str) -> Result<u64, String> {
+    parse_age(value).ok_or_else(|| {
+        format!("invalid value '{value}' for '--older-than' (expected an age such as 30d or 6mo)")
+    })
+}
+
+/// Parses the value of `--sort` into the [`SortMode`] the `s` key cycles
+/// through. Each key has a natural direction — size largest first, name A to
+/// Z, age oldest first — which a bare key gets; `-asc` or `-desc` picks one
+/// explicitly. Names only sort A to Z, as the `s` cycle has no Z to A.
+fn parse_sort(value: &str) -> Result<SortMode, String> {
+    match value {
+        "size" | "size-desc" => Ok(SortMode::Size),
+        "size-asc" => Ok(SortMode::SizeAscending),
+        "name" | "name-asc" => Ok(SortMode::Path),
+        "age" | "age-desc" => Ok(SortMode::Oldest),
+        "age-asc" => Ok(SortMode::Newest),
+        "smart" => Ok(SortMode::Smart),
+        _ => Err(format!(
+            "invalid value '{value}' for '--sort' (expected size, size-asc, size-desc, \
+             name, name-asc, age, age-asc, age-desc or smart)"
+        )),
+    }
+}
+
+/// Parses the value of `--theme`.
+fn parse_theme(value: &str) -> Result<ThemeName, String> {
+    match value {
+        "default" => Ok(ThemeName::Default),
+        "high-contrast" => Ok(ThemeName::HighContrast),
+        "mono" => Ok(ThemeName::Mono),
+        _ => Err(format!(
+            "invalid value '{value}' for '--theme' (expected default, high-contrast or mono)"
+        )),
+    }
+}
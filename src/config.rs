@@ -0,0 +1,270 @@
+//! # Saved state
+//!
+//! The little that killnode remembers between runs lives in its config
+//! directory, [`dir`]:
+//!
+//! | Platform | Location |
+//! |----------|----------|
+//! | Linux, macOS | `$XDG_CONFIG_HOME/killnode`, or `~/.config/killnode` |
+//! | Windows | `%APPDATA%\killnode` |
+//!
+//! Everything in it is plain text with one item per line, so it can be read,
+//! edited, or put under version control by hand. Blank lines and lines
+//! starting with `#` are ignored.
+//!
+//! ## Profiles
+//!
+//! `profiles/NAME` holds a saved selection: the paths that were selected when
+//! `P` was pressed with `--profile NAME`. A later scan with the same
+//! `--profile` selects exactly the entries that match a line (see
+//! [`pattern_matches`]). Lines may be edited into patterns, so
+//! `/home/alice/work/*` covers every project under `work`.
+//!
+//! ## Always keep
+//!
+//! `keep` lists `node_modules` the user never wants deleted, one path per
+//! line, added and removed with `p` (or `K`) in the list. A scan reports a
+//! matching entry as sensitive, for
+//! [that reason](crate::scanner::SensitiveReason::Kept), so it starts out
+//! unselected however the other options would have treated it, and
+//! `--apply` leaves it alone without `--force`. Paths are compared after
+//! [`normalize_path`].
+//!
+//! ## Ignore files
+//!
+//! An ignore file, given with `--ignore-file`, can live anywhere. It lists
+//! directories the scan should never enter, one pattern per line, in the
+//! same plain-text format (see [`load_ignore_file`] and [`ignore_set`]).
+//! `--exclude` gives a single pattern on the command line instead; it is
+//! always matched against the whole path (see [`exclude_set`]).
+//!
+//! Both are standard globs, compiled once per scan: `*` and `?` stay within
+//! one path component, `**` spans any number of them, `[abc]` matches one
+//! of a set of characters and `{a,b}` either alternative. Profiles keep
+//! their simpler patterns (see [`pattern_matches`]), where `*` crosses
+//! separators, so profiles saved before still mean what they did.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+// ─── Location ─────────────────────────────────────────────────────────────────
+
+/// Returns killnode's config directory, or `None` if the environment doesn't
+/// say where it should be (no home directory at all). The directory is not
+/// created until something is saved.
+pub fn dir() -> Option<PathBuf> {
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| Path::new(&h).join(".config")))
+    };
+    base.map(|b| b.join("killnode"))
+}
+
+// ─── Profiles ─────────────────────────────────────────────────────────────────
+
+/// Returns the file profile `name` is stored in under `dir`.
+pub fn profile_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join("profiles").join(name)
+}
+
+/// Reads the paths and patterns saved in profile `name`. A profile that has
+/// never been saved is an error of kind [`io::ErrorKind::NotFound`].
+pub fn load_profile(dir: &Path, name: &str) -> io::Result<Vec<String>> {
+    read_lines(&profile_path(dir, name))
+}
+
+/// Reads the non-blank, non-comment lines of `file`, trimmed.
+fn read_lines(file: &Path) -> io::Result<Vec<String>> {
+    let text = std::fs::read_to_string(file)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// Saves `paths` as profile `name`, replacing whatever it held before and
+/// creating the config directory if needed.
+pub fn save_profile(dir: &Path, name: &str, paths: &[String]) -> io::Result<()> {
+    let file = profile_path(dir, name);
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut text = format!("# killnode profile '{name}': one path or pattern per line\n");
+    for path in paths {
+        text.push_str(path);
+        text.push('\n');
+    }
+    std::fs::write(file, text)
+}
+
+// ─── Always keep ──────────────────────────────────────────────────────────────
+
+/// Returns the file the always-keep list is stored in under `dir`.
+pub fn keep_path(dir: &Path) -> PathBuf {
+    dir.join("keep")
+}
+
+/// Reads the always-keep list, normalized. A list that has never been saved
+/// is empty rather than an error.
+pub fn load_keep(dir: &Path) -> io::Result<Vec<String>> {
+    match read_lines(&keep_path(dir)) {
+        Ok(lines) => Ok(lines.iter().map(|l| normalize_path(l)).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reads the always-keep list from [`dir`], for a run that has nothing to
+/// say about where the config lives. A list that can't be read keeps
+/// nothing rather than stopping the run.
+pub fn keep_list() -> Vec<String> {
+    dir()
+        .and_then(|dir| load_keep(&dir).ok())
+        .unwrap_or_default()
+}
+
+/// Saves `paths` as the always-keep list, replacing what it held before and
+/// creating the config directory if needed.
+pub fn save_keep(dir: &Path, paths: &[String]) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let mut text = "# killnode: node_modules never to pre-select, one path per line\n".to_string();
+    for path in paths {
+        text.push_str(path);
+        text.push('\n');
+    }
+    std::fs::write(keep_path(dir), text)
+}
+
+/// Puts `path` in the form the always-keep list compares: without trailing
+/// separators, and on Windows with backslashes and in lower case, since
+/// paths there are case-insensitive.
+pub fn normalize_path(path: &str) -> String {
+    let trimmed = match path.trim_end_matches(['/', '\\']) {
+        "" => path,
+        trimmed => trimmed,
+    };
+    if cfg!(windows) {
+        trimmed.replace('/', "\\").to_lowercase()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+// ─── Ignore files ─────────────────────────────────────────────────────────────
+
+/// Reads the patterns in the ignore file at `file`, each tidied by
+/// [`ignore_pattern`].
+pub fn load_ignore_file(file: &Path) -> io::Result<Vec<String>> {
+    Ok(read_lines(file)?.into_iter().map(ignore_pattern).collect())
+}
+
+/// Tidies one ignore pattern, from a file or from `--exclude`. A leading
+/// `~/` is expanded to the home directory and a trailing separator is
+/// dropped, so `~/work/vendor/` is the same as `/home/alice/work/vendor`.
+/// On Windows backslashes become forward slashes, the separator globs use.
+pub fn ignore_pattern(pattern: String) -> String {
+    let pattern = if cfg!(windows) {
+        pattern.replace('\\', "/")
+    } else {
+        pattern
+    };
+    let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE"));
+    let pattern = match (pattern.strip_prefix("~/"), &home) {
+        (Some(rest), Ok(home)) => format!("{}/{rest}", home.trim_end_matches(['/', '\\'])),
+        _ => pattern,
+    };
+    match pattern.trim_end_matches(['/', '\\']) {
+        "" => pattern,
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Compiles ignore-file `patterns` into one set, matched against a
+/// directory's full path.
+///
+/// A pattern with a separator in it must match the whole path. One without
+/// — `vendor`, say, or `*.bak` — stands for the directory's own name, so it
+/// applies at any depth: it is compiled as `**/vendor`.
+///
+/// Returns a message naming the first pattern that isn't a valid glob.
+pub fn ignore_set(patterns: &[String]) -> Result<GlobSet, String> {
+    glob_set(patterns.iter().map(|p| {
+        if p.contains('/') {
+            p.clone()
+        } else {
+            format!("**/{p}")
+        }
+    }))
+}
+
+/// Compiles `--exclude` `patterns` into one set, matched against the full
+/// absolute path of everything the scan comes across.
+///
+/// Returns a message naming the first pattern that isn't a valid glob.
+pub fn exclude_set(patterns: &[String]) -> Result<GlobSet, String> {
+    glob_set(patterns.iter().cloned())
+}
+
+/// Compiles `patterns` as standard globs (see the [module docs](self)):
+/// `*` never crosses a separator, and on Windows case doesn't matter.
+fn glob_set(patterns: impl Iterator<Item = String>) -> Result<GlobSet, String> {
+    let mut set = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .case_insensitive(cfg!(windows))
+            .build()
+            .map_err(|e| format!("'{pattern}' isn't a valid pattern ({})", e.kind()))?;
+        set.add(glob);
+    }
+    set.build().map_err(|e| e.to_string())
+}
+
+// ─── Matching ─────────────────────────────────────────────────────────────────
+
+/// Returns whether `path` matches profile `pattern`.
+///
+/// A pattern without wildcards must equal the path exactly. `*` matches any
+/// run of characters, including none and including path separators, and `?`
+/// matches exactly one character. There is no escaping; `node_modules` paths
+/// containing a literal `*` are rare enough not to warrant it.
+pub fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+
+    // Classic greedy wildcard match with backtracking to the last `*`.
+    let (mut p, mut s) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while s < path.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == path[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                // Let the last `*` swallow one more character and retry.
+                Some((sp, ss)) => {
+                    p = sp + 1;
+                    s = ss + 1;
+                    star = Some((sp, ss + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -8,9 +8,18 @@
 //! [`start_delete`] spawns a thread and returns the receiving end of a channel
 //! immediately. The thread sends a [`DeleteMsg::Progress`] message just before
 //! it starts removing each directory, so the UI can show which path is
-//! currently being deleted. When all paths have been processed it sends a
-//! single [`DeleteMsg::Done`] with the total bytes freed and a list of any
-//! errors that occurred.
+//! currently being deleted, and a [`DeleteMsg::Finished`] once it is done
+//! with it. When all paths have been processed it sends a single
+//! [`DeleteMsg::Done`] with the total bytes freed and a list of any errors
+//! that occurred.
+//!
+//! ## Several at once
+//!
+//! With [`DeleteOptions::jobs`] above 1, the thread starts that many workers
+//! that take paths from a shared queue, so several directories are removed
+//! at the same time. The freed total is shared between them, and their
+//! results are put back in queue order before `Done` is sent, so a parallel
+//! run reports exactly what a sequential one would.
 //!
 //! The main thread drains the channel on every event-loop tick via
 //! [`crate::app::App::process_delete_messages`] without blocking, so the
@@ -18,43 +27,200 @@
 //!
 //! ## Error handling
 //!
-//! Deletion errors are non-fatal. If `remove_dir_all` fails for one path, the
-//! error is recorded and the thread moves on to the next path. This means a
+//! Deletion errors are non-fatal. If removing one path fails, the error is
+//! recorded and the thread moves on to the next path. This means a
 //! permission error on one directory won't prevent the others from being
 //! cleaned up. All errors are collected and shown to the user on the
 //! [`crate::app::Screen::Done`] screen at the end.
+//!
+//! The commonest baffling failure is a `node_modules` that a running dev
+//! server or bundler still has files open in — on Windows especially, where
+//! open files can't be removed. Those errors are recognised (see
+//! [`is_in_use`]) and say so in plain words instead of quoting the raw OS
+//! message.
+//!
+//! ## Skipped paths
+//!
+//! Some [`DeleteOptions`] make the thread refuse to delete a path even though
+//! it was queued (for example, a project with uncommitted git changes). Those
+//! paths are not errors — nothing went wrong — so they are reported
+//! separately in [`DeleteMsg::Done::skipped`].
+//!
+//! ## Cancelling
+//!
+//! [`start_delete`] also returns a stop flag. Once it is set, each worker
+//! finishes the directory it is in the middle of — stopping halfway through
+//! a `remove_dir_all` would leave a broken, half-deleted tree — and then
+//! reports every path it hadn't started as skipped and sends `Done` as
+//! usual.
+//!
+//! ## One deletion at a time
+//!
+//! Two killnode instances scanning overlapping roots could otherwise race
+//! each other through the same directories, each reporting the other's
+//! removals as errors. With [`DeleteOptions::lock`] set, the thread takes an
+//! exclusive advisory lock on that file before removing anything and holds
+//! it until it finishes; if the process dies, the OS releases it. A thread
+//! that finds the lock taken sends [`DeleteMsg::Waiting`] and waits its turn,
+//! or until it is cancelled.
+//!
+//! ## Trash first
+//!
+//! With [`DeleteMode::Trash`], directories are moved to the system trash
+//! instead of being removed, so a mistake can be undone from the file
+//! manager. Nothing is freed until the trash is emptied; [`start_purge`]
+//! empties exactly the items a run trashed and leaves the rest of the trash
+//! alone, and [`start_restore`] puts those same items back where they were.
+//! Both are only possible where the system trash can be listed
+//! ([`CAN_PURGE_TRASH`]) — not on macOS.
 
 use std::{
-    path::Path,
-    sync::mpsc::{self, Receiver, Sender},
+    fs::{File, TryLockError},
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+    time::{Duration, Instant},
 };
 
-use crate::scanner::dir_size;
+use crate::{
+    config, git,
+    scanner::{dir_size, is_reparse_point},
+};
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
+/// Settings that change how the delete thread treats each path.
+#[derive(Clone, Default)]
+pub struct DeleteOptions {
+    /// Skip any path whose enclosing git repository has uncommitted changes
+    /// to tracked files. See [`git::has_uncommitted_changes`].
+    pub skip_dirty_git: bool,
+
+    /// Don't measure directories before removing them, and ignore any size
+    /// passed in with them. `freed` in [`DeleteMsg::Done`] is then always 0,
+    /// meaning "unknown" — the price of never walking a directory's tree.
+    pub skip_sizes: bool,
+
+    /// Measure a directory by the space its files take up on disk rather
+    /// than their length, as [`ScanOptions::disk_usage`] does. Only matters
+    /// for paths passed in without a size.
+    ///
+    /// [`ScanOptions::disk_usage`]: crate::scanner::ScanOptions::disk_usage
+    pub disk_usage: bool,
+
+    /// After removing a directory, also remove any parent the removal left
+    /// empty, walking upwards until a non-empty directory — or the innermost
+    /// of these that holds it, normally the scan roots — is reached. The
+    /// directories given here are never removed themselves, and nothing
+    /// outside them is touched. Empty leaves parents alone.
+    pub prune_empty_parents: Vec<PathBuf>,
+
+    /// Hold an exclusive lock on this file for the whole run, so concurrent
+    /// runs in other processes take turns. Normally [`lock_path`]. `None`
+    /// takes no lock.
+    pub lock: Option<PathBuf>,
+
+    /// Whether directories are removed or moved to the system trash.
+    pub mode: DeleteMode,
+
+    /// How many paths to remove at once, each on its own worker thread.
+    /// 0 and 1 both mean one at a time. More helps most on SSDs and network
+    /// filesystems, where one `remove_dir_all` leaves the disk mostly idle.
+    pub jobs: usize,
+}
+
+/// What the delete thread does with each directory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeleteMode {
+    /// Remove it for good with `remove_dir_all`.
+    #[default]
+    Remove,
+
+    /// Move it to the system trash, so it can be restored from the file
+    /// manager. `freed` still reports its size, measured beforehand, though
+    /// the space only comes back once it is [purged](start_purge). Where
+    /// there is no trash, each path fails with an error saying so. Symlinks
+    /// are unlinked as usual: there is nothing behind them to recover.
+    Trash,
+}
+
+impl DeleteMode {
+    /// The other mode, for the toggle on the confirmation popup.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Remove => Self::Trash,
+            Self::Trash => Self::Remove,
+        }
+    }
+}
+
 /// A message sent from the background delete thread to the main thread.
 pub enum DeleteMsg {
+    /// Sent before anything is removed if another process holds the
+    /// [`DeleteOptions::lock`]. The thread then waits until it is released,
+    /// and the first [`DeleteMsg::Progress`] means it has been. Cancelling
+    /// ends the wait: every path is reported as skipped instead.
+    Waiting,
+
     /// Sent immediately *before* a directory is removed.
     ///
-    /// The enclosed string is the path about to be deleted. Sending this
-    /// before (rather than after) the deletion means the UI always shows
-    /// what the thread is currently working on, even if a particular
-    /// `remove_dir_all` call takes a long time.
-    Progress(String),
+    /// `path` is the path about to be deleted. Sending this before (rather
+    /// than after) the deletion means the UI always shows what the thread is
+    /// currently working on, even if a particular `remove_dir_all` call
+    /// takes a long time.
+    ///
+    /// `freed` is the number of bytes reclaimed so far in this run — by the
+    /// paths already finished, counted the same way as in
+    /// [`DeleteMsg::Done::freed`]. It is always 0 under
+    /// [`DeleteOptions::skip_sizes`].
+    Progress { path: String, freed: u64 },
+
+    /// Sent once a path has been dealt with — removed, failed or skipped by
+    /// a safety rule — so the UI can advance its progress bar. With several
+    /// [`DeleteOptions::jobs`], paths can finish in any order.
+    ///
+    /// `freed` is the number of bytes reclaimed so far in this run,
+    /// including this path.
+    Finished { freed: u64 },
 
     /// Sent once, after all directories have been processed.
     ///
     /// `freed` is the total number of bytes successfully reclaimed.
-    /// `errors` contains a human-readable message for each path that
-    /// could not be deleted (empty if everything succeeded).
-    Done { freed: u64, errors: Vec<String> },
+    /// `deleted` lists every path that was actually removed, so the caller
+    /// can drop exactly those from its list.
+    /// `skipped` contains a human-readable message for each path that was
+    /// deliberately left alone because of a [`DeleteOptions`] safety rule.
+    /// `errors` pairs each path that could not be deleted with a
+    /// human-readable message, which starts with the path (empty if
+    /// everything succeeded). Keeping the path apart lets the caller retry
+    /// exactly the failed paths.
+    /// `timings` records how long removing each path took, in the order
+    /// they were queued — successes and failures alike, but not skipped
+    /// paths, which were never touched. Useful for spotting slow
+    /// directories or filesystems.
+    Done {
+        freed: u64,
+        deleted: Vec<String>,
+        skipped: Vec<String>,
+        errors: Vec<(String, String)>,
+        timings: Vec<(String, Duration)>,
+    },
 }
 
 // ─── Public API ───────────────────────────────────────────────────────────────
 
 /// Spawns a background thread to delete `paths` and returns the receiving end
-/// of the progress channel.
+/// of the progress channel, along with a flag that stops the thread early
+/// when set (see [Cancelling](self#cancelling)).
+///
+/// Each path comes with its size, if already known — normally from the scan
+/// that found it. A known size is what a successful removal adds to `freed`;
+/// only a path given `None` is measured before it is removed, which would
+/// otherwise mean walking every tree twice.
 ///
 /// The caller should hold onto the returned [`Receiver`] and drain it on every
 /// event-loop tick (via [`crate::app::App::process_delete_messages`]) until a
@@ -63,49 +229,481 @@ pub enum DeleteMsg {
 /// Like the scanner, the thread is intentionally detached. If the receiver is
 /// dropped before `Done` arrives the thread will notice the channel is broken
 /// on its next send and exit early.
-pub fn start_delete(paths: Vec<String>) -> Receiver<DeleteMsg> {
+pub fn start_delete(
+    paths: Vec<(String, Option<u64>)>,
+    options: DeleteOptions,
+) -> (Receiver<DeleteMsg>, Arc<AtomicBool>) {
     let (tx, rx) = mpsc::channel::<DeleteMsg>();
-    std::thread::spawn(move || delete_thread(paths, tx));
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_clone = Arc::clone(&stop);
+    std::thread::spawn(move || delete_thread(paths, options, tx, stop_clone));
+    (rx, stop)
+}
+
+/// Returns the lock file deletions take by default: `delete.lock` in the
+/// [`crate::config`] directory, or the system temp directory if there is
+/// none. Every killnode run by the same user agrees on it.
+pub fn lock_path() -> PathBuf {
+    config::dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("delete.lock")
+}
+
+// ─── Locking ──────────────────────────────────────────────────────────────────
+
+/// How long the delete thread sleeps between attempts at a lock another
+/// process holds. Short enough that cancelling feels immediate.
+const LOCK_RETRY: Duration = Duration::from_millis(100);
+
+/// Takes the lock on `path`, sending [`DeleteMsg::Waiting`] first if another
+/// process has it. The lock lasts as long as the returned file is open.
+///
+/// While waiting it tries again every [`LOCK_RETRY`] rather than blocking,
+/// so that setting `stop` ends the wait: it then returns `None`, and the
+/// caller, seeing `stop`, skips every path without touching it.
+///
+/// The lock is a courtesy between killnode processes, not a safety rule: if
+/// the file can't even be created (a read-only config directory, say), the
+/// deletion goes ahead without one rather than not at all.
+fn acquire_lock(path: &Path, tx: &Sender<DeleteMsg>, stop: &AtomicBool) -> Option<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).ok()?;
+    }
+    let file = File::options()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .ok()?;
+    let mut waiting = false;
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Some(file),
+            Err(TryLockError::WouldBlock) => {
+                if !waiting {
+                    tx.send(DeleteMsg::Waiting).ok();
+                    waiting = true;
+                }
+                if stop.load(Ordering::Relaxed) {
+                    return None;
+                }
+                std::thread::sleep(LOCK_RETRY);
+            }
+            Err(TryLockError::Error(_)) => return None,
+        }
+    }
+}
+
+// ─── Removal ──────────────────────────────────────────────────────────────────
+
+/// Removes `path` and returns how many bytes that freed.
+///
+/// A symlink is removed on its own and never followed: `node_modules` is
+/// sometimes a link into a shared package store, and deleting through it
+/// would wipe the store for every project using it. Removing a link frees
+/// nothing, so it reports 0 bytes. The check uses `symlink_metadata` at
+/// delete time rather than trusting the scan, so a directory swapped for a
+/// link in the meantime is still handled safely. On Windows, junctions and
+/// other reparse points get the same treatment: only the reparse point itself
+/// is removed, so a delete can never follow one out of the project.
+///
+/// A real directory is then removed recursively or moved to the trash,
+/// according to `mode`, and `known_size` is returned. If that is `None`, the
+/// directory is measured *before* it is deleted — there is nothing left to
+/// measure after. A size from the scan may be a little out of date by now,
+/// but it is what the user was shown and agreed to free.
+fn remove(
+    path: &Path,
+    known_size: Option<u64>,
+    mode: DeleteMode,
+    disk_usage: bool,
+) -> io::Result<u64> {
+    let meta = std::fs::symlink_metadata(path)?;
+    if meta.file_type().is_symlink() || is_reparse_point(&meta) {
+        // Unix removes any symlink with `remove_file`; Windows directory
+        // symlinks and junctions need `remove_dir`. Neither touches the target.
+        std::fs::remove_file(path).or_else(|_| std::fs::remove_dir(path))?;
+        return Ok(0);
+    }
+
+    let size = known_size.unwrap_or_else(|| dir_size(path, disk_usage));
+    match mode {
+        DeleteMode::Remove => std::fs::remove_dir_all(path)?,
+        DeleteMode::Trash => trash::delete(path)
+            .map_err(|e| io::Error::other(format!("couldn't move to the trash: {e}")))?,
+    }
+    Ok(size)
+}
+
+/// Returns whether `e` means another process has the file open or locked,
+/// so it can't be removed until that process lets go.
+///
+/// On Windows that is a sharing or lock violation. Elsewhere, open files
+/// can be unlinked, so only a busy mount point or running executable comes
+/// close.
+pub fn is_in_use(e: &io::Error) -> bool {
+    // ERROR_SHARING_VIOLATION and ERROR_LOCK_VIOLATION.
+    if cfg!(windows) && matches!(e.raw_os_error(), Some(32 | 33)) {
+        return true;
+    }
+    matches!(
+        e.kind(),
+        io::ErrorKind::ResourceBusy | io::ErrorKind::ExecutableFileBusy
+    )
+}
+
+/// Removes the parents of `path` that are now empty, nearest first, stopping
+/// at the first one that isn't empty, can't be removed, or isn't strictly
+/// inside `stop`.
+///
+/// `remove_dir` only ever removes an empty directory, so even if something
+/// appears in one between the check and the removal, nothing is lost — the
+/// removal just fails and pruning stops there. Failures are not reported:
+/// the `node_modules` itself was deleted, which is what the user asked for.
+fn prune_empty_parents(path: &Path, stop: &Path) {
+    let stop = std::path::absolute(stop).unwrap_or_else(|_| stop.to_path_buf());
+    let mut dir = path.parent();
+    while let Some(d) = dir {
+        if d == stop || !d.starts_with(&stop) {
+            break;
+        }
+        let empty = std::fs::read_dir(d).is_ok_and(|mut entries| entries.next().is_none());
+        if !empty || std::fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
+// ─── Trash ────────────────────────────────────────────────────────────────────
+
+/// Whether [`start_purge`] and [`start_restore`] can work here. The system
+/// trash can be listed, emptied and restored from item by item on Windows
+/// and freedesktop systems, but not on macOS.
+pub const CAN_PURGE_TRASH: bool = cfg!(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+));
+
+/// Spawns a background thread that permanently removes `paths` — which an
+/// earlier run with [`DeleteMode::Trash`] moved to the trash — from the
+/// trash, and returns a receiver for how many items it removed.
+///
+/// Only the most recently trashed item for each path is touched, so an
+/// older copy of the same directory, trashed by hand or by an earlier
+/// session, stays where it is, as does everything else in the trash. An
+/// error is sent as its message.
+pub fn start_purge(paths: Vec<String>) -> Receiver<Result<usize, String>> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        tx.send(purge(&paths).map_err(|e| e.to_string())).ok();
+    });
     rx
 }
 
+/// Spawns a background thread that moves `paths` — which an earlier run
+/// with [`DeleteMode::Trash`] moved to the trash — back where they were,
+/// and returns a receiver for how many items it restored.
+///
+/// The items are picked as [`start_purge`] picks them. Nothing is restored
+/// if something already exists at any of the original paths; the error
+/// sent then names it.
+pub fn start_restore(paths: Vec<String>) -> Receiver<Result<usize, String>> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        tx.send(restore(&paths).map_err(|e| e.to_string())).ok();
+    });
+    rx
+}
+
+#[cfg(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+fn purge(paths: &[String]) -> Result<usize, trash::Error> {
+    let items = newest_trashed(paths)?;
+    let count = items.len();
+    trash::os_limited::purge_all(items)?;
+    Ok(count)
+}
+
+#[cfg(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+fn restore(paths: &[String]) -> Result<usize, trash::Error> {
+    let items = newest_trashed(paths)?;
+    let count = items.len();
+    trash::os_limited::restore_all(items)?;
+    Ok(count)
+}
+
+/// Returns the most recently trashed item for each of `paths` that is in
+/// the trash at all.
+#[cfg(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+fn newest_trashed(paths: &[String]) -> Result<Vec<trash::TrashItem>, trash::Error> {
+    use std::collections::HashMap;
+
+    let mut newest: HashMap<PathBuf, trash::TrashItem> = HashMap::new();
+    for item in trash::os_limited::list()? {
+        let original = item.original_path();
+        if !paths.iter().any(|p| Path::new(p) == original) {
+            continue;
+        }
+        if newest
+            .get(&original)
+            .is_none_or(|kept| kept.time_deleted < item.time_deleted)
+        {
+            newest.insert(original, item);
+        }
+    }
+    Ok(newest.into_values().collect())
+}
+
+#[cfg(not(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+fn purge(_paths: &[String]) -> Result<usize, trash::Error> {
+    Err(trash::into_unknown(
+        "emptying single items from the trash isn't supported on this system",
+    ))
+}
+
+#[cfg(not(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+fn restore(_paths: &[String]) -> Result<usize, trash::Error> {
+    Err(trash::into_unknown(
+        "restoring single items from the trash isn't supported on this system",
+    ))
+}
+
 // ─── Background thread ────────────────────────────────────────────────────────
 
-/// The function that runs on the background delete thread.
+/// What happened to one queued path.
+enum Outcome {
+    /// Removed.
+    Deleted { took: Duration },
+
+    /// Removal was attempted and failed, for this reason.
+    Failed { error: String, took: Duration },
+
+    /// Left alone, for this reason — a safety rule, or a cancel.
+    Skipped(String),
+}
+
+/// The main loop of the background delete thread.
 ///
-/// For each path in `paths`:
+/// First takes the [`DeleteOptions::lock`], if one is set, waiting for
+/// another process to release it if need be. Then starts
+/// [`DeleteOptions::jobs`] workers (at least one, and no more than there are
+/// paths), each taking the next path nobody has started yet and handing it
+/// to [`delete_one`], until none are left. Once it has been set, `stop` makes
+/// each worker record the rest as skipped instead of starting them.
+///
+/// A running total of the bytes freed is shared between the workers, so the
+/// [`DeleteMsg::Progress`] and [`DeleteMsg::Finished`] messages they send
+/// always carry the run's total so far. When every worker has finished, the
+/// outcomes are put back in the order `paths` were given — so `deleted`,
+/// `skipped`, `errors` and `timings` read the same whatever the number of
+/// workers — and sent as a single [`DeleteMsg::Done`].
+fn delete_thread(
+    paths: Vec<(String, Option<u64>)>,
+    options: DeleteOptions,
+    tx: Sender<DeleteMsg>,
+    stop: Arc<AtomicBool>,
+) {
+    // Held until the thread returns, which releases it.
+    let _lock = options
+        .lock
+        .as_deref()
+        .and_then(|p| acquire_lock(p, &tx, &stop));
+
+    let next = AtomicUsize::new(0);
+    let freed = AtomicU64::new(0);
+    let outcomes: Mutex<Vec<Option<Outcome>>> =
+        Mutex::new(std::iter::repeat_with(|| None).take(paths.len()).collect());
+    let workers = options.jobs.clamp(1, paths.len().max(1));
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some((path, size)) = paths.get(i) else {
+                        break;
+                    };
+                    let outcome = if stop.load(Ordering::Relaxed) {
+                        Outcome::Skipped(format!("{path}: cancelled"))
+                    } else {
+                        delete_one(path, *size, &options, &tx, &freed)
+                    };
+                    if let Ok(mut outcomes) = outcomes.lock() {
+                        outcomes[i] = Some(outcome);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut deleted: Vec<String> = Vec::new();
+    let mut skipped: Vec<String> = Vec::new();
+    let mut errors: Vec<(String, String)> = Vec::new();
+    let mut timings: Vec<(String, Duration)> = Vec::new();
+    let outcomes = outcomes.into_inner().unwrap_or_default();
+    for ((path, _), outcome) in paths.iter().zip(outcomes) {
+        match outcome {
+            Some(Outcome::Deleted { took, .. }) => {
+                deleted.push(path.clone());
+                timings.push((path.clone(), took));
+            }
+            Some(Outcome::Failed { error, took }) => {
+                errors.push((path.clone(), error));
+                timings.push((path.clone(), took));
+            }
+            Some(Outcome::Skipped(reason)) => skipped.push(reason),
+            // A worker that panicked leaves its path without an outcome.
+            None => errors.push((path.clone(), format!("{path}: deletion was interrupted"))),
+        }
+    }
+
+    tx.send(DeleteMsg::Done {
+        freed: freed.into_inner(),
+        deleted,
+        skipped,
+        errors,
+        timings,
+    })
+    .ok();
+}
+
+/// Deletes one path for [`delete_thread`], adding what it frees to `freed`:
 ///
 /// 1. Sends [`DeleteMsg::Progress`] so the UI can update the "currently
 ///    removing" label before any blocking I/O begins.
 ///
-/// 2. Measures the directory size *before* deleting it, because once it's
-///    gone there is nothing left to measure. The size is only added to
-///    `freed` if the deletion actually succeeds.
+/// 2. Applies the [`DeleteOptions`] safety rules. A path that fails one is
+///    skipped and left untouched.
 ///
-/// 3. Calls [`std::fs::remove_dir_all`]. On success, adds the size to the
-///    running `freed` total. On failure, appends a human-readable error
-///    string and continues to the next path.
+/// 3. Removes the path via [`remove`], which measures a directory whose
+///    size isn't known yet and only ever unlinks a symlink. The removal is timed
+///    either way. On success, checks that the path is really gone: if it
+///    is, adds the freed size to `freed`, then prunes any parents left
+///    empty if [`DeleteOptions::prune_empty_parents`] is set. If it is still
+///    there, or the removal failed, the outcome is a human-readable error —
+///    one that says so when the path is [in use](is_in_use).
 ///
-/// After all paths are processed, sends [`DeleteMsg::Done`] with the final
-/// totals.
-fn delete_thread(paths: Vec<String>, tx: Sender<DeleteMsg>) {
-    let mut freed: u64 = 0;
-    let mut errors: Vec<String> = Vec::new();
+/// 4. Sends [`DeleteMsg::Finished`], whatever happened.
+fn delete_one(
+    path: &str,
+    size: Option<u64>,
+    options: &DeleteOptions,
+    tx: &Sender<DeleteMsg>,
+    freed: &AtomicU64,
+) -> Outcome {
+    // Notify the UI first so it shows this path while the deletion runs.
+    tx.send(DeleteMsg::Progress {
+        path: path.to_string(),
+        freed: freed.load(Ordering::Relaxed),
+    })
+    .ok();
+
+    let outcome = remove_checked(path, size, options, freed);
+    tx.send(DeleteMsg::Finished {
+        freed: freed.load(Ordering::Relaxed),
+    })
+    .ok();
+    outcome
+}
 
-    for path in &paths {
-        // Notify the UI first so it shows this path while the deletion runs.
-        tx.send(DeleteMsg::Progress(path.clone())).ok();
+/// Steps 2 and 3 of [`delete_one`].
+fn remove_checked(
+    path: &str,
+    size: Option<u64>,
+    options: &DeleteOptions,
+    freed: &AtomicU64,
+) -> Outcome {
+    let p = Path::new(path);
 
-        let p = Path::new(path);
+    // Check the project that owns this node_modules, not node_modules
+    // itself (which is almost always git-ignored).
+    if options.skip_dirty_git && p.parent().is_some_and(git::has_uncommitted_changes) {
+        return Outcome::Skipped(format!("{path}: uncommitted git changes"));
+    }
 
-        // Measure before deleting — there will be nothing to measure after.
-        let size = dir_size(p);
+    let started = Instant::now();
+    let size = if options.skip_sizes { Some(0) } else { size };
+    let result = remove(p, size, options.mode, options.disk_usage);
+    let took = started.elapsed();
 
-        match std::fs::remove_dir_all(p) {
-            Ok(_) => freed += size,
-            Err(e) => errors.push(format!("{path}: {e}")),
+    match result {
+        // `remove` succeeding isn't always the end of it: antivirus and
+        // sync clients can put a directory straight back, or hold on to
+        // part of it. Only a path that is really gone counts as deleted.
+        Ok(_) if std::fs::symlink_metadata(p).is_ok() => Outcome::Failed {
+            error: format!(
+                "{path}: removal did not persist (the directory is still there; \
+                 antivirus or sync software may have restored it)"
+            ),
+            took,
+        },
+        Ok(size) => {
+            freed.fetch_add(size, Ordering::Relaxed);
+            let stop = options
+                .prune_empty_parents
+                .iter()
+                .filter(|stop| p.starts_with(stop))
+                .max_by_key(|stop| stop.components().count());
+            if let Some(stop) = stop {
+                prune_empty_parents(p, stop);
+            }
+            Outcome::Deleted { took }
         }
+        Err(e) if is_in_use(&e) => Outcome::Failed {
+            error: format!(
+                "{path}: in use by another process (stop any dev server or \
+                 bundler using it and try again)"
+            ),
+            took,
+        },
+        Err(e) => Outcome::Failed {
+            error: format!("{path}: {e}"),
+            took,
+        },
     }
-
-    tx.send(DeleteMsg::Done { freed, errors }).ok();
 }
@@ -0,0 +1,84 @@
+//! # Disk space
+//!
+//! How large, and how full, the filesystems holding the scan roots are. The
+//! welcome screen shows how much room there is before anything happens, and
+//! the done screen how much more there is afterwards — the figure that
+//! actually matters when a disk has filled up.
+//!
+//! ## Several roots
+//!
+//! Roots are often on the same filesystem, and adding up what each one
+//! reports would count that filesystem's space once per root. Each
+//! filesystem is counted once instead: on Unix they are told apart by
+//! device number, elsewhere by the drive or share a path starts with. A
+//! root that can't be looked up — missing, or on an unusual mount — is left
+//! out rather than spoiling the total for the rest.
+//!
+//! A root can have other filesystems mounted somewhere underneath it. Those
+//! are not counted: only the filesystem the root itself is on is known
+//! without walking the tree.
+
+use std::{collections::HashSet, io, path::Path};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Space on the filesystems holding a set of roots, each filesystem counted
+/// once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskSpace {
+    /// Bytes an ordinary user can still write.
+    pub available: u64,
+
+    /// Total size, in bytes.
+    pub total: u64,
+
+    /// How many distinct filesystems the figures add up.
+    pub filesystems: usize,
+}
+
+// ─── Lookup ───────────────────────────────────────────────────────────────────
+
+/// Returns the space on the filesystems holding `roots`, or `None` if not a
+/// single one could be looked up.
+pub fn of_roots(roots: &[String]) -> Option<DiskSpace> {
+    let mut seen = HashSet::new();
+    let mut space = DiskSpace::default();
+    for root in roots {
+        let Ok(key) = filesystem_key(Path::new(root)) else {
+            continue;
+        };
+        if seen.contains(&key) {
+            continue;
+        }
+        let Ok(stats) = fs4::statvfs(root) else {
+            continue;
+        };
+        seen.insert(key);
+        space.available += stats.available_space();
+        space.total += stats.total_space();
+        space.filesystems += 1;
+    }
+    (space.filesystems > 0).then_some(space)
+}
+
+/// Returns something that is the same for two paths on the same filesystem
+/// and different otherwise: the device number on Unix.
+#[cfg(unix)]
+fn filesystem_key(path: &Path) -> io::Result<String> {
+    use std::os::unix::fs::MetadataExt;
+    Ok(std::fs::metadata(path)?.dev().to_string())
+}
+
+/// Returns something that is the same for two paths on the same filesystem
+/// and different otherwise: the drive or share the path starts on. A volume
+/// mounted in a folder shares its parent drive's key, and is missed.
+#[cfg(not(unix))]
+fn filesystem_key(path: &Path) -> io::Result<String> {
+    let path = std::path::absolute(path)?;
+    std::fs::metadata(&path)?;
+    Ok(path
+        .components()
+        .next()
+        .map(|c| c.as_os_str().to_string_lossy().to_lowercase())
+        .unwrap_or_default())
+}
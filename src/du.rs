@@ -0,0 +1,54 @@
+//! # `du`-style output
+//!
+//! With `--du-format`, killnode doesn't start the TUI. It scans and prints
+//! one line per `node_modules` in the format `du` uses — the size, a tab,
+//! then the path — so it can stand in for `du` at the start of an existing
+//! pipeline:
+//!
+//! ```text
+//! $ killnode --du-format ~/projects | sort -rn | head
+//! 412872  /home/alice/projects/app/node_modules
+//! 98304   /home/alice/projects/site/node_modules
+//! ```
+//!
+//! Sizes are in [`DuUnit`]s, chosen with `--du-unit` (kibibytes by default,
+//! like `du -k`). As with `du`, a size is rounded *up* to a whole unit, so
+//! only an empty directory shows as 0. Every size is 0 under `--no-size`.
+
+use crate::app::NodeModuleEntry;
+
+// ─── Units ────────────────────────────────────────────────────────────────────
+
+/// The unit `--du-format` sizes are printed in. Set by `--du-unit`; the
+/// letters match `du`'s `-B` suffixes, and like `du` they are powers of 1024.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuUnit {
+    /// Bytes (`B`), like `du -b`.
+    Bytes,
+    /// Kibibytes (`K`), like `du -k`.
+    #[default]
+    Kibibytes,
+    /// Mebibytes (`M`), like `du -m`.
+    Mebibytes,
+    /// Gibibytes (`G`), like `du -BG`.
+    Gibibytes,
+}
+
+impl DuUnit {
+    /// Returns how many bytes one unit is.
+    pub fn bytes(self) -> u64 {
+        match self {
+            DuUnit::Bytes => 1,
+            DuUnit::Kibibytes => 1 << 10,
+            DuUnit::Mebibytes => 1 << 20,
+            DuUnit::Gibibytes => 1 << 30,
+        }
+    }
+}
+
+// ─── Lines ────────────────────────────────────────────────────────────────────
+
+/// The `du`-style line for `entry`, without a trailing newline.
+pub fn line(entry: &NodeModuleEntry, unit: DuUnit) -> String {
+    format!("{}\t{}", entry.size.div_ceil(unit.bytes()), entry.path)
+}
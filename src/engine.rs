@@ -0,0 +1,220 @@
+//! # Headless engine
+//!
+//! The same scan → delete pipeline the TUI runs, but blocking and without a
+//! terminal. [`Engine`] drives the regular background [`crate::scanner`] and
+//! [`crate::deleter`] threads and simply waits on their channels instead of
+//! draining them once per frame.
+//!
+//! This is what non-interactive modes build on, and it is what the
+//! integration tests in `tests/` use to check discovery, sizes, and deletion
+//! against a real directory tree.
+//!
+//! ## Typical use
+//!
+//! ```no_run
+//! use killnode::engine::Engine;
+//!
+//! let engine = Engine::new("/home/alice/projects");
+//! let entries = engine.scan();
+//! let paths = entries
+//!     .iter()
+//!     .filter(|e| e.selected)
+//!     .map(|e| e.path.clone())
+//!     .collect();
+//! let report = engine.delete(paths);
+//! println!("freed {} bytes", report.freed);
+//! ```
+//!
+//! [`Engine::scan_with_progress`] and [`Engine::delete_with_progress`] do the
+//! same while reporting each event as it happens, which is what
+//! `--progress-json` streams to stdout (see [`crate::progress`]).
+
+use std::time::Duration;
+
+use crate::{
+    app::{NodeModuleEntry, sort_entries},
+    cli::Options,
+    config,
+    deleter::{DeleteMsg, DeleteOptions, start_delete},
+    scanner::{ScanMsg, ScanOptions, start_scan},
+};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Runs scans and deletions rooted at one or more directories, blocking
+/// until each one finishes.
+pub struct Engine {
+    /// The directories every scan walks, in order.
+    roots: Vec<String>,
+
+    /// Settings passed to the scan thread by [`Engine::scan`].
+    scan_options: ScanOptions,
+
+    /// Settings passed to the delete thread by [`Engine::delete`].
+    delete_options: DeleteOptions,
+}
+
+/// The outcome of [`Engine::delete`]. Mirrors the final
+/// [`DeleteMsg::Done`] message the delete thread sends.
+#[derive(Default)]
+pub struct DeleteReport {
+    /// Total bytes reclaimed by the directories that were removed.
+    pub freed: u64,
+
+    /// Every path that was actually removed.
+    pub deleted: Vec<String>,
+
+    /// A human-readable message for each path left alone because of a
+    /// [`DeleteOptions`] safety rule.
+    pub skipped: Vec<String>,
+
+    /// A human-readable message for each path that could not be removed.
+    pub errors: Vec<String>,
+
+    /// How long each removal took, in the order the paths were attempted.
+    /// Covers removed and failed paths; skipped ones were never touched.
+    pub timings: Vec<(String, Duration)>,
+}
+
+// ─── Public API ───────────────────────────────────────────────────────────────
+
+impl Engine {
+    /// Creates an engine that scans `root`.
+    pub fn new(root: impl Into<String>) -> Self {
+        Self::with_roots(vec![root.into()])
+    }
+
+    /// Creates an engine that scans every one of `roots` into a single
+    /// list, as when several directories are given on the command line.
+    pub fn with_roots(roots: Vec<String>) -> Self {
+        Self {
+            roots,
+            scan_options: ScanOptions::default(),
+            delete_options: DeleteOptions::default(),
+        }
+    }
+
+    /// Creates an engine configured from the command line: the same roots,
+    /// scan options and delete options the TUI would use. That includes the
+    /// always-keep list in the config directory, so what the user pinned
+    /// is reported as [`SensitiveReason::Kept`](crate::scanner::SensitiveReason::Kept)
+    /// here too.
+    pub fn from_options(options: &Options) -> Self {
+        let roots = options.scan_roots();
+        let delete_options = options.delete_options(&roots);
+        let mut scan_options = options.scan_options();
+        scan_options.keep = config::keep_list();
+        Self::with_roots(roots)
+            .with_scan_options(scan_options)
+            .with_delete_options(delete_options)
+    }
+
+    /// Sets the options every subsequent [`Engine::scan`] runs with.
+    pub fn with_scan_options(mut self, options: ScanOptions) -> Self {
+        self.scan_options = options;
+        self
+    }
+
+    /// Sets the options every subsequent [`Engine::delete`] runs with.
+    pub fn with_delete_options(mut self, options: DeleteOptions) -> Self {
+        self.delete_options = options;
+        self
+    }
+
+    /// Returns the directories this engine scans.
+    pub fn roots(&self) -> &[String] {
+        &self.roots
+    }
+
+    /// Scans the roots and returns every `node_modules` found, sorted
+    /// largest-first with the usual pre-selection applied (safe entries
+    /// selected, sensitive ones not).
+    ///
+    /// Blocks until the scan thread reports [`ScanMsg::Done`]. If the thread
+    /// dies early, whatever was found up to that point is returned. With
+    /// [`ScanOptions::max_results`] set, at most that many entries come back
+    /// that [`ScanOptions::lists`] passes, besides any it doesn't.
+    pub fn scan(&self) -> Vec<NodeModuleEntry> {
+        self.scan_with_progress(|_| {})
+    }
+
+    /// Like [`Engine::scan`], but calls `on_found` with each entry as soon as
+    /// the scan thread reports it — in discovery order, before sorting.
+    pub fn scan_with_progress(
+        &self,
+        mut on_found: impl FnMut(&NodeModuleEntry),
+    ) -> Vec<NodeModuleEntry> {
+        let scan = start_scan(self.roots.clone(), self.scan_options.clone());
+        let mut entries = Vec::new();
+
+        for msg in scan.rx.iter() {
+            match msg {
+                ScanMsg::Found {
+                    path,
+                    size,
+                    sensitive,
+                    last_modified,
+                    symlink,
+                } => {
+                    let entry = NodeModuleEntry::new(path, size, sensitive, last_modified, symlink);
+                    on_found(&entry);
+                    entries.push(entry);
+                }
+                ScanMsg::Done { .. } => break,
+            }
+        }
+
+        sort_entries(&mut entries);
+        entries
+    }
+
+    /// Deletes `paths` and returns what happened.
+    ///
+    /// Blocks until the delete thread reports [`DeleteMsg::Done`]. Progress
+    /// messages are ignored. If the thread dies before finishing, an empty
+    /// report is returned.
+    ///
+    /// Only paths are given, so each directory is measured just before it
+    /// is removed (unless sizes are skipped).
+    pub fn delete(&self, paths: Vec<String>) -> DeleteReport {
+        self.delete_with_progress(paths, |_, _, _| {})
+    }
+
+    /// Like [`Engine::delete`], but calls `on_progress(index, total, path)`
+    /// for each [`DeleteMsg::Progress`], just before that path is removed.
+    /// `index` counts from 0; `total` is the number of paths passed in.
+    pub fn delete_with_progress(
+        &self,
+        paths: Vec<String>,
+        mut on_progress: impl FnMut(usize, usize, &str),
+    ) -> DeleteReport {
+        let total = paths.len();
+        let paths = paths.into_iter().map(|p| (p, None)).collect();
+        let (rx, _) = start_delete(paths, self.delete_options.clone());
+        let mut index = 0;
+
+        for msg in rx.iter() {
+            if let DeleteMsg::Progress { path, .. } = &msg {
+                on_progress(index, total, path);
+                index += 1;
+            } else if let DeleteMsg::Done {
+                freed,
+                deleted,
+                skipped,
+                errors,
+                timings,
+            } = msg
+            {
+                return DeleteReport {
+                    freed,
+                    deleted,
+                    skipped,
+                    errors: errors.into_iter().map(|(_, message)| message).collect(),
+                    timings,
+                };
+            }
+        }
+
+        DeleteReport::default()
+    }
+}
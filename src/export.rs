@@ -0,0 +1,157 @@
+//! # JSON export
+//!
+//! With `--json FILE`, killnode doesn't start the TUI. It scans, writes what
+//! it found to `FILE` as one pretty-printed JSON array, and exits — a
+//! snapshot for a script or a report to pick up later:
+//!
+//! ```text
+//! $ killnode --json found.json ~/projects
+//! $ cat found.json
+//! [
+//!   {
+//!     "last_modified": 1760000000,
+//!     "path": "/home/alice/projects/app/node_modules",
+//!     "sensitive": false,
+//!     "size": 412872301
+//!   }
+//! ]
+//! ```
+//!
+//! Entries come largest-first, as the list starts out, and each object's
+//! keys are in alphabetical order. `size` is in bytes and is 0 for every
+//! entry under `--no-size`. `last_modified` is in seconds since the Unix
+//! epoch, or `null` if the system didn't say.
+//!
+//! Unlike [`crate::progress`], this is a summary written once the scan is
+//! over, not a stream.
+//!
+//! ## Applying an export
+//!
+//! `--apply FILE` reads such a file back — usually trimmed by hand or by a
+//! script to the entries worth deleting — and deletes every path in it
+//! without starting the TUI, for cron-style cleanup:
+//!
+//! ```text
+//! $ killnode --apply found.json
+//! Deleted 3 directories, freed 1.20 GB
+//! ```
+//!
+//! Only `path` is read from each object; the sizes are stale by then, so
+//! each directory is measured again just before it goes. A path is treated
+//! as sensitive if the file says so *or* it looks sensitive now (see
+//! [`crate::scanner::sensitive_reason`]) *or* it is on the always-keep list
+//! now (see [`crate::config`]), and sensitive paths are left alone unless
+//! `--force` is given too. The process exits with status 1 if any
+//! deletion failed.
+
+use serde_json::{Value, json};
+
+use crate::{
+    app::NodeModuleEntry,
+    config,
+    engine::DeleteReport,
+    scanner::sensitive_reason,
+    ui::{count_noun, format_bytes, format_count},
+};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// One path read back from an export by [`from_json`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Exported {
+    pub path: String,
+
+    /// Whether the export marked it sensitive, or it looks sensitive or is
+    /// on the always-keep list now.
+    pub sensitive: bool,
+}
+
+// ─── Output ───────────────────────────────────────────────────────────────────
+
+/// The export of `entries`, in the order given, with a trailing newline.
+pub fn to_json(entries: &[NodeModuleEntry]) -> String {
+    let array: Vec<Value> = entries
+        .iter()
+        .map(|e| {
+            json!({
+                "path": e.path,
+                "size": e.size,
+                "sensitive": e.sensitive.is_some(),
+                "last_modified": e.last_modified,
+            })
+        })
+        .collect();
+    let mut text = serde_json::to_string_pretty(&array).unwrap_or_else(|_| "[]".to_string());
+    text.push('\n');
+    text
+}
+
+// ─── Input ────────────────────────────────────────────────────────────────────
+
+/// Reads back an export written by [`to_json`], in its order. Objects need
+/// an absolute `path` — as every export has, and so nothing depends on the
+/// directory `--apply` happens to run in; everything else in them is
+/// optional and ignored apart from `sensitive`.
+///
+/// `keep` is the always-keep list as it is now, normalized as
+/// [`config::load_keep`] returns it; a path on it counts as sensitive even
+/// if it was pinned after the export was written.
+///
+/// Returns a message naming the problem if `text` isn't such an array.
+pub fn from_json(text: &str, keep: &[String]) -> Result<Vec<Exported>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| format!("not valid JSON ({e})"))?;
+    let Value::Array(items) = value else {
+        return Err("expected a JSON array of objects, as --json writes".to_string());
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let path = item
+                .get("path")
+                .and_then(Value::as_str)
+                .filter(|p| !p.is_empty())
+                .ok_or_else(|| format!("entry {} has no \"path\"", i + 1))?;
+            if !std::path::Path::new(path).is_absolute() {
+                return Err(format!("entry {}'s path '{path}' isn't absolute", i + 1));
+            }
+            let marked = item.get("sensitive").and_then(Value::as_bool) == Some(true);
+            let kept = keep.contains(&config::normalize_path(path));
+            Ok(Exported {
+                path: path.to_string(),
+                sensitive: marked || kept || sensitive_reason(path).is_some(),
+            })
+        })
+        .collect()
+}
+
+// ─── Summary ──────────────────────────────────────────────────────────────────
+
+/// The one-line summary `--apply` prints once `report` is in. `held_back`
+/// is how many sensitive paths were left alone for want of `--force`, and
+/// `sizes` is false under `--no-size`.
+pub fn apply_summary(report: &DeleteReport, held_back: usize, sizes: bool) -> String {
+    let mut line = format!(
+        "Deleted {}",
+        count_noun(report.deleted.len(), "directory", "directories")
+    );
+    if sizes {
+        line.push_str(&format!(", freed {}", format_bytes(report.freed)));
+    }
+    if !report.skipped.is_empty() {
+        line.push_str(&format!("; {} skipped", format_count(report.skipped.len())));
+    }
+    if held_back > 0 {
+        line.push_str(&format!(
+            "; {} left alone (needs --force)",
+            count_noun(held_back, "sensitive path", "sensitive paths")
+        ));
+    }
+    if !report.errors.is_empty() {
+        line.push_str(&format!(
+            "; {}",
+            count_noun(report.errors.len(), "error", "errors")
+        ));
+    }
+    line
+}
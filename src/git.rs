@@ -0,0 +1,34 @@
+//! # Git working-tree checks
+//!
+//! Used by the `--exclude-git-repos-with-uncommitted-changes` safety mode to
+//! leave a project's `node_modules` alone while the project has work in
+//! flight.
+//!
+//! The check shells out to the `git` binary rather than reading the index
+//! directly. It only runs for directories that are about to be deleted, so
+//! the cost of a process per directory is small next to the deletion itself.
+
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
+
+/// Returns `true` if `dir` is inside a git repository whose tracked files
+/// have uncommitted changes (staged or not).
+///
+/// Untracked files are ignored — almost every project has some, and they are
+/// not "work in flight" in the sense that matters here.
+///
+/// Anything that stops the check from running — `dir` not being in a
+/// repository, `git` not being installed — counts as "no changes", since
+/// there is no work for git to protect in that case.
+pub fn has_uncommitted_changes(dir: &Path) -> bool {
+    Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .is_ok_and(|out| out.status.success() && !out.stdout.is_empty())
+}
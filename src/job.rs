@@ -0,0 +1,158 @@
+//! # Detached deletion jobs
+//!
+//! Deleting terabytes can take a long time, and there is no reason to keep a
+//! terminal open for it. With `--detach`, confirming a deletion hands the work
+//! to a separate killnode process and the TUI exits straight away:
+//!
+//! 1. [`write_plan`] writes the queued paths to a *plan file* in the `jobs`
+//!    folder of the [`crate::config`] directory (or the system temp
+//!    directory if there is none), one path per line.
+//!
+//! 2. [`spawn_worker`] starts `killnode --run-plan PLAN` with the same
+//!    safety options and scan root, in its own process group and with no
+//!    terminal attached, so closing the terminal doesn't stop it.
+//!
+//! 3. The worker runs [`run_plan`]: it deletes the paths with the usual
+//!    [`Engine`], writes a *report* next to the plan (same name, `.report`
+//!    extension), and removes the plan.
+//!
+//! The report is plain text: a `freed` line, then one `deleted`, `skipped`
+//! or `error` line per path.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{cli::Options, config, deleter::DeleteMode, engine::Engine};
+
+// ─── Plans ────────────────────────────────────────────────────────────────────
+
+/// Returns the folder plan and report files are written to.
+pub fn jobs_dir() -> PathBuf {
+    config::dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("jobs")
+}
+
+/// Writes `paths` to a new plan file in `dir` and returns its path. The name
+/// includes the time and process id, so two jobs never share a plan.
+pub fn write_plan(dir: &Path, paths: &[String]) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let plan = dir.join(format!("delete-{secs}-{}.plan", std::process::id()));
+    let mut text = String::from("# killnode delete plan: one path per line\n");
+    for path in paths {
+        text.push_str(path);
+        text.push('\n');
+    }
+    std::fs::write(&plan, text)?;
+    Ok(plan)
+}
+
+/// Returns where the report for `plan` is written.
+pub fn report_path(plan: &Path) -> PathBuf {
+    plan.with_extension("report")
+}
+
+// ─── Worker ───────────────────────────────────────────────────────────────────
+
+/// Returns the arguments that make a worker run `plan` under `options`: the
+/// options that change how paths are deleted, and `scan_roots` so
+/// `--prune-empty-parents` stops in the same places.
+pub fn worker_args(plan: &Path, options: &Options, scan_roots: &[String]) -> Vec<String> {
+    let mut args = vec!["--run-plan".to_string(), plan.to_string_lossy().to_string()];
+    if options.exclude_dirty_git {
+        args.push("--exclude-git-repos-with-uncommitted-changes".to_string());
+    }
+    if options.no_size {
+        args.push("--no-size".to_string());
+    }
+    if options.disk_usage {
+        args.push("--disk-usage".to_string());
+    }
+    if options.prune_empty_parents {
+        args.push("--prune-empty-parents".to_string());
+    }
+    if options.delete_mode == DeleteMode::Trash {
+        args.push("--trash".to_string());
+    }
+    if let Some(jobs) = options.jobs {
+        args.extend(["--jobs".to_string(), jobs.to_string()]);
+    }
+    args.push("--".to_string());
+    args.extend(scan_roots.iter().cloned());
+    args
+}
+
+/// Starts a killnode process running `plan` in the background and returns
+/// without waiting for it.
+///
+/// The worker gets no stdin, stdout or stderr — everything it has to say
+/// goes in the report — and runs in its own process group (a detached
+/// process on Windows), so it outlives both this process and the terminal.
+pub fn spawn_worker(plan: &Path, options: &Options, scan_roots: &[String]) -> io::Result<()> {
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .args(worker_args(plan, options, scan_roots))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const DETACHED_PROCESS: u32 = 0x0000_0008;
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+        command.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+    }
+
+    command.spawn().map(|_| ())
+}
+
+/// Deletes every path in `plan` under `options`, blocking until done, then
+/// writes the report and removes the plan. Returns the report's path.
+///
+/// This is what `--run-plan` does. Blank lines and `#` comments in the plan
+/// are ignored.
+pub fn run_plan(plan: &Path, options: &Options) -> io::Result<PathBuf> {
+    let paths: Vec<String> = std::fs::read_to_string(plan)?
+        .lines()
+        // Not trimmed: a path could really end in a space, and deleting a
+        // neighbour by accident is the one thing a plan must never do.
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(String::from)
+        .collect();
+
+    let report = Engine::from_options(options).delete(paths);
+
+    let mut text = format!("# killnode delete report for {}\n", plan.display());
+    if options.no_size {
+        text.push_str("freed unknown (--no-size)\n");
+    } else {
+        text.push_str(&format!("freed {}\n", report.freed));
+    }
+    for path in &report.deleted {
+        text.push_str(&format!("deleted {path}\n"));
+    }
+    for message in &report.skipped {
+        text.push_str(&format!("skipped {message}\n"));
+    }
+    for message in &report.errors {
+        text.push_str(&format!("error {message}\n"));
+    }
+
+    let report_file = report_path(plan);
+    std::fs::write(&report_file, text)?;
+    std::fs::remove_file(plan)?;
+    Ok(report_file)
+}
@@ -0,0 +1,41 @@
+//! # killnode
+//!
+//! The library half of killnode. The binary in `main.rs` owns the terminal
+//! and the event loop; everything it drives lives here so it can also be
+//! exercised without a terminal.
+//!
+//! | Module | Responsibility |
+//! |--------|----------------|
+//! | [`app`] | All runtime state for the TUI and the screen state machine |
+//! | [`cli`] | Command-line flag parsing |
+//! | [`config`] | State saved between runs, such as selection profiles |
+//! | [`preselect`] | `--preselect` rules for which entries start out selected |
+//! | [`scanner`] | Background walk that finds `node_modules` directories |
+//! | [`deleter`] | Background removal of the selected directories |
+//! | [`diskspace`] | Free and total space on the filesystems being cleaned |
+//! | [`engine`] | Blocking scan → delete pipeline with no terminal attached |
+//! | [`du`] | `du`-style size lines for `--du-format` |
+//! | [`stats`] | Headline count and size totals for `--stats-only` |
+//! | [`export`] | The JSON array `--json` writes |
+//! | [`progress`] | JSON-lines progress events for `--progress-json` |
+//! | [`job`] | Deletions handed to a background process with `--detach` |
+//! | [`git`] | Checks for uncommitted work in a project's repository |
+//! | [`project`] | The project each entry belongs to, for grouping the list |
+//! | [`ui`] | Rendering of every screen |
+
+pub mod app;
+pub mod cli;
+pub mod config;
+pub mod deleter;
+pub mod diskspace;
+pub mod du;
+pub mod engine;
+pub mod export;
+pub mod git;
+pub mod job;
+pub mod preselect;
+pub mod progress;
+pub mod project;
+pub mod scanner;
+pub mod stats;
+pub mod ui;
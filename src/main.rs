@@ -2,11 +2,20 @@
 //!
 //! This is where the program starts. It does three things in order:
 //!
-//! 1. **Handle CLI flags** (`--help`, `--version`) without touching the terminal,
-//!    so they work correctly when piped or redirected.
+//! 1. **Parse CLI flags** via [`killnode::cli`] and handle `--help` and
+//!    `--version` without touching the terminal, so they work correctly when
+//!    piped or redirected.
+//!
+//!    `--progress-json`, `--du-format`, `--stats-only`, `--json`, `--apply`
+//!    and `--run-plan` also stop here: they run headlessly
+//!    ([`run_progress_json`], [`run_du_format`], [`run_stats_only`],
+//!    [`run_json`], [`run_apply`], [`killnode::job::run_plan`]) and never
+//!    touch the terminal either. The parser allows only one of them, except
+//!    that `--apply` streams its deletion when given `--progress-json`.
 //!
 //! 2. **Set up the terminal** for full-screen TUI mode — raw input, alternate
-//!    screen buffer, hidden cursor.
+//!    screen buffer, hidden cursor, and the user's window title saved so
+//!    killnode can show its own.
 //!
 //! 3. **Run the event loop** ([`run`]), then unconditionally restore the terminal
 //!    to its original state when the loop exits (whether normally or via panic).
@@ -17,48 +26,48 @@
 //! main()
 //!  └─ run(terminal)
 //!      └─ loop:
-//!          ├─ drain background thread messages  (scan / delete progress)
+//!          ├─ drain background thread messages  (scan / delete / refresh / breakdown / trash / open)
+//!          ├─ update the window title if it changed
 //!          ├─ draw the current screen
 //!          ├─ wait up to 80 ms for a keypress
 //!          └─ dispatch key → App method → possibly change App::screen
+//!                (Esc is dispatched first, to App::go_back, on every screen)
 //! ```
 //!
 //! The 80 ms poll timeout keeps the spinner animation smooth even when the
 //! user isn't pressing anything.
-
-mod app;
-mod deleter;
-mod scanner;
-mod ui;
+//!
+//! Everything other than the terminal handling lives in the `killnode`
+//! library crate (`lib.rs`), so it can be driven headlessly via
+//! [`killnode::engine`].
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
-const HELP: &str = "\
-killnode — find and delete node_modules directories
-
-USAGE:
-    killnode [OPTIONS] [DIRECTORY]
-
-ARGS:
-    [DIRECTORY]    Directory to scan (defaults to current directory)
-
-OPTIONS:
-    -h, --help       Print this help message
-    -V, --version    Print version information
-";
 
 use std::{
-    io::{self, Stdout},
+    fmt,
+    io::{self, IsTerminal, Stdout, Write},
     time::Duration,
 };
 
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+    terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode,
+    },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 
-use app::{App, Screen};
+use killnode::{
+    app::{App, NodeModuleEntry, Screen},
+    cli::{self, Command, HELP, Options, now_secs},
+    config, du,
+    engine::Engine,
+    export, job, progress, stats, ui,
+};
 
 /// The main event loop.
 ///
@@ -72,29 +81,60 @@ use app::{App, Screen};
 /// 3. **Tick** the animation counter so spinners and other time-based
 ///    visuals advance at a steady pace.
 ///
-/// 4. **Poll for input** for up to 80 ms. If a key arrives, dispatch it
-///    to the appropriate handler for the active [`Screen`]. If no key
-///    arrives within the timeout, loop again (this keeps the UI alive
-///    during scanning/deleting even if the user is idle).
+/// 4. **Poll for input** for up to 80 ms. If a key or mouse event arrives,
+///    dispatch it to the appropriate handler for the active [`Screen`]. If
+///    nothing arrives within the timeout, loop again (this keeps the UI
+///    alive during scanning/deleting even if the user is idle).
 ///
-/// Returns `Ok(())` when the user quits, or bubbles up any I/O error.
-fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Result<()> {
-    let mut app = App::new();
+/// Returns `Ok` when the user quits, carrying a message to print once the
+/// terminal is back to normal, if there is one. Bubbles up any I/O error.
+fn run(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    options: Options,
+) -> io::Result<Option<String>> {
+    let mut app = App::new(options);
+    let mut title = String::new();
 
     loop {
         app.process_scan_messages();
         app.process_delete_messages();
+        app.process_refresh_messages();
+        app.process_breakdown_messages();
+        app.process_purge_messages();
+        app.process_restore_messages();
+        app.process_countdown();
+        app.process_open_messages();
+
+        // Only sent when it changes; most ticks it doesn't.
+        let new_title = ui::window_title(&app);
+        if new_title != title {
+            execute!(terminal.backend_mut(), SetTitle(&new_title))?;
+            title = new_title;
+        }
+
+        // A deletion handed off with `--detach` is the end of the session.
+        if let Some(report) = &app.detached {
+            return Ok(Some(format!(
+                "killnode: deleting in the background; the report will be written to {}",
+                report.display()
+            )));
+        }
 
         terminal.draw(|f| ui::ui(f, &mut app))?;
 
-        app.ticker = app.ticker.wrapping_add(1);
+        app.tick();
 
         if !event::poll(Duration::from_millis(80))? {
             continue;
         }
 
-        let Event::Key(key) = event::read()? else {
-            continue;
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Mouse(mouse) => {
+                handle_mouse(&mut app, mouse);
+                continue;
+            }
+            _ => continue,
         };
 
         // Ignore key-release and key-repeat events — only act on key-down.
@@ -102,56 +142,374 @@ fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Result<()> {
             continue;
         }
 
+        // Ctrl-C / Ctrl-Q twice in a row force-quits from anywhere, even
+        // mid-delete. Any other key disarms it.
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        if ctrl && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('q')) {
+            if app.press_force_quit() {
+                return Ok(None);
+            }
+            continue;
+        }
+        app.force_quit_armed = false;
+
+        // The help overlay swallows the next key, whatever it is. `?` opens
+        // it anywhere except while a filter is being typed, where it is
+        // just a character.
+        if app.show_help {
+            app.show_help = false;
+            continue;
+        }
+        if key.code == KeyCode::Char('?') && !app.list_filter_editing && !app.error_filter_editing {
+            app.show_help = true;
+            continue;
+        }
+
+        // Esc means "go back one step" on every screen, so it is handled
+        // once here rather than per screen. It only quits from the first and
+        // last screens; `q` is the way to quit from anywhere else.
+        if key.code == KeyCode::Esc {
+            if app.go_back() {
+                return Ok(None);
+            }
+            continue;
+        }
+
         match &app.screen {
             Screen::Welcome => match key.code {
                 KeyCode::Enter | KeyCode::Char(' ') => app.begin_scan(),
-                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('q') => return Ok(None),
                 _ => {}
             },
 
             // Scanning is fully automatic — the only thing the user can do is bail out.
-            Screen::Scanning => match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            Screen::Scanning => {
+                if key.code == KeyCode::Char('q') {
+                    return Ok(None);
+                }
+            }
+
+            // While the filter is being typed, printable keys go into it.
+            // The arrow keys still move through what it matches; Enter
+            // keeps it, and Esc (above) clears it.
+            Screen::List if app.list_filter_editing => match key.code {
+                KeyCode::Char(c) => app.push_list_filter(c),
+                KeyCode::Backspace => app.pop_list_filter(),
+                KeyCode::Enter => app.end_list_filter(),
+                KeyCode::Up => app.navigate_up(),
+                KeyCode::Down => app.navigate_down(),
                 _ => {}
             },
 
             Screen::List => match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                // Quitting may first show the Done summary if anything was deleted.
+                KeyCode::Char('q') if app.quit_from_list() => return Ok(None),
                 KeyCode::Up | KeyCode::Char('k') => app.navigate_up(),
                 KeyCode::Down | KeyCode::Char('j') => app.navigate_down(),
+                KeyCode::PageUp => app.page_up(),
+                KeyCode::PageDown => app.page_down(),
+                KeyCode::Home => app.jump_to_start(),
+                KeyCode::End => app.jump_to_end(),
                 KeyCode::Char(' ') => app.toggle_selected(),
+                KeyCode::Char('v') => app.mark_range(),
                 KeyCode::Char('a') => app.toggle_all(),
                 KeyCode::Char('A') => app.toggle_all_force(),
-                KeyCode::Enter => {
-                    if app.selected_count() > 0 {
-                        app.screen = Screen::Confirming;
-                    }
+                KeyCode::Char('.') => app.toggle_relative_paths(),
+                KeyCode::Char('C') => app.toggle_compact(),
+                KeyCode::Char('R') => app.refresh_selected_size(),
+                KeyCode::Char('s') => app.cycle_sort(),
+                KeyCode::Char('g') => app.toggle_grouping(),
+                KeyCode::Left | KeyCode::Char('h') => app.collapse_group(),
+                KeyCode::Right | KeyCode::Char('l') => app.expand_group(),
+                KeyCode::Char('P') => app.save_profile(),
+                KeyCode::Char('/') => app.begin_list_filter(),
+                KeyCode::Char('p') | KeyCode::Char('K') => app.toggle_keep(),
+                KeyCode::Char('i') => app.toggle_stats(),
+                KeyCode::Char('c') => app.toggle_cumulative(),
+                KeyCode::Char('d') => app.open_breakdown(),
+                KeyCode::Char('I') => app.open_detail(),
+                KeyCode::Char('o') => app.open_parent(),
+                KeyCode::Char('y') => app.copy_highlighted(),
+                KeyCode::Char('e') => app.exclude_parent_of_selected(),
+                KeyCode::Char('x') => app.begin_single_delete(false),
+                KeyCode::Char('X') => app.begin_single_delete(true),
+                KeyCode::Delete => {
+                    app.begin_single_delete(key.modifiers.contains(KeyModifiers::SHIFT));
                 }
+                // While a range is marked, Enter finishes it like Space.
+                KeyCode::Enter if app.anchor.is_some() => app.mark_range(),
+                KeyCode::Enter => app.request_delete(),
+                _ => {}
+            },
+
+            // With something sensitive queued, the confirmation wants a
+            // word typed out, so letters are text. Tab takes over from T.
+            Screen::Confirming if app.needs_typed_confirm() => match key.code {
+                KeyCode::Char(c) => app.push_confirm_typed(c),
+                KeyCode::Backspace => app.pop_confirm_typed(),
+                KeyCode::Enter => app.submit_confirm_typed(),
+                KeyCode::Tab => app.toggle_delete_mode(),
                 _ => {}
             },
 
             // Confirming is rendered as an overlay on top of the list screen.
-            // Y/Enter proceeds; N/Esc drops back to the list.
+            // Y/Enter proceeds; N (or Esc, above) drops back to the list. T
+            // switches between removing and moving to the trash.
             Screen::Confirming => match key.code {
-                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.begin_delete(),
-                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                    app.screen = Screen::List;
-                }
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.confirm_delete(),
+                KeyCode::Char('n') | KeyCode::Char('N') => app.cancel_delete(),
+                KeyCode::Char('t') | KeyCode::Char('T') => app.toggle_delete_mode(),
+                _ => {}
+            },
+
+            // A second, deliberate yes for sensitive entries. Enter is left
+            // out on purpose so it can't be pressed through by habit.
+            Screen::SensitiveReview => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => app.begin_delete_after_countdown(),
+                KeyCode::Char('s') | KeyCode::Char('S') => app.skip_sensitive(),
+                KeyCode::Char('n') | KeyCode::Char('N') => app.cancel_delete(),
                 _ => {}
             },
 
-            // Input is intentionally blocked during deletion — wait for the
-            // background thread to finish so nothing can interrupt a delete in progress.
-            Screen::Deleting => {}
+            // Only Esc, handled above, does anything while counting down.
+            Screen::Countdown => {}
+
+            // The breakdown is read-only; `d` closes it again, like Esc.
+            Screen::Breakdown => {
+                if key.code == KeyCode::Char('d') {
+                    app.close_breakdown();
+                }
+            }
+
+            // Read-only too; `I` toggles it like `d` does the breakdown.
+            Screen::Detail => {
+                if key.code == KeyCode::Char('I') {
+                    app.close_detail();
+                }
+            }
+
+            // `q` (like Esc, above) cancels: the thread finishes the
+            // directory in hand and skips the rest. Nothing can interrupt a
+            // `remove_dir_all` already running except the force quit.
+            Screen::Deleting => {
+                if key.code == KeyCode::Char('q') {
+                    app.cancel_running_delete();
+                }
+            }
+
+            // While the error filter is being edited, printable keys are typed
+            // into it instead of acting as commands. Enter keeps the filter;
+            // Esc (above) clears it.
+            Screen::Done if app.error_filter_editing => match key.code {
+                KeyCode::Char(c) => app.push_error_filter(c),
+                KeyCode::Backspace => app.pop_error_filter(),
+                KeyCode::Enter => app.end_error_filter(),
+                _ => {}
+            },
 
             Screen::Done => match key.code {
-                KeyCode::Char('q') | KeyCode::Enter | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('q') | KeyCode::Enter => return Ok(None),
+                KeyCode::Up | KeyCode::Char('k') => app.errors_up(),
+                KeyCode::Down | KeyCode::Char('j') => app.errors_down(),
+                KeyCode::Char('/') if !app.delete_errors.is_empty() => app.begin_error_filter(),
+                KeyCode::Char('e' | 'E') => app.empty_trash(),
+                KeyCode::Char('y') => app.copy_highlighted(),
+                KeyCode::Char('u' | 'U') => app.restore_trash(),
+                KeyCode::Char('r' | 'R') => app.retry_failed(),
                 _ => {}
             },
         }
     }
 }
 
+/// Handles a mouse event. Only the list screen takes the mouse: a left
+/// click highlights or toggles a row (see [`App::click_list`]) and the
+/// wheel moves the highlight. Any click closes the help overlay, as a key
+/// would.
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    if app.show_help {
+        if matches!(mouse.kind, MouseEventKind::Down(_)) {
+            app.show_help = false;
+        }
+        return;
+    }
+    if !matches!(app.screen, Screen::List) {
+        return;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => app.click_list(mouse.column, mouse.row),
+        MouseEventKind::ScrollUp => app.scroll_up(),
+        MouseEventKind::ScrollDown => app.scroll_down(),
+        _ => {}
+    }
+}
+
+/// Runs headlessly for `--progress-json`: scans the root and writes each
+/// event to stdout as a line of JSON (see [`killnode::progress`]) instead of
+/// drawing anything. Only what [`Options::lists`] passes is reported or
+/// counted, and nothing is deleted.
+///
+/// Stops early if stdout goes away (for example, the reading process exits).
+fn run_progress_json(options: &Options) -> io::Result<()> {
+    let engine = Engine::from_options(options);
+    let now = now_secs();
+    let mut out = io::stdout().lock();
+
+    // The callback can't use `?`, so the first write error is kept and
+    // everything after it is skipped.
+    let mut result = Ok(());
+    let mut index = 0;
+    let mut entries = engine.scan_with_progress(|entry| {
+        if result.is_err() || !options.lists(entry, now) {
+            return;
+        }
+        result = writeln!(out, "{}", progress::found(index, entry));
+        index += 1;
+    });
+    result?;
+
+    entries.retain(|e| options.lists(e, now));
+    writeln!(out, "{}", progress::scan_done(&entries))
+}
+
+/// Runs headlessly for `--du-format`: scans the root and prints a `du`-style
+/// line (see [`killnode::du`]) for each directory [`Options::lists`] passes,
+/// as it is found. Nothing is deleted.
+///
+/// Stops early if stdout goes away, as it does when the output is piped
+/// into `head`.
+fn run_du_format(options: &Options) -> io::Result<()> {
+    let engine = Engine::from_options(options);
+    let now = now_secs();
+    let mut out = io::stdout().lock();
+
+    // As in `run_progress_json`: keep the first write error, skip the rest.
+    let mut result = Ok(());
+    engine.scan_with_progress(|entry| {
+        if result.is_err() || !options.lists(entry, now) {
+            return;
+        }
+        result = writeln!(out, "{}", du::line(entry, options.du_unit));
+    });
+    result
+}
+
+/// Runs headlessly for `--stats-only`: scans the root and prints only the
+/// count and total size of what it found (see [`killnode::stats`] and
+/// [`scan_listed`]). `--color` decides whether the numbers are highlighted.
+/// Nothing is deleted.
+fn run_stats_only(options: &Options) -> io::Result<()> {
+    let entries = scan_listed(options);
+    let color = options.use_color(io::stdout().is_terminal());
+    let totals = stats::Totals::of(&entries);
+    writeln!(
+        io::stdout(),
+        "{}",
+        stats::summary(&totals, !options.no_size, color)
+    )
+}
+
+/// Runs headlessly for `--json`: scans the root and writes what it found to
+/// `file` as a JSON array (see [`killnode::export`]), filtered as by
+/// [`scan_listed`]. Nothing is deleted.
+fn run_json(options: &Options, file: &str) -> io::Result<()> {
+    let entries = scan_listed(options);
+    std::fs::write(file, export::to_json(&entries))
+}
+
+/// Runs headlessly for `--apply`: deletes every path in `file`, a `--json`
+/// export (see [Applying an export](killnode::export#applying-an-export)),
+/// and prints a one-line summary. Sensitive paths, including any on the
+/// always-keep list, are left alone unless `--force` was given; each of
+/// those, and each error, gets a line on stderr.
+///
+/// With `--progress-json` the summary is replaced by the progress stream: a
+/// `deleting` line before each path and a `delete_done` line at the end (see
+/// [`progress`]).
+///
+/// Returns whether every deletion succeeded, or a message if the file
+/// couldn't be read.
+fn run_apply(options: &Options, file: &str) -> Result<bool, String> {
+    let text = std::fs::read_to_string(file).map_err(|e| e.to_string())?;
+    let (paths, held_back): (Vec<_>, Vec<_>) = export::from_json(&text, &config::keep_list())?
+        .into_iter()
+        .partition(|e| options.force || !e.sensitive);
+
+    for entry in &held_back {
+        eprintln!("killnode: left alone (sensitive): {}", entry.path);
+    }
+    let paths = paths.into_iter().map(|e| e.path).collect();
+    let engine = Engine::from_options(options);
+    let report = if options.progress_json {
+        let mut out = io::stdout().lock();
+        // As in `run_progress_json`: keep the first write error, skip the rest.
+        let mut written = Ok(());
+        let report = engine.delete_with_progress(paths, |index, total, path| {
+            if written.is_ok() {
+                written = writeln!(out, "{}", progress::deleting(index, total, path));
+            }
+        });
+        written
+            .and_then(|()| writeln!(out, "{}", progress::delete_done(&report)))
+            .map_err(|e| e.to_string())?;
+        report
+    } else {
+        engine.delete(paths)
+    };
+    for message in &report.errors {
+        eprintln!("killnode: {message}");
+    }
+    if !options.progress_json {
+        println!(
+            "{}",
+            export::apply_summary(&report, held_back.len(), !options.no_size)
+        );
+    }
+    Ok(report.errors.is_empty())
+}
+
+/// Scans the root and returns what the list would show, largest first:
+/// only the entries [`Options::lists`] passes.
+fn scan_listed(options: &Options) -> Vec<NodeModuleEntry> {
+    let mut entries = Engine::from_options(options).scan();
+    let now = now_secs();
+    entries.retain(|e| options.lists(e, now));
+    entries
+}
+
+/// Pushes the terminal's window title onto its title stack (`CSI 22 ; 0 t`),
+/// for [`RestoreTitle`] to pop. crossterm can set the title but not read it,
+/// so the stack is the only way to give the user's own title back.
+///
+/// Terminals without a title stack ignore both sequences. The legacy
+/// Windows console has no equivalent, so there they do nothing at all.
+struct SaveTitle;
+
+/// Pops the title [`SaveTitle`] pushed (`CSI 23 ; 0 t`).
+struct RestoreTitle;
+
+impl crossterm::Command for SaveTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1b[22;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl crossterm::Command for RestoreTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1b[23;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Sets up the terminal, runs the app, and restores the terminal on exit.
 ///
 /// Terminal setup and teardown are kept here (rather than scattered across the
@@ -170,22 +528,56 @@ fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Result<()> {
 /// 4. **Always** pop the alternate screen and restore cooked mode — even if the
 ///    loop returned an error.
 fn main() -> io::Result<()> {
-    // Handle informational flags before touching the terminal.
-    // These are intentionally checked before any terminal setup so that
-    // `killnode --help | cat` works as expected.
-    for arg in std::env::args().skip(1) {
-        match arg.as_str() {
-            "-h" | "--help" => {
-                print!("{HELP}");
-                return Ok(());
-            }
-            "-V" | "--version" => {
-                println!("killnode {VERSION}");
-                return Ok(());
+    // Parse flags and handle the informational ones before touching the
+    // terminal. This is intentionally done before any terminal setup so that
+    // `killnode --help | cat` works as expected, and so usage errors are
+    // printed to a normal shell rather than the alternate screen.
+    let options = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => *options,
+        Ok(Command::Help) => {
+            print!("{HELP}");
+            return Ok(());
+        }
+        Ok(Command::Version) => {
+            println!("killnode {VERSION}");
+            return Ok(());
+        }
+        Err(msg) => {
+            eprintln!("killnode: {msg}\n\nRun 'killnode --help' for usage.");
+            std::process::exit(2);
+        }
+    };
+
+    if options.progress_json && options.apply.is_none() {
+        return run_progress_json(&options);
+    }
+    if options.du_format {
+        return run_du_format(&options);
+    }
+    if options.stats_only {
+        return run_stats_only(&options);
+    }
+    if let Some(file) = &options.json {
+        if let Err(e) = run_json(&options, file) {
+            eprintln!("killnode: can't write '{file}': {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    if let Some(file) = &options.apply {
+        match run_apply(&options, file) {
+            Ok(true) => return Ok(()),
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("killnode: can't apply '{file}': {e}");
+                std::process::exit(1);
             }
-            _ => {}
         }
     }
+    if let Some(plan) = &options.run_plan {
+        job::run_plan(std::path::Path::new(plan), &options)?;
+        return Ok(());
+    }
 
     // If the app panics, restore the terminal before letting Rust print the
     // panic message. Without this, a crash would leave the shell in raw mode
@@ -193,14 +585,21 @@ fn main() -> io::Result<()> {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = execute!(
+            io::stdout(),
+            DisableMouseCapture,
+            LeaveAlternateScreen,
+            RestoreTitle
+        );
         original_hook(info);
     }));
 
-    // Enter full-screen TUI mode.
+    // Enter full-screen TUI mode, keeping the user's window title to put
+    // back afterwards — `run` replaces it with killnode's own — and asking
+    // for mouse clicks and the wheel.
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, SaveTitle, EnterAlternateScreen, EnableMouseCapture)?;
 
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -208,13 +607,21 @@ fn main() -> io::Result<()> {
 
     // Run the app. We capture the result so we can restore the terminal before
     // returning it — otherwise an early `?` would skip cleanup.
-    let result = run(&mut terminal);
+    let result = run(&mut terminal, options);
 
     // Restore the terminal unconditionally. If any of these fail there is
     // nothing sensible to do, so the errors are intentionally ignored.
     let _ = terminal.show_cursor();
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        RestoreTitle
+    )?;
 
-    result
+    if let Some(message) = result? {
+        println!("{message}");
+    }
+    Ok(())
 }
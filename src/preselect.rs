@@ -0,0 +1,192 @@
+//! # Pre-selection rules
+//!
+//! By default every safe entry starts out selected. `--preselect` narrows
+//! that down with a small rule, so the list opens already focused on what
+//! the user usually deletes:
+//!
+//! ```text
+//! killnode --preselect 'age>90d and size>200MB'
+//! killnode --preselect 'size>=1GB or age>1y'
+//! ```
+//!
+//! ## Grammar
+//!
+//! ```text
+//! rule   = all ("or" all)*
+//! all    = clause ("and" clause)*
+//! clause = field op value
+//! field  = "size" | "age"
+//! op     = ">" | ">=" | "<" | "<="
+//! ```
+//!
+//! `and` binds tighter than `or`, and there are no parentheses. Sizes take
+//! the same decimal units the list shows (`B`, `KB`, `MB`, `GB`, `TB`; a bare
+//! number is bytes). Ages take `h`, `d`, `w`, `mo` (30 days) or `y` (365
+//! days). Whitespace around operators is optional.
+//!
+//! An entry whose age is unknown never satisfies an `age` clause. The rule
+//! only ever *removes* entries from the default selection: a sensitive entry
+//! still starts unselected unless `--sensitive include` says otherwise.
+
+use crate::app::NodeModuleEntry;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// A parsed `--preselect` rule. See the [module docs](self) for the syntax.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preselect {
+    /// Alternatives joined by `or`, each a list of clauses joined by `and`.
+    any: Vec<Vec<Clause>>,
+}
+
+/// One `field op value` comparison.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Clause {
+    field: Field,
+    op: Op,
+    /// Bytes for [`Field::Size`], seconds for [`Field::Age`].
+    value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+    Size,
+    Age,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+// ─── Parsing ──────────────────────────────────────────────────────────────────
+
+impl Preselect {
+    /// Parses a rule, returning a message naming the part that doesn't make
+    /// sense if it can't.
+    pub fn parse(rule: &str) -> Result<Self, String> {
+        let words: Vec<&str> = rule.split_whitespace().collect();
+        if words.is_empty() {
+            return Err("empty rule".to_string());
+        }
+
+        let mut any = Vec::new();
+        for alternative in words.split(|w| w.eq_ignore_ascii_case("or")) {
+            let mut all = Vec::new();
+            for clause in alternative.split(|w| w.eq_ignore_ascii_case("and")) {
+                // A clause may be written `age>90d` or `age > 90d`.
+                all.push(parse_clause(&clause.concat())?);
+            }
+            any.push(all);
+        }
+        Ok(Self { any })
+    }
+}
+
+/// Parses one `field op value` clause, already joined into a single word.
+fn parse_clause(clause: &str) -> Result<Clause, String> {
+    if clause.is_empty() {
+        return Err("missing condition next to 'and' or 'or'".to_string());
+    }
+    let Some(op_at) = clause.find(['<', '>']) else {
+        return Err(format!(
+            "'{clause}' has no comparison (expected >, >=, < or <=)"
+        ));
+    };
+    let (field, rest) = clause.split_at(op_at);
+    let (op, value) = if let Some(v) = rest.strip_prefix(">=") {
+        (Op::Ge, v)
+    } else if let Some(v) = rest.strip_prefix("<=") {
+        (Op::Le, v)
+    } else if let Some(v) = rest.strip_prefix('>') {
+        (Op::Gt, v)
+    } else {
+        (Op::Lt, &rest[1..])
+    };
+
+    let (field, value) = match field.to_ascii_lowercase().as_str() {
+        "size" => (Field::Size, parse_size(value)),
+        "age" => (Field::Age, parse_amount(value, AGE_UNITS)),
+        other => return Err(format!("unknown field '{other}' (expected size or age)")),
+    };
+    let value = value.ok_or_else(|| format!("invalid value in '{clause}'"))?;
+    Ok(Clause { field, op, value })
+}
+
+/// Size units and how many bytes each is worth. Decimal, like the list.
+const SIZE_UNITS: &[(&str, u64)] = &[
+    ("", 1),
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("tb", 1_000_000_000_000),
+];
+
+/// Age units and how many seconds each is worth.
+const AGE_UNITS: &[(&str, u64)] = &[
+    ("h", 3_600),
+    ("d", 86_400),
+    ("w", 7 * 86_400),
+    ("mo", 30 * 86_400),
+    ("y", 365 * 86_400),
+];
+
+/// Parses a size such as `50MB` or `1.5gb` into bytes, in the units a
+/// `size` clause takes. Also used for `--min-size`.
+pub fn parse_size(value: &str) -> Option<u64> {
+    parse_amount(value, SIZE_UNITS)
+}
+
+/// Parses an age such as `90d` or `6mo` into seconds, in the units an `age`
+/// clause takes. Also used for `--older-than`.
+pub fn parse_age(value: &str) -> Option<u64> {
+    parse_amount(value, AGE_UNITS)
+}
+
+/// Parses a number followed by one of `units` (case-insensitively), such as
+/// `200MB` or `1.5GB`, into the base unit.
+fn parse_amount(value: &str, units: &[(&str, u64)]) -> Option<u64> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let (_, scale) = units
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))?;
+    Some((number * *scale as f64) as u64)
+}
+
+// ─── Matching ─────────────────────────────────────────────────────────────────
+
+impl Preselect {
+    /// Returns whether `entry` satisfies the rule. `now_secs` (seconds since
+    /// the Unix epoch) is the reference point for ages.
+    pub fn matches(&self, entry: &NodeModuleEntry, now_secs: u64) -> bool {
+        self.any
+            .iter()
+            .any(|all| all.iter().all(|c| c.matches(entry, now_secs)))
+    }
+}
+
+impl Clause {
+    fn matches(&self, entry: &NodeModuleEntry, now_secs: u64) -> bool {
+        let actual = match self.field {
+            Field::Size => entry.size,
+            Field::Age => match entry.last_modified {
+                Some(t) => now_secs.saturating_sub(t),
+                None => return false,
+            },
+        };
+        match self.op {
+            Op::Gt => actual > self.value,
+            Op::Ge => actual >= self.value,
+            Op::Lt => actual < self.value,
+            Op::Le => actual <= self.value,
+        }
+    }
+}
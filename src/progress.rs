@@ -0,0 +1,76 @@
+//! # JSON progress stream
+//!
+//! With `--progress-json`, killnode doesn't start the TUI. It runs the
+//! [`crate::engine`] pipeline and writes one JSON object per line to stdout
+//! for every event, so another program — a GUI, an editor plugin, a bigger
+//! TUI — can use killnode as its backend and draw progress however it likes.
+//!
+//! Each line is a complete object with an `event` field saying what kind it
+//! is. Fields that don't apply to an event are left out rather than `null`.
+//!
+//! | `event` | Sent | Fields |
+//! |---------|------|--------|
+//! | `found` | for each `node_modules` as the scan finds it | `index`, `path`, `bytes`, `sensitive`, `symlink` |
+//! | `scan_done` | once, when the scan finishes | `total`, `bytes` |
+//! | `deleting` | just before each path is removed | `index`, `total`, `path` |
+//! | `delete_done` | once, when every path has been processed | `deleted`, `skipped`, `errors`, `bytes` |
+//!
+//! `index` counts from 0. `bytes` is 0 for every entry under `--no-size`,
+//! since nothing was measured.
+//!
+//! This stream reports progress only. It is not a summary of the run and is
+//! kept separate from any summary output, so a consumer never has to tell
+//! the two apart.
+
+use serde_json::json;
+
+use crate::{app::NodeModuleEntry, engine::DeleteReport};
+
+// ─── Events ───────────────────────────────────────────────────────────────────
+
+/// The `found` line for `entry`, the `index`-th directory the scan reported.
+pub fn found(index: usize, entry: &NodeModuleEntry) -> String {
+    json!({
+        "event": "found",
+        "index": index,
+        "path": entry.path,
+        "bytes": entry.size,
+        "sensitive": entry.sensitive.is_some(),
+        "symlink": entry.symlink,
+    })
+    .to_string()
+}
+
+/// The `scan_done` line for a scan that listed `entries`.
+pub fn scan_done(entries: &[NodeModuleEntry]) -> String {
+    json!({
+        "event": "scan_done",
+        "total": entries.len(),
+        "bytes": entries.iter().map(|e| e.size).sum::<u64>(),
+    })
+    .to_string()
+}
+
+/// The `deleting` line for `path`, the `index`-th of `total` paths queued.
+pub fn deleting(index: usize, total: usize, path: &str) -> String {
+    json!({
+        "event": "deleting",
+        "index": index,
+        "total": total,
+        "path": path,
+    })
+    .to_string()
+}
+
+/// The `delete_done` line for a finished deletion run. Counts only — the
+/// individual paths were already reported by `deleting`.
+pub fn delete_done(report: &DeleteReport) -> String {
+    json!({
+        "event": "delete_done",
+        "deleted": report.deleted.len(),
+        "skipped": report.skipped.len(),
+        "errors": report.errors.len(),
+        "bytes": report.freed,
+    })
+    .to_string()
+}
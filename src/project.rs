@@ -0,0 +1,50 @@
+//! # Projects
+//!
+//! Which project a `node_modules` belongs to, for grouping the list with
+//! `g`. A monorepo can hold dozens of `node_modules` — one per package, plus
+//! one at the top — and grouped under their repository they read as one
+//! thing to keep or clear rather than dozens of unrelated rows.
+//!
+//! ## What counts as the project
+//!
+//! The nearest directory above the `node_modules` that holds a `.git` is
+//! the project: that is the repository, however many packages it has. Only
+//! outside any repository does the nearest `package.json` decide instead,
+//! since in a monorepo every package has its own and grouping by them would
+//! group nothing. With neither, the directory holding the `node_modules` is
+//! its own project.
+//!
+//! The search never climbs above the scan root the entry was found under,
+//! so a repository that happens to contain the scan root doesn't swallow
+//! everything into one group.
+
+use std::path::Path;
+
+// ─── Lookup ───────────────────────────────────────────────────────────────────
+
+/// Returns the project directory `path` (a `node_modules` or other target
+/// directory) belongs to, searching no higher than `root`. See the module
+/// docs for the rules. Touches the filesystem: one or two `stat` calls per
+/// directory climbed.
+pub fn project_dir(path: &str, root: Option<&str>) -> String {
+    let Some(parent) = Path::new(path).parent() else {
+        return path.to_string();
+    };
+    let within_root = |dir: &&Path| root.is_none_or(|root| dir.starts_with(root));
+
+    let repository = parent
+        .ancestors()
+        .take_while(within_root)
+        .find(|dir| dir.join(".git").exists());
+    let package = || {
+        parent
+            .ancestors()
+            .take_while(within_root)
+            .find(|dir| dir.join("package.json").is_file())
+    };
+    repository
+        .or_else(package)
+        .unwrap_or(parent)
+        .to_string_lossy()
+        .to_string()
+}
@@ -1,7 +1,7 @@
 //! # Background scanner
 //!
 //! This module is responsible for finding every `node_modules` directory
-//! under a given root path as fast as possible, without freezing the UI.
+//! under one or more root paths as fast as possible, without freezing the UI.
 //!
 //! ## How it works
 //!
@@ -21,23 +21,35 @@
 //! top-level directory removes everything inside it anyway, and reporting
 //! nested ones would just create confusing duplicates in the list.
 //!
+//! ## Symlinked `node_modules`
+//!
+//! Some setups make `node_modules` a symlink into a shared package store.
+//! The walker never follows symlinks, so the store itself is never measured
+//! or listed; the link is reported with [`ScanMsg::Found::symlink`] set, and
+//! the deleter removes just the link.
+//!
 //! ## Sensitive path detection
 //!
 //! Not all `node_modules` directories are safe to delete. A global npm cache
 //! at `~/.npm` or an app bundled inside `/Applications/Foo.app` should not be
-//! touched. [`is_sensitive_dir`] encodes the rules for what counts as
+//! touched. [`sensitive_reason`] encodes the rules for what counts as
 //! "sensitive" on each platform; entries that match are flagged and shown with
 //! a warning in the UI rather than being pre-selected for deletion.
 
 use std::{
-    path::Path,
+    collections::HashSet,
+    path::{Path, PathBuf},
     sync::{
         Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
         mpsc::{self, Sender},
     },
 };
 
-use jwalk::WalkDir;
+use jwalk::{WalkDir, WalkDirGeneric};
+
+use crate::{cli::now_secs, config};
+use globset::GlobSet;
 
 // ─── Public types ─────────────────────────────────────────────────────────────
 
@@ -59,6 +71,72 @@ pub struct ActiveScan {
     /// Used as a cheap guard so [`crate::app::App::process_scan_messages`]
     /// can return immediately on subsequent ticks without touching the channel.
     pub done: bool,
+
+    /// Tells the scan thread to stop walking. Set when the handle is dropped
+    /// — going back from the scanning screen, or starting another scan —
+    /// so an abandoned scan doesn't go on reading the whole tree alongside
+    /// the next one.
+    pub stop: Arc<AtomicBool>,
+}
+
+impl Drop for ActiveScan {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Why a `node_modules` is in a location where deleting it could break
+/// something. Returned by [`sensitive_reason`]; see there for the rules
+/// behind each variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SensitiveReason {
+    /// Under `~/.config`.
+    XdgConfig,
+    /// Under `~/.local/share`.
+    XdgData,
+    /// Under `~/.cache`.
+    XdgCache,
+    /// Under some other hidden top-level folder in the home directory.
+    DotDirectory,
+    /// Inside an `/Applications/Foo.app` bundle.
+    MacAppBundle,
+    /// Under a hidden folder on a Windows network share.
+    UncHidden,
+    /// Under `AppData\Roaming`.
+    AppDataRoaming,
+    /// Under `AppData\Local`, outside the package manager caches.
+    AppDataLocal,
+    /// The `node_modules` is itself a Windows junction or other reparse
+    /// point (see [`is_reparse_point`]), so it may lead anywhere.
+    ReparsePoint,
+    /// The path is relative, so where it really lies can't be told (see
+    /// [`sensitive_reason`]).
+    RelativePath,
+    /// The user pinned it to the always-keep list (see
+    /// [`ScanOptions::keep`]). Unlike the others this is a choice, not a
+    /// property of the location, so it wins over whatever the location
+    /// would say.
+    Kept,
+}
+
+impl SensitiveReason {
+    /// A short phrase for the user, shown wherever they are asked to think
+    /// twice about deleting the entry.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::XdgConfig => "app configuration in ~/.config",
+            Self::XdgData => "app data in ~/.local/share",
+            Self::XdgCache => "a cache another tool manages in ~/.cache",
+            Self::DotDirectory => "a hidden folder in your home directory",
+            Self::MacAppBundle => "inside a macOS application bundle",
+            Self::UncHidden => "a hidden folder on a network share",
+            Self::AppDataRoaming => "installed app data in AppData\\Roaming",
+            Self::AppDataLocal => "app data in AppData\\Local",
+            Self::ReparsePoint => "a link or junction that may lead anywhere",
+            Self::RelativePath => "a relative path whose location is unknown",
+            Self::Kept => "on your always-keep list",
+        }
+    }
 }
 
 /// A message sent from the scanner thread to the main thread.
@@ -69,20 +147,169 @@ pub enum ScanMsg {
         /// Absolute path to the directory.
         path: String,
 
-        /// Total size of all files inside, in bytes.
+        /// Total size of all files inside, in bytes. Always 0 when the scan
+        /// was started with [`ScanOptions::skip_sizes`].
         size: u64,
 
-        /// Whether the directory lives in a location that should not be
-        /// deleted automatically. See [`is_sensitive_dir`] for the rules.
-        sensitive: bool,
+        /// Why the directory lives in a location that should not be deleted
+        /// automatically, or `None` if it doesn't. See [`sensitive_reason`]
+        /// for the rules.
+        sensitive: Option<SensitiveReason>,
 
         /// When the directory was last modified, in seconds since the Unix
         /// epoch. `None` if the OS didn't return valid metadata.
         last_modified: Option<u64>,
+
+        /// Whether `path` is a symlink to a directory rather than a real
+        /// directory. `size` is always 0 for these, because removing the
+        /// link frees nothing — the target is left alone.
+        symlink: bool,
     },
 
     /// The walk has finished. No more `Found` messages will be sent.
-    Done,
+    Done {
+        /// Whether the walk was cut short by [`ScanOptions::max_results`],
+        /// so there may be more `node_modules` than were reported.
+        truncated: bool,
+    },
+}
+
+/// Settings that change what the scan thread does for each directory found.
+#[derive(Clone, Default)]
+pub struct ScanOptions {
+    /// Don't measure directory sizes at all. Every [`ScanMsg::Found`] then
+    /// reports a size of 0, which turns the scan into a plain enumeration of
+    /// locations — much faster on large trees.
+    pub skip_sizes: bool,
+
+    /// Measure the space files take up on disk rather than their length.
+    /// See [`dir_size`]. Set by `--disk-usage`.
+    pub disk_usage: bool,
+
+    /// Stop the walk once this many directories have been found that the
+    /// list would show. Keeps a scan of a huge shared drive from flooding
+    /// the list. Ones that [`ScanOptions::hide_sensitive`],
+    /// [`ScanOptions::min_size`] or [`ScanOptions::older_than`] leave out
+    /// are still reported but don't count (see [`ScanOptions::lists`]).
+    /// `None` means no limit.
+    pub max_results: Option<usize>,
+
+    /// Leave sensitive directories out of the list. Set by
+    /// `--sensitive hide`; only [`ScanOptions::max_results`] looks at it.
+    pub hide_sensitive: bool,
+
+    /// Leave directories smaller than this many bytes out of the list. Set
+    /// by `--min-size`; only [`ScanOptions::max_results`] looks at it.
+    pub min_size: Option<u64>,
+
+    /// Leave directories modified less than this many seconds ago out of
+    /// the list, and undated ones too unless
+    /// [`ScanOptions::include_undated`] is set. Set by `--older-than`; only
+    /// [`ScanOptions::max_results`] looks at it.
+    pub older_than: Option<u64>,
+
+    /// Keep directories with no known modification time in the list
+    /// despite [`ScanOptions::older_than`]. Set by `--include-undated`.
+    pub include_undated: bool,
+
+    /// Look at most this many directory levels below the root: a
+    /// `node_modules` directly in the root is at depth 1, one in a project
+    /// folder at depth 2. Deeper directories are never opened, so anything
+    /// below the limit is neither walked nor reported. `None` means no
+    /// limit.
+    pub max_depth: Option<usize>,
+
+    /// Stay on the filesystem each root is on, like `du -x` or
+    /// `find -xdev`: a directory that is the mount point of another one —
+    /// a network share or an external drive, say — is never entered. Set by
+    /// `--one-file-system`. Only Unix says which filesystem a directory is
+    /// on cheaply enough; elsewhere this makes no difference.
+    pub one_file_system: bool,
+
+    /// Only report a `node_modules` if one of the directories above it (up
+    /// to and including the filesystem root) contains an entry with this
+    /// name — `.git`, say. Keeps the scan to real projects and out of data
+    /// directories that happen to hold a `node_modules`. `None` reports
+    /// every one.
+    pub root_marker: Option<String>,
+
+    /// Never enter or report a directory whose full path matches this set,
+    /// nor anything under it. Compiled once from `--ignore-file` patterns by
+    /// [`crate::config::ignore_set`]; empty matches nothing.
+    pub ignore: GlobSet,
+
+    /// Never enter or report anything whose full absolute path matches this
+    /// set. Compiled once from `--exclude` patterns by
+    /// [`crate::config::exclude_set`]; empty matches nothing.
+    pub exclude: GlobSet,
+
+    /// The directory names to look for — `target` or `.venv`, say, besides
+    /// `node_modules`. Empty means just [`DEFAULT_TARGET`]. Set from
+    /// `--targets`.
+    pub targets: Vec<String>,
+
+    /// The always-keep list, as [`crate::config::load_keep`] reads it. A
+    /// directory on it is reported as [`SensitiveReason::Kept`], so every
+    /// consumer of the scan — the list and each headless mode alike —
+    /// treats it as sensitive. Compared after
+    /// [`crate::config::normalize_path`].
+    pub keep: Vec<String>,
+}
+
+impl ScanOptions {
+    /// Returns whether the list would show a directory found with these
+    /// details, as of `now_secs`, and so whether it counts towards
+    /// [`ScanOptions::max_results`]. The same test as
+    /// [`crate::cli::Options::lists`], which the list and the headless modes
+    /// apply to what the scan reports.
+    pub fn lists(
+        &self,
+        size: u64,
+        sensitive: bool,
+        last_modified: Option<u64>,
+        now_secs: u64,
+    ) -> bool {
+        let too_recent = self.older_than.is_some_and(|min_age| match last_modified {
+            Some(t) => now_secs.saturating_sub(t) < min_age,
+            None => !self.include_undated,
+        });
+        !(self.hide_sensitive && sensitive)
+            && self.min_size.is_none_or(|min| size >= min)
+            && !too_recent
+    }
+}
+
+/// What the scan looks for unless [`ScanOptions::targets`] says otherwise.
+pub const DEFAULT_TARGET: &str = "node_modules";
+
+impl ScanOptions {
+    /// Returns the directory names to look for, never empty.
+    pub fn target_names(&self) -> Vec<String> {
+        if self.targets.is_empty() {
+            vec![DEFAULT_TARGET.to_string()]
+        } else {
+            self.targets.clone()
+        }
+    }
+}
+
+// ─── Filesystems ──────────────────────────────────────────────────────────────
+
+/// Returns the device number of the filesystem `path` is on, for
+/// [`ScanOptions::one_file_system`]. `None` if it can't be read, and always
+/// on systems other than Unix, where the option does nothing.
+#[cfg(unix)]
+fn device_of(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::symlink_metadata(path).ok().map(|m| m.dev())
+}
+
+/// Returns the device number of the filesystem `path` is on, for
+/// [`ScanOptions::one_file_system`]. `None` if it can't be read, and always
+/// on systems other than Unix, where the option does nothing.
+#[cfg(not(unix))]
+fn device_of(_path: &Path) -> Option<u64> {
+    None
 }
 
 // ─── Size calculation ─────────────────────────────────────────────────────────
@@ -90,22 +317,46 @@ pub enum ScanMsg {
 /// Calculates the total size of all files inside `path`, in bytes.
 ///
 /// This is a recursive walk — every file in every subdirectory is counted.
-/// Symlinks to files are followed (their target's size is used); broken
-/// symlinks are silently skipped.
+/// Symlinks are not followed and count for nothing: in a pnpm-style
+/// `node_modules` most packages are links into a shared store, and counting
+/// the store's files would claim space that deleting the links never frees.
+/// Each real file counts as [`file_size`] says.
 ///
 /// This function is deliberately synchronous. It is only called from the
 /// background scan thread, so blocking there is fine.
-pub(crate) fn dir_size(path: impl AsRef<Path>) -> u64 {
+pub(crate) fn dir_size(path: impl AsRef<Path>, disk_usage: bool) -> u64 {
     WalkDir::new(path)
         .skip_hidden(false)
         .into_iter()
         .filter_map(|e| e.ok())
         .filter(|e| e.file_type().is_file())
         .filter_map(|e| e.metadata().ok())
-        .map(|m| m.len())
+        .map(|m| file_size(&m, disk_usage))
         .sum()
 }
 
+/// Returns how much a file counts for: its length, or with `disk_usage`
+/// the blocks allocated to it, as `du` and `df` count it.
+///
+/// The two differ most in a `node_modules`: thousands of tiny files each
+/// take up at least a whole block, so the space deleting them gives back is
+/// often well above the sum of their lengths. Sparse and compressed files
+/// go the other way.
+///
+/// Only Unix reports allocated blocks cheaply. Elsewhere `disk_usage` makes
+/// no difference.
+fn file_size(meta: &std::fs::Metadata, disk_usage: bool) -> u64 {
+    #[cfg(unix)]
+    if disk_usage {
+        use std::os::unix::fs::MetadataExt;
+        // `st_blocks` is always in 512-byte units, whatever the block size.
+        return meta.blocks() * 512;
+    }
+    #[cfg(not(unix))]
+    let _ = disk_usage;
+    meta.len()
+}
+
 // ─── Path normalisation ───────────────────────────────────────────────────────
 
 /// Normalises a path string for case-insensitive, cross-platform comparison.
@@ -127,10 +378,23 @@ fn normalize_path(p: &str) -> String {
     s
 }
 
+/// Returns `true` if `p` is a Windows UNC path (`\\server\share\…`), or the
+/// same thing written with forward slashes.
+fn is_unc(p: &str) -> bool {
+    p.starts_with("\\\\") || p.starts_with("//")
+}
+
 // ─── Sensitive path detection ─────────────────────────────────────────────────
 
-/// Returns `true` if `path` is in a location where deleting `node_modules`
-/// could break something important.
+/// Returns why `path` is in a location where deleting `node_modules` could
+/// break something important, or `None` if it isn't in one.
+///
+/// [`SensitiveReason::description`] turns the reason into a short phrase for
+/// the user, shown when they inspect or review sensitive entries before
+/// deleting them. [`SensitiveReason::ReparsePoint`] is never returned here:
+/// it depends on the directory's metadata, not its path, and
+/// [`location_reason`] checks it separately. Nor is
+/// [`SensitiveReason::Kept`], which comes from [`ScanOptions::keep`].
 ///
 /// The goal is to be conservative: it is better to flag something as sensitive
 /// and let the user override it manually than to silently delete something that
@@ -168,37 +432,34 @@ fn normalize_path(p: &str) -> String {
 /// - `AppData/Roaming/**` — always sensitive (installed apps live here).
 /// - `AppData/Local/**` — sensitive, **except** for known package manager
 ///   cache directories (`.cache`, `.npm`, `.pnpm`) which are safe to delete.
-fn is_sensitive_dir(path: impl AsRef<Path>) -> bool {
-    let original = path.as_ref();
-    let original_str = original.to_string_lossy();
-
-    let is_unc = original_str.starts_with("\\\\") || original_str.starts_with("//");
-
-    // Make sure we're working with an absolute path so prefix comparisons
-    // against the home directory are reliable.
-    let absolute = if is_unc || original.is_absolute() {
-        original.to_path_buf()
-    } else {
-        std::env::current_dir().unwrap_or_default().join(original)
-    };
+///
+/// ## Input
+///
+/// `path` should be absolute (or a UNC path). The scanner walks from an
+/// absolute root, so every path it reports already is; resolving relative
+/// paths here would mean consulting the current directory, which can fail
+/// or change and silently skew the result. A relative path is instead
+/// reported as [`SensitiveReason::RelativePath`]: in keeping with the goal
+/// above, a location that can't be checked is treated as a risky one.
+pub fn sensitive_reason(path: impl AsRef<Path>) -> Option<SensitiveReason> {
+    let path = path.as_ref();
+    if !path.is_absolute() && !is_unc(&path.to_string_lossy()) {
+        return Some(SensitiveReason::RelativePath);
+    }
 
-    let norm = normalize_path(&absolute.to_string_lossy());
-    let norm_original = normalize_path(&original_str);
+    let norm = normalize_path(&path.to_string_lossy());
 
     // ── Home directory rules ───────────────────────────────────────────────────
 
+    // A relative HOME can't be compared against an absolute path without
+    // guessing what it is relative to, so the home rules are skipped rather
+    // than risking a misclassification.
     let home = std::env::var("HOME")
         .or_else(|_| std::env::var("USERPROFILE"))
         .unwrap_or_default();
 
-    if !home.is_empty() {
-        let home_path = Path::new(&home);
-        let home_abs = if home_path.is_absolute() {
-            home_path.to_path_buf()
-        } else {
-            std::env::current_dir().unwrap_or_default().join(home_path)
-        };
-        let norm_home = normalize_path(&home_abs.to_string_lossy());
+    if Path::new(&home).is_absolute() {
+        let norm_home = normalize_path(&home);
 
         let in_home = norm == norm_home || norm.starts_with(&format!("{norm_home}/"));
 
@@ -208,21 +469,24 @@ fn is_sensitive_dir(path: impl AsRef<Path>) -> bool {
             let top = rel.split('/').next().unwrap_or("");
 
             // Always-sensitive XDG directories.
-            if rel.starts_with(".config")
-                || rel.starts_with(".local/share")
-                || rel.starts_with(".cache")
-            {
-                return true;
+            if rel.starts_with(".config") {
+                return Some(SensitiveReason::XdgConfig);
+            }
+            if rel.starts_with(".local/share") {
+                return Some(SensitiveReason::XdgData);
+            }
+            if rel.starts_with(".cache") {
+                return Some(SensitiveReason::XdgCache);
             }
 
             // Package manager caches — look hidden but are safe.
             if top == ".npm" || top == ".pnpm" {
-                return false;
+                return None;
             }
 
             // Anything else under a hidden top-level directory is sensitive.
             if top.starts_with('.') && top != "." && top != ".." {
-                return true;
+                return Some(SensitiveReason::DotDirectory);
             }
         }
     }
@@ -237,7 +501,7 @@ fn is_sensitive_dir(path: impl AsRef<Path>) -> bool {
         if let Some(app_end) = rest.find(".app/")
             && !rest[..app_end].contains('/')
         {
-            return true;
+            return Some(SensitiveReason::MacAppBundle);
         }
     }
 
@@ -246,20 +510,20 @@ fn is_sensitive_dir(path: impl AsRef<Path>) -> bool {
     // A UNC path looks like "//server/share/path/…". We skip the first four
     // components (the two empty strings, server name, and share name) and
     // check whether any remaining segment starts with a dot.
-    if norm_original.starts_with("//") {
-        let hidden = norm_original
+    if norm.starts_with("//") {
+        let hidden = norm
             .split('/')
             .skip(4)
             .any(|part| !part.is_empty() && part.starts_with('.'));
         if hidden {
-            return true;
+            return Some(SensitiveReason::UncHidden);
         }
     }
 
     // ── Windows AppData ────────────────────────────────────────────────────────
 
     if norm.contains("/appdata/roaming") {
-        return true;
+        return Some(SensitiveReason::AppDataRoaming);
     }
 
     if norm.contains("/appdata/local") {
@@ -267,42 +531,224 @@ fn is_sensitive_dir(path: impl AsRef<Path>) -> bool {
         let whitelisted = [".cache", ".npm", ".pnpm"]
             .iter()
             .any(|name| norm.contains(&format!("/{name}/")) || norm.ends_with(&format!("/{name}")));
-        return !whitelisted;
+        return (!whitelisted).then_some(SensitiveReason::AppDataLocal);
     }
 
+    None
+}
+
+// ─── Windows reparse points ───────────────────────────────────────────────────
+
+/// `FILE_ATTRIBUTE_REPARSE_POINT` from the Windows API.
+#[cfg(windows)]
+const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+
+/// Returns `true` if `meta` (from `symlink_metadata`, so the link itself
+/// rather than its target) describes a Windows reparse point.
+///
+/// Junctions and directory symlinks are reparse points, but so are things
+/// like OneDrive placeholders and deduplicated files, which the standard
+/// library doesn't report as symlinks. Any of them can make a recursive
+/// delete end up somewhere unexpected, so a `node_modules` that is one is
+/// flagged sensitive by the scanner and removed as a link by the deleter.
+///
+/// Always `false` on other platforms, where plain symlinks cover the same
+/// ground.
+#[cfg(windows)]
+pub(crate) fn is_reparse_point(meta: &std::fs::Metadata) -> bool {
+    use std::os::windows::fs::MetadataExt;
+    meta.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+}
+
+#[cfg(not(windows))]
+pub(crate) fn is_reparse_point(_meta: &std::fs::Metadata) -> bool {
     false
 }
 
+/// Returns why `path` is sensitive judging by the directory itself: its
+/// location (see [`sensitive_reason`]), or else its being a reparse point,
+/// which may lead anywhere. [`SensitiveReason::Kept`] is never returned;
+/// that comes from the always-keep list, not the directory.
+///
+/// Used to find out what an entry is once it is taken off the list.
+pub fn location_reason(path: &Path) -> Option<SensitiveReason> {
+    reason_with_metadata(path, std::fs::symlink_metadata(path).ok().as_ref())
+}
+
+/// [`location_reason`], given the directory's metadata if it has already
+/// been read.
+fn reason_with_metadata(path: &Path, meta: Option<&std::fs::Metadata>) -> Option<SensitiveReason> {
+    sensitive_reason(path).or_else(|| {
+        meta.is_some_and(is_reparse_point)
+            .then_some(SensitiveReason::ReparsePoint)
+    })
+}
+
 // ─── Public API ───────────────────────────────────────────────────────────────
 
-/// Starts a background scan of `root` and returns a handle to it immediately.
+/// Starts a background scan of `roots` and returns a handle to it
+/// immediately. The roots are walked one after another on the same thread,
+/// into the same channel, so the caller sees a single scan.
 ///
 /// The caller should hold onto the returned [`ActiveScan`] and call
 /// [`crate::app::App::process_scan_messages`] (which reads from
 /// `ActiveScan::rx`) on every event-loop tick until `ActiveScan::done` is
 /// `true`.
 ///
-/// The background thread is intentionally detached — if the caller drops the
-/// `ActiveScan` (e.g. the user quits mid-scan) the thread will finish its
-/// current directory, fail to send on the now-closed channel, and exit cleanly.
-pub fn start_scan(root: String) -> ActiveScan {
+/// The background thread is intentionally detached. Dropping the
+/// `ActiveScan` (e.g. going back to the welcome screen mid-scan) sets its
+/// [`ActiveScan::stop`] flag: the thread finishes the directory in hand —
+/// measuring one `node_modules` isn't interrupted — and exits without
+/// reading any further. It also exits as soon as a send fails because the
+/// receiver is gone.
+pub fn start_scan(roots: Vec<String>, options: ScanOptions) -> ActiveScan {
     let (tx, rx) = mpsc::channel::<ScanMsg>();
     let current_path = Arc::new(Mutex::new(String::new()));
+    let stop = Arc::new(AtomicBool::new(false));
 
     let current_path_clone = Arc::clone(&current_path);
-    std::thread::spawn(move || scan_thread(root, tx, current_path_clone));
+    let stop_clone = Arc::clone(&stop);
+    std::thread::spawn(move || scan_thread(roots, options, tx, current_path_clone, stop_clone));
 
     ActiveScan {
         rx,
         current_path,
         done: false,
+        stop,
     }
 }
 
+/// Measures a single directory on a background thread and returns a channel
+/// that receives its size once, when the walk finishes.
+///
+/// Used to refresh one entry's size after the scan without blocking the UI.
+/// As with [`start_scan`], the thread is detached; if the receiver is dropped
+/// first, the result is simply discarded.
+pub fn start_measure(path: String, disk_usage: bool) -> mpsc::Receiver<u64> {
+    let (tx, rx) = mpsc::channel::<u64>();
+    std::thread::spawn(move || {
+        let _ = tx.send(dir_size(&path, disk_usage));
+    });
+    rx
+}
+
+/// Measures every direct child of `path` on a background thread and returns
+/// a channel that receives them once, as `(name, bytes)` pairs sorted
+/// largest-first.
+///
+/// Used by the breakdown view to show which packages dominate a
+/// `node_modules`. Only one level is listed, but each child directory is
+/// measured in full. Files count their own size, and symlinks count 0
+/// since they aren't followed. Children that can't be read are left out.
+/// Sizes are measured as [`dir_size`] measures them under `disk_usage`.
+/// The thread is detached like [`start_measure`]'s.
+pub fn start_breakdown(path: String, disk_usage: bool) -> mpsc::Receiver<Vec<(String, u64)>> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut children: Vec<(String, u64)> = std::fs::read_dir(&path)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let meta = std::fs::symlink_metadata(e.path()).ok()?;
+                let size = if meta.is_dir() && !is_reparse_point(&meta) {
+                    dir_size(e.path(), disk_usage)
+                } else if meta.is_file() {
+                    file_size(&meta, disk_usage)
+                } else {
+                    0
+                };
+                Some((e.file_name().to_string_lossy().to_string(), size))
+            })
+            .collect();
+        children.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let _ = tx.send(children);
+    });
+    rx
+}
+
+/// Returns the directory `root` names as one canonical absolute path: `..`
+/// and symlinks resolved, so two spellings of the same directory come out
+/// the same. A root that can't be resolved — one that doesn't exist, say —
+/// is only made absolute, and one that can't even be that is kept as given.
+///
+/// On Windows the `\\?\` prefix [`std::fs::canonicalize`] adds to a drive
+/// path is dropped, so paths look as they do everywhere else and the
+/// [`sensitive_reason`] rules still apply to them.
+pub fn resolve_root(root: &str) -> PathBuf {
+    match std::fs::canonicalize(root) {
+        Ok(path) => strip_verbatim(path),
+        Err(_) => std::path::absolute(root).unwrap_or_else(|_| root.into()),
+    }
+}
+
+/// Turns `\\?\C:\…` into `C:\…`. Verbatim UNC paths (`\\?\UNC\…`) are
+/// left as they are.
+#[cfg(windows)]
+fn strip_verbatim(path: PathBuf) -> PathBuf {
+    let stripped = path
+        .to_str()
+        .and_then(|text| text.strip_prefix(r"\\?\"))
+        .filter(|rest| !rest.starts_with(r"UNC\"))
+        .map(PathBuf::from);
+    stripped.unwrap_or(path)
+}
+
+#[cfg(not(windows))]
+fn strip_verbatim(path: PathBuf) -> PathBuf {
+    path
+}
+
 // ─── Background thread ────────────────────────────────────────────────────────
 
 /// The function that runs on the background scanner thread.
 ///
+/// Walks each of `roots` in turn with [`walk_root`], then sends a single
+/// [`ScanMsg::Done`]. Roots may overlap — one inside another, or two
+/// spellings of the same directory, which [`resolve_root`] turns into one —
+/// so a path that has already been reported is not reported again.
+/// [`ScanOptions::max_results`] counts across all roots.
+///
+/// Once `stop` is set the remaining roots are skipped and nothing more is
+/// sent — nobody is listening for `Done`.
+fn scan_thread(
+    roots: Vec<String>,
+    options: ScanOptions,
+    tx: Sender<ScanMsg>,
+    current_path: Arc<Mutex<String>>,
+    stop: Arc<AtomicBool>,
+) {
+    let mut seen = HashSet::new();
+    let mut listed = 0;
+    let mut truncated = false;
+    for root in roots {
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        if walk_root(
+            root,
+            &options,
+            &tx,
+            &current_path,
+            &stop,
+            &mut seen,
+            &mut listed,
+        ) {
+            truncated = true;
+            break;
+        }
+    }
+    if !stop.load(Ordering::Relaxed) {
+        tx.send(ScanMsg::Done { truncated }).ok();
+    }
+}
+
+/// Returns whether `name` is one of the directory names the scan looks for.
+fn is_target(targets: &[String], name: &std::ffi::OsStr) -> bool {
+    let name = name.to_string_lossy();
+    targets.iter().any(|t| *t == name)
+}
+
 /// Walks the directory tree rooted at `root` using `jwalk`, which reads
 /// directory contents in parallel using a thread pool internally.
 ///
@@ -317,51 +763,203 @@ pub fn start_scan(root: String) -> ActiveScan {
 ///    `node_modules` but not any nested ones inside packages.
 ///
 /// For each `node_modules` directory found, a [`ScanMsg::Found`] is sent with
-/// its path, size, sensitivity flag, and last-modified time.
-///
-/// When the walk is complete, a final [`ScanMsg::Done`] is sent.
-fn scan_thread(root: String, tx: Sender<ScanMsg>, current_path: Arc<Mutex<String>>) {
-    let walker = WalkDir::new(&root).skip_hidden(false).process_read_dir({
-        let cp = Arc::clone(&current_path);
-        move |_depth, path, _state, children| {
-            // Update the live "currently scanning" display.
-            if let Ok(mut g) = cp.lock() {
-                *g = path.to_string_lossy().to_string();
-            }
-            // Tell jwalk not to recurse into any node_modules it sees as
-            // children of the current directory. We will report the directory
-            // itself (in the loop below) but we don't want to walk inside it —
-            // that would both be slow and produce spurious nested results.
-            for de in children.iter_mut().flatten() {
-                if de.file_name().to_string_lossy() == "node_modules" && de.file_type().is_dir() {
-                    de.read_children_path = None;
+/// its path, size, sensitivity flag, and last-modified time. A `node_modules`
+/// that is a symlink to a directory (some setups link it to a shared store)
+/// is reported too, flagged as a symlink with a size of 0. On Windows, a
+/// `node_modules` that is a junction or other reparse point is also flagged
+/// sensitive (see [`is_reparse_point`]). With
+/// [`ScanOptions::skip_sizes`] every size is 0 and nothing is measured, and
+/// with [`ScanOptions::root_marker`] only directories inside a marked
+/// project are reported. Directories matching [`ScanOptions::ignore`] or
+/// [`ScanOptions::exclude`], or deeper than [`ScanOptions::max_depth`], are
+/// skipped entirely, and with [`ScanOptions::one_file_system`] the walk
+/// doesn't cross into another filesystem (see [`device_of`]).
+///
+/// Paths already in `seen` are skipped, and reported ones are added to it;
+/// `listed` counts those the list would show (see [`ScanOptions::lists`]).
+/// The walk ends when the tree is exhausted or another such result turns up
+/// once `listed` has reached [`ScanOptions::max_results`]; the latter
+/// returns `true`, meaning the scan was truncated. Stopping early drops the
+/// walker, which stops it reading any further directories.
+///
+/// The walk also ends, returning `false`, once `stop` is set or a send fails
+/// because the receiver has gone; a failed send sets `stop` itself, so
+/// [`scan_thread`] knows not to go on to the next root. Directories read
+/// after `stop` is set have their children dropped, so the walker winds
+/// down without opening anything new.
+///
+/// `root` is resolved with [`resolve_root`] before the walk starts, so every
+/// reported path is absolute, and the same directory reached through two
+/// spellings of a root is reported once, however the roots were given on
+/// the command line. `seen` holds each entry with its parent resolved the
+/// same way.
+///
+/// Everything said here of `node_modules` holds for each name in
+/// [`ScanOptions::targets`].
+fn walk_root(
+    root: String,
+    options: &ScanOptions,
+    tx: &Sender<ScanMsg>,
+    current_path: &Arc<Mutex<String>>,
+    stop: &Arc<AtomicBool>,
+    seen: &mut HashSet<PathBuf>,
+    listed: &mut usize,
+) -> bool {
+    // Walk from an absolute root so every path the walker yields is absolute
+    // too — [`sensitive_reason`] relies on that.
+    let root = resolve_root(&root);
+
+    // With a root marker, each directory carries a flag saying whether it
+    // lies inside a marked project. The flag is the walker's per-branch
+    // state: it starts out set if the marker sits anywhere above the root,
+    // and is switched on for a subtree as soon as a directory is seen to
+    // contain the marker. Every entry records the flag of the directory it
+    // was found in.
+    let marker = options.root_marker.clone();
+    let targets = options.target_names();
+    let ignore = options.ignore.clone();
+    let exclude = options.exclude.clone();
+    let root_device = options.one_file_system.then(|| device_of(&root)).flatten();
+    let root_in_project = marker
+        .as_ref()
+        .is_some_and(|m| root.ancestors().skip(1).any(|a| a.join(m).exists()));
+
+    let walker = WalkDirGeneric::<(bool, bool)>::new(&root)
+        .skip_hidden(false)
+        .max_depth(options.max_depth.unwrap_or(usize::MAX))
+        .root_read_dir_state(root_in_project)
+        .process_read_dir({
+            let cp = Arc::clone(current_path);
+            let targets = targets.clone();
+            let stop = Arc::clone(stop);
+            move |_depth, path, in_project, children| {
+                if stop.load(Ordering::Relaxed) {
+                    children.clear();
+                    return;
+                }
+                // Update the live "currently scanning" display.
+                if let Ok(mut g) = cp.lock() {
+                    *g = path.to_string_lossy().to_string();
+                }
+                // Ignored directories are dropped before jwalk sees them,
+                // so they are neither reported nor walked.
+                if !ignore.is_empty() {
+                    children.retain(|de| {
+                        de.as_ref().map_or(true, |de| {
+                            !(de.file_type().is_dir() && ignore.is_match(de.path()))
+                        })
+                    });
+                }
+                // Excluded paths go the same way, whatever they are — a
+                // symlinked node_modules included.
+                if !exclude.is_empty() {
+                    children
+                        .retain(|de| de.as_ref().map_or(true, |de| !exclude.is_match(de.path())));
+                }
+                // A directory holding the marker turns the flag on for
+                // everything below it.
+                if let Some(m) = &marker
+                    && children
+                        .iter()
+                        .flatten()
+                        .any(|de| de.file_name().to_string_lossy() == m.as_str())
+                {
+                    *in_project = true;
+                }
+                for de in children.iter_mut().flatten() {
+                    de.client_state = *in_project;
+                }
+                // Tell jwalk not to recurse into any target (node_modules,
+                // normally) it sees as children of the current directory. We
+                // will report the directory itself (in the loop below) but we
+                // don't want to walk inside it — that would both be slow and
+                // produce spurious nested results.
+                for de in children.iter_mut().flatten() {
+                    if de.file_type().is_dir() && is_target(&targets, de.file_name()) {
+                        de.read_children_path = None;
+                    }
+                }
+                // Nor into a directory on another filesystem, which is
+                // still listed itself — as `find -xdev` lists a mount point.
+                if let Some(device) = root_device {
+                    for de in children.iter_mut().flatten() {
+                        if de.file_type().is_dir() && device_of(&de.path()) != Some(device) {
+                            de.read_children_path = None;
+                        }
+                    }
                 }
             }
-        }
-    });
+        });
 
+    let now = now_secs();
     for entry in walker.into_iter().filter_map(|e| e.ok()) {
-        if entry.file_name().to_string_lossy() == "node_modules" && entry.file_type().is_dir() {
-            let path = entry.path();
-            let size = dir_size(&path);
-            let sensitive = is_sensitive_dir(&path);
-            let last_modified = std::fs::metadata(&path)
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        if !is_target(&targets, entry.file_name()) {
+            continue;
+        }
+        if options.root_marker.is_some() && !entry.client_state {
+            continue;
+        }
+        let path = entry.path();
+        // The walker doesn't follow symlinks, so a linked node_modules shows
+        // up as a symlink rather than a directory. Report it if it points at
+        // a directory, flagged so the deleter only ever removes the link.
+        let symlink = entry.file_type().is_symlink();
+        let is_dir = entry.file_type().is_dir() || (symlink && path.is_dir());
+        if !is_dir {
+            continue;
+        }
+        // Only the directory holding the entry is resolved: a linked
+        // node_modules is a different thing from the directory it points at.
+        let key = path
+            .parent()
+            .and_then(|p| p.canonicalize().ok())
+            .map_or_else(|| path.clone(), |p| p.join(entry.file_name()));
+        if seen.contains(&key) {
+            continue;
+        }
+
+        let size = if symlink || options.skip_sizes {
+            0
+        } else {
+            dir_size(&path, options.disk_usage)
+        };
+        let meta = std::fs::symlink_metadata(&path).ok();
+        let kept = options
+            .keep
+            .contains(&config::normalize_path(&path.to_string_lossy()));
+        let sensitive = if kept {
+            Some(SensitiveReason::Kept)
+        } else {
+            reason_with_metadata(&path, meta.as_ref())
+        };
+        let last_modified = meta.and_then(|m| m.modified().ok()).and_then(|t| {
+            t.duration_since(std::time::UNIX_EPOCH)
                 .ok()
-                .and_then(|m| m.modified().ok())
-                .and_then(|t| {
-                    t.duration_since(std::time::UNIX_EPOCH)
-                        .ok()
-                        .map(|d| d.as_secs())
-                });
-            tx.send(ScanMsg::Found {
-                path: path.to_string_lossy().to_string(),
-                size,
-                sensitive,
-                last_modified,
-            })
-            .ok();
+                .map(|d| d.as_secs())
+        });
+        // Only a result the list would show counts towards the cap, and
+        // only one past it means something was left out: a tree with
+        // exactly `max_results` of them is reported in full.
+        let lists = options.lists(size, sensitive.is_some(), last_modified, now);
+        if lists && options.max_results.is_some_and(|max| *listed >= max) {
+            return true;
         }
+        let sent = tx.send(ScanMsg::Found {
+            path: path.to_string_lossy().to_string(),
+            size,
+            sensitive,
+            last_modified,
+            symlink,
+        });
+        if sent.is_err() {
+            stop.store(true, Ordering::Relaxed);
+            return false;
+        }
+        seen.insert(key);
+        *listed += usize::from(lists);
     }
-
-    tx.send(ScanMsg::Done).ok();
+    false
 }
@@ -0,0 +1,91 @@
+//! # Headline totals
+//!
+//! With `--stats-only`, killnode doesn't start the TUI or list anything. It
+//! scans and prints how many `node_modules` it found and how much space they
+//! take, which is all a quick "how bad is it" check — or a report mailed by
+//! cron — needs:
+//!
+//! ```text
+//! $ killnode --stats-only ~/projects
+//! 14 node_modules directories totaling 3.2 GB
+//! 3 of them in sensitive locations (1.1 GB)
+//! ```
+//!
+//! The second line only appears when something sensitive was found. Under
+//! `--no-size` nothing is measured, so the first line says so instead of
+//! giving a total.
+
+use crossterm::style::Stylize;
+
+use crate::{
+    app::NodeModuleEntry,
+    ui::{count_noun, format_bytes, format_count},
+};
+
+// ─── Totals ───────────────────────────────────────────────────────────────────
+
+/// How many entries a scan found and how big they are, overall and for the
+/// sensitive ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub count: usize,
+    pub bytes: u64,
+    pub sensitive: usize,
+    pub sensitive_bytes: u64,
+}
+
+impl Totals {
+    /// Adds up `entries`.
+    pub fn of(entries: &[NodeModuleEntry]) -> Self {
+        entries.iter().fold(Self::default(), |mut t, e| {
+            t.count += 1;
+            t.bytes += e.size;
+            if e.sensitive.is_some() {
+                t.sensitive += 1;
+                t.sensitive_bytes += e.size;
+            }
+            t
+        })
+    }
+}
+
+// ─── Output ───────────────────────────────────────────────────────────────────
+
+/// The report for `totals`, one line per fact and without a trailing
+/// newline. `sizes` is false under `--no-size`. With `color`, the numbers
+/// are bold and the sensitive line is yellow, as in the list.
+pub fn summary(totals: &Totals, sizes: bool, color: bool) -> String {
+    let paint = |text: String, style: fn(String) -> String| {
+        if color { style(text) } else { text }
+    };
+    if totals.count == 0 {
+        return "No node_modules directories found".to_string();
+    }
+
+    let count = paint(
+        count_noun(
+            totals.count,
+            "node_modules directory",
+            "node_modules directories",
+        ),
+        |t| t.bold().to_string(),
+    );
+    let mut lines = vec![if sizes {
+        let bytes = paint(format_bytes(totals.bytes), |t| t.bold().to_string());
+        format!("{count} totaling {bytes}")
+    } else {
+        format!("{count} (sizes not measured)")
+    }];
+
+    if totals.sensitive > 0 {
+        let mut line = format!(
+            "{} of them in sensitive locations",
+            format_count(totals.sensitive)
+        );
+        if sizes {
+            line.push_str(&format!(" ({})", format_bytes(totals.sensitive_bytes)));
+        }
+        lines.push(paint(line, |t| t.yellow().to_string()));
+    }
+    lines.join("\n")
+}
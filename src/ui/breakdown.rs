@@ -0,0 +1,141 @@
+//! # Breakdown popup
+//!
+//! An overlay on top of the list, opened with `d`, that answers "what am I
+//! actually deleting?" for the highlighted entry. It lists the largest
+//! direct children of the `node_modules` — normally one per package, or per
+//! `@scope` — with a bar showing each one's share.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ┌─ Breakdown  ·  …/my-app/node_modules ──────────────────────┐
+//! │  typescript     ████████████████████████████       23.1 MB  │
+//! │  @babel         ██████████████                     11.8 MB  │
+//! │  esbuild        ██████████                          8.4 MB  │
+//! │  …                                                          │
+//! │  + 214 more                                        19.7 MB  │
+//! ├─────────────────────────────────────────────────────────────┤
+//! │  [d / Esc] Close                                            │
+//! └─────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! The children are measured on a background thread when the popup opens,
+//! so a spinner is shown until the numbers arrive.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::{
+    SPINNER, centered_rect, format_bytes, format_count, help_bar, inner_area, truncate_left,
+};
+use crate::app::App;
+
+/// How many children are listed by name. The rest are summed on one line.
+pub const BREAKDOWN_TOP: usize = 10;
+
+/// Width of the package-name column, in characters.
+const NAME_W: usize = 24;
+
+/// Width of the size column, in characters.
+const SIZE_W: usize = 10;
+
+/// Renders the breakdown popup for [`App::breakdown_path`] on top of
+/// whatever was already drawn.
+pub fn render_breakdown(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
+    // Room for every listed child, the "more" line, and the help bar.
+    let popup_area = centered_rect(70, BREAKDOWN_TOP as u16 + 5, area);
+    f.render_widget(Clear, popup_area);
+
+    // ── Popup border ──────────────────────────────────────────────────────────
+    let path = app.breakdown_path.as_deref().unwrap_or_default();
+    let title_room = (popup_area.width as usize).saturating_sub(18);
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.info))
+        .title(Span::styled(
+            format!(" Breakdown  ·  {} ", truncate_left(path, title_room)),
+            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+        ));
+    f.render_widget(popup_block, popup_area);
+
+    let inner = inner_area(popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // children
+            Constraint::Length(2), // help bar
+        ])
+        .split(inner);
+
+    // ── Children ──────────────────────────────────────────────────────────────
+    //
+    // Bars are scaled to the largest child rather than to the total, so the
+    // top package always fills the bar and the rest read relative to it.
+    let lines = match &app.breakdown {
+        None => {
+            let spinner = SPINNER[(app.ticker / 2) as usize % SPINNER.len()];
+            vec![Line::from(Span::styled(
+                format!("  {spinner} Measuring…"),
+                Style::default().fg(theme.warning),
+            ))]
+        }
+        Some(children) if children.is_empty() => {
+            vec![Line::from(Span::styled("  Empty.", theme.dim))]
+        }
+        Some(children) => {
+            let bar_w = (chunks[0].width as usize).saturating_sub(NAME_W + SIZE_W + 6);
+            let largest = children.first().map_or(0, |c| c.1).max(1);
+
+            let mut lines: Vec<Line> = children
+                .iter()
+                .take(BREAKDOWN_TOP)
+                .map(|(name, size)| {
+                    let filled = (*size as u128 * bar_w as u128 / largest as u128) as usize;
+                    Line::from(vec![
+                        Span::styled(
+                            format!("  {:<NAME_W$} ", truncate_left(name, NAME_W)),
+                            Style::default().fg(theme.text),
+                        ),
+                        Span::styled(
+                            format!("{:<bar_w$}", "█".repeat(filled)),
+                            Style::default().fg(theme.size_color(*size)),
+                        ),
+                        Span::styled(
+                            format!(" {:>SIZE_W$}", format_bytes(*size)),
+                            Style::default().fg(theme.size_color(*size)),
+                        ),
+                    ])
+                })
+                .collect();
+
+            if children.len() > BREAKDOWN_TOP {
+                let rest = &children[BREAKDOWN_TOP..];
+                let rest_size: u64 = rest.iter().map(|c| c.1).sum();
+                let label = format!("+ {} more", format_count(rest.len()));
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {label:<NAME_W$} {:bar_w$}", ""), theme.dim),
+                    Span::styled(format!(" {:>SIZE_W$}", format_bytes(rest_size)), theme.dim),
+                ]));
+            }
+            lines
+        }
+    };
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, HINTS), chunks[1]);
+}
+
+/// The keys that work on the breakdown, for its help bar and the `?`
+/// overlay.
+pub const HINTS: &[(&str, &str)] = &[("d / Esc", "Close"), ("?", "Help")];
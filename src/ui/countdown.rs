@@ -0,0 +1,149 @@
+//! # Countdown popup
+//!
+//! Confirming a deletion that includes sensitive entries doesn't start it
+//! straight away: this popup, drawn over the list, counts down
+//! [`SENSITIVE_COUNTDOWN`](crate::app::SENSITIVE_COUNTDOWN) first, with Esc
+//! still backing out. Deletions with nothing sensitive in them never see it.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ┌─ Deleting sensitive directories ─┐
+//! │                                  │
+//! │              ████                │
+//! │                 █                │
+//! │              ████                │
+//! │                 █                │
+//! │              ████                │
+//! │                                  │
+//! │  2 sensitive directories queued  │
+//! ├──────────────────────────────────┤
+//! │  [Esc] Cancel   [?] Help         │
+//! └──────────────────────────────────┘
+//! ```
+//!
+//! The number is drawn in block digits, five rows tall, so it can't be
+//! missed while glancing elsewhere. It pulses with the animation ticker;
+//! the time itself comes from the clock, see [`App::countdown_until`].
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::{centered_rect, count_noun, help_bar, inner_area};
+use crate::app::App;
+
+/// Each digit 0–9 as five rows of four cells, `#` for a filled cell.
+const DIGITS: [[&str; 5]; 10] = [
+    ["####", "#  #", "#  #", "#  #", "####"],
+    ["   #", "   #", "   #", "   #", "   #"],
+    ["####", "   #", "####", "#   ", "####"],
+    ["####", "   #", "####", "   #", "####"],
+    ["#  #", "#  #", "####", "   #", "   #"],
+    ["####", "#   ", "####", "   #", "####"],
+    ["####", "#   ", "####", "#  #", "####"],
+    ["####", "   #", "   #", "   #", "   #"],
+    ["####", "#  #", "####", "#  #", "####"],
+    ["####", "#  #", "####", "   #", "####"],
+];
+
+/// Returns `n` as five rows of block digits, one cell apart.
+pub fn big_number(n: u64) -> Vec<String> {
+    let digits: Vec<usize> = n
+        .to_string()
+        .bytes()
+        .map(|b| usize::from(b - b'0'))
+        .collect();
+    (0..5)
+        .map(|row| {
+            digits
+                .iter()
+                .map(|&d| DIGITS[d][row].replace('#', "█"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// Renders the countdown popup on top of whatever was already drawn.
+pub fn render_countdown(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
+    // Whole seconds left, rounded up so "0" never shows while still waiting.
+    let remaining = app.countdown_remaining().unwrap_or_default();
+    let seconds = remaining.as_millis().div_ceil(1000) as u64;
+    let sensitive = app
+        .queued_entries()
+        .iter()
+        .filter(|e| e.sensitive.is_some())
+        .count();
+
+    // The digits, a blank row either side, the note, and the help bar.
+    let popup_area = centered_rect(50, 13, area);
+    f.render_widget(Clear, popup_area);
+
+    // ── Popup border ──────────────────────────────────────────────────────────
+    //
+    // Red like the sensitive review: this is the last moment to back out.
+    let danger = Style::default()
+        .fg(theme.danger)
+        .add_modifier(Modifier::BOLD);
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(danger)
+        .title(Span::styled(" Deleting sensitive directories ", danger));
+    f.render_widget(popup_block, popup_area);
+
+    let inner = inner_area(popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7), // digits, padded
+            Constraint::Min(0),    // note
+            Constraint::Length(2), // help bar
+        ])
+        .split(inner);
+
+    // ── Digits ────────────────────────────────────────────────────────────────
+    //
+    // Bold on alternate beats of the ticker, so the number visibly pulses.
+    let pulse = if (app.ticker / 4).is_multiple_of(2) {
+        danger
+    } else {
+        Style::default().fg(theme.danger)
+    };
+    let mut lines = vec![Line::default()];
+    lines.extend(
+        big_number(seconds)
+            .into_iter()
+            .map(|row| Line::from(Span::styled(row, pulse))),
+    );
+    f.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center),
+        chunks[0],
+    );
+
+    // ── Note ──────────────────────────────────────────────────────────────────
+    let note = Line::from(Span::styled(
+        format!(
+            "{} queued",
+            count_noun(sensitive, "sensitive directory", "sensitive directories")
+        ),
+        theme.dim,
+    ));
+    f.render_widget(Paragraph::new(note).alignment(Alignment::Center), chunks[1]);
+
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, HINTS), chunks[2]);
+}
+
+/// The keys that work during the countdown, for its help bar and the `?`
+/// overlay.
+pub const HINTS: &[(&str, &str)] = &[("Esc", "Cancel"), ("?", "Help")];
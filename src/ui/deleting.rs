@@ -1,9 +1,10 @@
 //! # Deleting screen
 //!
 //! Shown while the background delete thread is working through the list of
-//! selected directories. The user cannot interact with this screen — input is
-//! intentionally blocked until deletion finishes so nothing can interrupt a
-//! `remove_dir_all` call that is already in progress.
+//! selected directories. The only thing to do here is stop: Q, Esc or Ctrl-C
+//! cancels, letting the directory in hand finish — nothing interrupts a
+//! `remove_dir_all` call that is already in progress — and skipping the
+//! rest. Ctrl-C (or Ctrl-Q) a second time is the emergency exit.
 //!
 //! ## Layout
 //!
@@ -17,26 +18,37 @@
 //! │  Removing:                                                    │
 //! │  …/old-project/node_modules                                   │
 //! │                                                               │
+//! │  Freed so far: 1.2 GB                                         │
+//! │  Remaining:    ~12 s                                          │
+//! │                                                               │
+//! ├───────────────────────────────────────────────────────────────┤
+//! │  [Q / Esc] Cancel    [Ctrl-C ×2] Force quit                   │
 //! └───────────────────────────────────────────────────────────────┘
 //! ```
 //!
-//! There is no help bar on this screen because no keys are active. The spinner
-//! in the title and the advancing progress gauge are the only live elements.
+//! Once cancelled, the "Removing:" label says the run is stopping. The
+//! spinner in the title, the advancing progress gauge, the freed total and
+//! the estimate of the time left are the only live elements.
 
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Gauge, Paragraph},
 };
 
-use super::{SPINNER, inner_area, truncate_left};
+use std::time::Duration;
+
+use super::{
+    SPINNER, border_style, format_bytes, format_count, format_duration, help_bar, inner_area,
+    truncate_left,
+};
 use crate::app::App;
 
 /// Renders the deleting screen into `f`.
 ///
-/// The layout has six vertical regions:
+/// The layout has nine vertical regions:
 ///
 /// 1. **Top spacer** — a single blank row so the progress gauge doesn't sit
 ///    flush against the outer border, giving it room to breathe.
@@ -55,10 +67,24 @@ use crate::app::App;
 ///    via [`truncate_left`] so the meaningful part (the end of the path) stays
 ///    visible even on narrow terminals.
 ///
-/// 6. **Bottom spacer** — fills remaining vertical space so the content block
+/// 6. **Spacer** — a blank row separating the path from the total below.
+///
+/// 7. **Freed so far** — the bytes reclaimed by the directories finished in
+///    this run, counted only for those removed successfully, as on the
+///    Done screen. Under `--no-size` nothing is measured, and it says so.
+///
+/// 8. **Remaining** — a rough estimate of the time left, from
+///    [`App::delete_eta`]. Until the first directory is done it says it is
+///    estimating; between then and the estimate settling it is blank.
+///
+/// 9. **Bottom spacer** — fills remaining vertical space so the content block
 ///    sits near the top rather than being stretched to fill the whole screen.
 pub fn render_deleting(f: &mut Frame, app: &App) {
     let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
 
     // Advance the spinner the same way the scanning screen does: divide the
     // raw ticker by 2 so each frame is held for ~160 ms instead of ~80 ms.
@@ -70,10 +96,12 @@ pub fn render_deleting(f: &mut Frame, app: &App) {
     // The spinner makes it immediately obvious that the app is busy.
     let outer = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Reset))
+        .border_style(border_style(&theme, &app.screen))
         .title(Span::styled(
             format!(" 💥 killnode  {}  Deleting… ", spinner),
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
         ));
     f.render_widget(outer, area);
 
@@ -88,7 +116,11 @@ pub fn render_deleting(f: &mut Frame, app: &App) {
             Constraint::Length(1), // middle spacer
             Constraint::Length(1), // "Removing:" label
             Constraint::Length(1), // currently-deleting path
+            Constraint::Length(1), // spacer
+            Constraint::Length(1), // freed so far
+            Constraint::Length(1), // time remaining
             Constraint::Min(0),    // bottom spacer — expands to fill remaining height
+            Constraint::Length(3), // help bar (1 line of hints + top border + padding)
         ])
         .split(inner);
 
@@ -99,23 +131,26 @@ pub fn render_deleting(f: &mut Frame, app: &App) {
     // somehow 0 (which the UI prevents, but is handled defensively here).
     let total = app.delete_total.max(1);
     let ratio = app.delete_done as f64 / total as f64;
-    let label = format!("{} / {}", app.delete_done, app.delete_total);
+    let label = format!(
+        "{} / {}",
+        format_count(app.delete_done),
+        format_count(app.delete_total)
+    );
 
     let gauge = Gauge::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Reset))
-                .title(Span::styled(
-                    " Progress ",
-                    Style::default()
-                        .fg(Color::Reset)
-                        .add_modifier(Modifier::DIM),
-                )),
+                .border_style(Style::default().fg(theme.text))
+                .title(Span::styled(" Progress ", theme.dim)),
         )
         // Red fill on a dark-gray track so the remaining work is visible
         // even when only a small fraction has been completed.
-        .gauge_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        .gauge_style(
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
+        )
         .ratio(ratio)
         .label(label);
 
@@ -125,15 +160,23 @@ pub fn render_deleting(f: &mut Frame, app: &App) {
     //
     // A plain dim label so the path below it doesn't look like a random
     // string floating on screen without context.
-    f.render_widget(
-        Paragraph::new(Line::from(Span::styled(
-            "  Removing:",
-            Style::default()
-                .fg(Color::Reset)
-                .add_modifier(Modifier::DIM),
-        ))),
-        chunks[3],
-    );
+    //
+    // While another killnode holds the delete lock, this says so instead:
+    // nothing is being removed yet, and the gauge won't move until it is.
+    let label = if app.delete_cancelled() {
+        Span::styled(
+            "  Cancelling — finishing the current directory, skipping the rest…",
+            Style::default().fg(theme.warning),
+        )
+    } else if app.delete_waiting {
+        Span::styled(
+            "  Waiting for another killnode to finish deleting…",
+            Style::default().fg(theme.warning),
+        )
+    } else {
+        Span::styled("  Removing:", theme.dim)
+    };
+    f.render_widget(Paragraph::new(Line::from(label)), chunks[3]);
 
     // ── Current path ──────────────────────────────────────────────────────────
     //
@@ -150,10 +193,80 @@ pub fn render_deleting(f: &mut Frame, app: &App) {
     f.render_widget(
         Paragraph::new(Line::from(Span::styled(
             format!("  {display_path}"),
-            Style::default()
-                .fg(Color::Reset)
-                .add_modifier(Modifier::DIM),
+            theme.dim,
         ))),
         chunks[4],
     );
+
+    // ── Freed so far ──────────────────────────────────────────────────────────
+    //
+    // Lags one directory behind the path above: the total arrives with the
+    // same message, so it covers everything finished before this one.
+    let freed = if app.sizes_known() {
+        format_bytes(app.delete_run_freed)
+    } else {
+        "not measured (--no-size)".to_string()
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("  Freed so far: ", theme.dim),
+            Span::styled(
+                freed,
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])),
+        chunks[6],
+    );
+
+    // ── Remaining ─────────────────────────────────────────────────────────────
+    let estimate = if let Some(eta) = app.delete_eta() {
+        Some(format!("~{}", format_eta(eta)))
+    } else if app.delete_done == 0 && !app.delete_waiting && !app.delete_cancelled() {
+        Some("estimating…".to_string())
+    } else {
+        None
+    };
+    if let Some(estimate) = estimate {
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("  Remaining:    ", theme.dim),
+                Span::styled(estimate, Style::default().fg(theme.text)),
+            ])),
+            chunks[7],
+        );
+    }
+
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, hints(app)), chunks[9]);
+}
+
+/// Formats an estimate in whole seconds, rounded up: a tenth of a second is
+/// more precision than an extrapolation has.
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs() + u64::from(eta.subsec_nanos() > 0);
+    if secs >= 60 {
+        format_duration(Duration::from_secs(secs))
+    } else {
+        format!("{secs} s")
+    }
+}
+
+/// The keys that work while deleting, for the help bar and the `?` overlay.
+///
+/// Cancelling and the force quit are the only ones — and once the force
+/// quit is armed, the hint says what the next press will do.
+pub fn hints(app: &App) -> &'static [(&'static str, &'static str)] {
+    if app.force_quit_armed {
+        &[("Ctrl-C", "Again to force quit, abandoning the delete")]
+    } else if app.delete_cancelled() {
+        &[("Ctrl-C ×2", "Force quit"), ("?", "Help")]
+    } else {
+        &[
+            ("Q / Esc", "Cancel"),
+            ("Ctrl-C ×2", "Force quit"),
+            ("?", "Help"),
+        ]
+    }
 }
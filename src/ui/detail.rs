@@ -0,0 +1,169 @@
+//! # Detail popup
+//!
+//! An overlay on top of the list, opened with `I` (or Enter with nothing
+//! selected), that shows everything killnode knows about the highlighted
+//! entry — the things a single list row has to truncate or leave out.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ┌─ Detail ────────────────────────────────────────────────────┐
+//! │  Path       /home/alice/projects/clients/acme/web-shop/     │
+//! │             storefront/node_modules                         │
+//! │  Project    storefront                                      │
+//! │  Size       412.87 MB  (412,872,301 bytes)                  │
+//! │  Modified   2025-10-09 14:03 UTC                            │
+//! │  Sensitive  no                                              │
+//! ├─────────────────────────────────────────────────────────────┤
+//! │  [I / Esc] Close                                            │
+//! └─────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! The path is wrapped rather than truncated, so it is always shown in
+//! full. Dates are in UTC: killnode has no time-zone database to consult.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::{centered_rect, format_bytes, format_count, help_bar, inner_area};
+use crate::app::App;
+
+/// Width of the label column, in characters.
+const LABEL_W: usize = 11;
+
+/// Renders the detail popup for [`App::detail_entry`] on top of whatever
+/// was already drawn.
+pub fn render_detail(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let Some(entry) = app.detail_entry() else {
+        return;
+    };
+    let theme = app.theme;
+
+    // The path gets as many rows as it needs at the popup's width; the
+    // popup is sized to fit them, up to the height of the terminal.
+    let width = centered_rect(70, 0, area).width as usize;
+    let path_w = width.saturating_sub(LABEL_W + 6).max(1);
+    let path_rows: Vec<String> = entry
+        .path
+        .chars()
+        .collect::<Vec<_>>()
+        .chunks(path_w)
+        .map(|c| c.iter().collect())
+        .collect();
+    // Four fixed fields, the help bar and borders.
+    let fields = path_rows.len() as u16 + 4;
+    let popup_area = centered_rect(70, fields + 4, area);
+    f.render_widget(Clear, popup_area);
+
+    // ── Popup border ──────────────────────────────────────────────────────────
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.info))
+        .title(Span::styled(
+            " Detail ",
+            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+        ));
+    f.render_widget(popup_block, popup_area);
+
+    let inner = inner_area(popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // fields
+            Constraint::Length(2), // help bar
+        ])
+        .split(inner);
+
+    // ── Fields ────────────────────────────────────────────────────────────────
+    let row = |label: &str, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!("  {label:<LABEL_W$}"), theme.dim),
+            Span::styled(value, style),
+        ])
+    };
+    let text = Style::default().fg(theme.text);
+
+    let mut lines: Vec<Line> = path_rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, part)| row(if i == 0 { "Path" } else { "" }, part, text))
+        .collect();
+
+    // The folder holding the node_modules is, almost always, the project.
+    let project = std::path::Path::new(&entry.path)
+        .parent()
+        .and_then(|p| p.file_name())
+        .map_or("—".to_string(), |n| n.to_string_lossy().to_string());
+    lines.push(row("Project", project, text));
+
+    let size = if entry.symlink {
+        "a symlink — only the link is removed".to_string()
+    } else if app.sizes_known() {
+        format!(
+            "{}  ({} bytes)",
+            format_bytes(entry.size),
+            format_count(entry.size as usize)
+        )
+    } else {
+        "not measured (--no-size)".to_string()
+    };
+    lines.push(row(
+        "Size",
+        size,
+        Style::default().fg(theme.size_color(entry.size)),
+    ));
+
+    let modified = entry
+        .last_modified
+        .map_or("unknown".to_string(), format_date);
+    lines.push(row("Modified", modified, text));
+
+    let sensitive = match entry.sensitive {
+        Some(reason) => row(
+            "Sensitive",
+            format!("yes — {}", reason.description()),
+            Style::default().fg(theme.warning),
+        ),
+        None => row("Sensitive", "no".to_string(), text),
+    };
+    lines.push(sensitive);
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, HINTS), chunks[1]);
+}
+
+/// The keys that work on the detail popup, for its help bar and the `?`
+/// overlay.
+pub const HINTS: &[(&str, &str)] = &[("I / Esc", "Close"), ("?", "Help")];
+
+/// Formats `secs` since the Unix epoch as a UTC date and time:
+/// `2025-10-09 14:03 UTC`.
+///
+/// The calendar arithmetic is Howard Hinnant's `civil_from_days`, which is
+/// exact for every date a filesystem can report.
+pub fn format_date(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let (hour, minute) = ((secs % 86_400) / 3600, (secs % 3600) / 60);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02} UTC")
+}
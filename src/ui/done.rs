@@ -2,12 +2,14 @@
 //!
 //! The final screen. Shown when either:
 //!
-//! - The scan finished and the user deleted their selected directories, or
-//! - The scan finished but nothing was found (or the user quit without
-//!   deleting anything).
+//! - Every discovered directory has been deleted, or
+//! - The user quit from the list after deleting at least one directory, or
+//! - The scan finished but nothing was found.
 //!
 //! The screen summarises what happened and, if any deletions failed, lists
 //! each error so the user knows which directories were not cleaned up.
+//! `R` deletes just those again, since a failure is often only a file that
+//! was briefly locked; the retry comes back here with the totals updated.
 //!
 //! ## Layout (normal completion)
 //!
@@ -16,13 +18,16 @@
 //! │                                                               │
 //! │   Removed   12  directories                                   │
 //! │   Freed     2.3 GB                                            │
+//! │   Free space  46.1 GB → 48.4 GB                               │
 //! │   Failed    1  (see errors below)                             │
+//! │   Slowest   4.2 s  …/projects/big-app/node_modules            │
+//! │   Scanned   1.8 s  (40 found, 22.2 per second)                │
 //! │                                                               │
 //! │  ┌─ Errors ────────────────────────────────────────────────┐  │
 //! │  │  ✗  /some/path/node_modules: permission denied          │  │
 //! │  └─────────────────────────────────────────────────────────┘  │
 //! ├───────────────────────────────────────────────────────────────┤
-//! │  [Q / Enter] Quit                                             │
+//! │  [↑↓ / jk] Scroll   [/] Filter   [R] Retry failed   [Q] Quit  │
 //! └───────────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -32,40 +37,61 @@
 //! ┌─ 💥 killnode  ·  Complete ────────────────────────────────────┐
 //! │                                                               │
 //! │   No node_modules found in the specified path.                │
+//! │   Scanned   640 ms  (0 found)                                 │
 //! │                                                               │
 //! ├───────────────────────────────────────────────────────────────┤
 //! │  [Q / Enter] Quit                                             │
 //! └───────────────────────────────────────────────────────────────┘
 //! ```
 
-use humansize::{DECIMAL, format_size};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
 };
 
-use super::{help_bar, inner_area};
-use crate::app::App;
+use std::time::Duration;
+
+use super::{
+    border_style, format_bytes, format_count, format_duration, help_bar, inner_area, truncate_left,
+};
+use crate::{app::App, deleter::CAN_PURGE_TRASH};
+
+/// A single removal taking at least this long is flagged as slow in the
+/// summary. `node_modules` on a local SSD goes in a second or two, even a
+/// big one.
+pub const SLOW_DELETE: Duration = Duration::from_secs(10);
 
 /// Renders the done screen into `f`.
 ///
-/// The layout has three vertical regions:
+/// The layout has four vertical regions:
 ///
-/// 1. **Summary** — four lines that describe the outcome. The content varies
+/// 1. **Summary** — up to eight lines that describe the outcome. The content varies
 ///    depending on whether anything was found and whether anything was deleted.
 ///    See [`build_summary`] for the three cases.
 ///
 /// 2. **Error list** (conditional) — a bordered list of every path that could
 ///    not be deleted, along with the OS error message for each one. Only
 ///    rendered when `app.delete_errors` is non-empty; the region collapses to
-///    nothing when there are no errors.
+///    nothing when there are no errors. The list scrolls with `app.error_list_state`
+///    and can be narrowed with a substring filter (`/`), shown in its title.
+///
+/// 3. **Skipped list** (conditional) — paths a safety option deliberately
+///    left alone, with the reason for each. Shares the region with the error
+///    list when both are present.
 ///
-/// 3. **Help bar** — only one action is available here: quit.
-pub fn render_done(f: &mut Frame, app: &App) {
+/// 4. **Help bar** — quit, plus scrolling and filtering when there are errors.
+///    While the filter is being typed, it shows how to finish instead.
+///
+/// `app` is `&mut` because the error list is a stateful widget.
+pub fn render_done(f: &mut Frame, app: &mut App) {
     let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
 
     // ── Outer border ──────────────────────────────────────────────────────────
     //
@@ -73,11 +99,11 @@ pub fn render_done(f: &mut Frame, app: &App) {
     // the red used while the app is active, so it reads as "all done, relax".
     let outer = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Reset))
+        .border_style(border_style(&theme, &app.screen))
         .title(Span::styled(
             " 💥 killnode  ·  Complete ",
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.success)
                 .add_modifier(Modifier::BOLD),
         ));
     f.render_widget(outer, area);
@@ -88,8 +114,8 @@ pub fn render_done(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(4), // summary block (up to 4 lines of stats)
-            Constraint::Min(0),    // error list — expands to fill height, or collapses if empty
+            Constraint::Length(9), // summary block (up to 9 lines of stats)
+            Constraint::Min(0), // error/skipped lists — expand to fill height, or collapse if empty
             Constraint::Length(3), // help bar (1 line of hints + top border + padding)
         ])
         .split(inner);
@@ -98,7 +124,7 @@ pub fn render_done(f: &mut Frame, app: &App) {
     //
     // The summary is built separately in `build_summary` to keep this function
     // readable. It returns a `Vec<Line>` so Paragraph can render it directly.
-    let summary = build_summary(app);
+    let summary = build_summary(app, chunks[0].width as usize);
     f.render_widget(Paragraph::new(summary), chunks[0]);
 
     // ── Error list ────────────────────────────────────────────────────────────
@@ -107,36 +133,162 @@ pub fn render_done(f: &mut Frame, app: &App) {
     // when the user's eye lands on this region. The list is only rendered when
     // there is something to show — otherwise the space is left empty and the
     // help bar shifts up naturally.
+    //
+    // When some paths were also skipped, the region is split so both lists
+    // are visible; skipped paths get their own yellow box because they are
+    // not failures.
+    let (errors_area, skipped_area) =
+        match (app.delete_errors.is_empty(), app.delete_skipped.is_empty()) {
+            (false, false) => {
+                let halves = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(chunks[1]);
+                (halves[0], halves[1])
+            }
+            _ => (chunks[1], chunks[1]),
+        };
+
     if !app.delete_errors.is_empty() {
         let items: Vec<ListItem> = app
-            .delete_errors
-            .iter()
+            .visible_errors()
+            .into_iter()
             .map(|e| {
                 ListItem::new(Line::from(Span::styled(
                     format!("  ✗  {e}"),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.danger),
                 )))
             })
             .collect();
 
-        let error_list = List::new(items).block(
+        // The title shows the filter (with a cursor while it's being typed)
+        // and how many errors it matches, so an empty list never looks like
+        // "no errors".
+        let title = if app.error_filter.is_empty() && !app.error_filter_editing {
+            " Errors ".to_string()
+        } else {
+            let cursor = if app.error_filter_editing { "▏" } else { "" };
+            format!(
+                " Errors  ·  /{}{cursor}  ·  {} of {} ",
+                app.error_filter,
+                format_count(items.len()),
+                format_count(app.delete_errors.len())
+            )
+        };
+
+        let error_list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.danger))
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(theme.danger)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        f.render_stateful_widget(error_list, errors_area, &mut app.error_list_state);
+    }
+
+    // ── Skipped list ──────────────────────────────────────────────────────────
+    if !app.delete_skipped.is_empty() {
+        let items: Vec<ListItem> = app
+            .delete_skipped
+            .iter()
+            .map(|s| {
+                ListItem::new(Line::from(Span::styled(
+                    format!("  –  {s}"),
+                    Style::default().fg(theme.warning),
+                )))
+            })
+            .collect();
+
+        let skipped_list = List::new(items).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red))
+                .border_style(Style::default().fg(theme.warning))
                 .title(Span::styled(
-                    " Errors ",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    " Skipped ",
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD),
                 )),
         );
-        f.render_widget(error_list, chunks[1]);
+        f.render_widget(skipped_list, skipped_area);
     }
 
     // ── Help bar ──────────────────────────────────────────────────────────────
-    f.render_widget(help_bar(&[("Q / Enter", "Quit")]), chunks[2]);
+    f.render_widget(help_bar(&theme, hints(app)), chunks[2]);
+}
+
+/// The keys that work on the done screen, for the help bar and the `?`
+/// overlay.
+///
+/// Esc clears an active filter before it quits, so the hint says which one
+/// it will do. While the filter is being typed, `?` is typed into it.
+pub fn hints(app: &App) -> &'static [(&'static str, &'static str)] {
+    if app.error_filter_editing {
+        &[
+            ("Enter", "Keep filter"),
+            ("Esc", "Clear filter"),
+            ("Backspace", "Erase"),
+        ]
+    } else if app.delete_errors.is_empty() && app.can_empty_trash() {
+        &[
+            ("E", "Empty from trash"),
+            ("U", "Restore from trash"),
+            ("Q / Enter / Esc", "Quit"),
+            ("?", "Help"),
+        ]
+    } else if app.delete_errors.is_empty() {
+        &[("Q / Enter / Esc", "Quit"), ("?", "Help")]
+    } else if !app.error_filter.is_empty() {
+        &[
+            ("↑↓ / jk", "Scroll"),
+            ("/", "Filter"),
+            ("y", "Copy error"),
+            ("R", "Retry failed"),
+            ("Esc", "Clear filter"),
+            ("Q / Enter", "Quit"),
+            ("?", "Help"),
+        ]
+    } else {
+        &[
+            ("↑↓ / jk", "Scroll"),
+            ("/", "Filter"),
+            ("y", "Copy error"),
+            ("R", "Retry failed"),
+            ("Q / Enter / Esc", "Quit"),
+            ("?", "Help"),
+        ]
+    }
 }
 
 // ─── Summary builder ──────────────────────────────────────────────────────────
 
+/// What to say after the trashed total: how to empty or restore from the
+/// trash, that either is under way, or why it failed. Empty when nothing is
+/// in the trash.
+fn trash_note(app: &App) -> String {
+    if app.trashed.is_empty() {
+        String::new()
+    } else if app.purge_rx.is_some() {
+        "  (emptying from the trash…)".to_string()
+    } else if app.restore_rx.is_some() {
+        "  (restoring from the trash…)".to_string()
+    } else if let Some(e) = &app.purge_error {
+        format!("  (couldn't empty the trash: {e})")
+    } else if let Some(e) = &app.restore_error {
+        format!("  (couldn't restore from the trash: {e})")
+    } else if CAN_PURGE_TRASH {
+        "  (press E to empty these from the trash, U to put them back)".to_string()
+    } else {
+        "  (empty the trash to free the space)".to_string()
+    }
+}
+
 /// Builds the summary paragraph for the done screen.
 ///
 /// There are three distinct cases, each producing different output:
@@ -149,105 +301,275 @@ pub fn render_done(f: &mut Frame, app: &App) {
 ///
 /// ### 2. Scan finished but nothing was deleted
 ///
-/// The user either quit from the list screen without selecting anything, or
-/// pressed Enter but then cancelled at the confirmation prompt. A yellow
-/// message acknowledges that no directories were removed.
+/// Kept as a fallback: quitting from the list without having deleted
+/// anything exits straight away rather than coming here. A yellow message
+/// acknowledges that no directories were removed.
 ///
 /// ### 3. Normal completion
 ///
-/// One or more directories were deleted. The summary shows three stats:
+/// One or more directories were deleted. The summary shows three stats,
+/// covering every deletion run in the session:
 ///
 /// - **Removed** — the number of directories successfully deleted, in green.
 /// - **Freed** — the total bytes reclaimed, in cyan (formatted as KB/MB/GB).
-/// - **Failed** — the number of errors, in red. Only shown when > 0.
-fn build_summary(app: &App) -> Vec<Line<'static>> {
-    // Case 1: the scan found nothing at all.
-    if app.entries.is_empty() {
-        return vec![
+/// - **Restored** — how many trashed directories `U` put back. Only shown
+///   when > 0; they no longer count as removed or freed.
+/// - **Free space** — the disk's available space before the first deletion
+///   and now. Only shown when the OS reported both.
+/// - **Failed** — the number of errors in the last run, in red. Only shown
+///   when > 0.
+/// - **Skipped** — the number of paths a safety option kept in the last run,
+///   in yellow. Only shown when > 0.
+/// - **Slowest** — the single removal that took longest, with its path
+///   truncated to fit `width`. In yellow, with a hint at the likely cause,
+///   when it took [`SLOW_DELETE`] or more.
+///
+/// Every case ends with how long the scan took (see [`scan_line`]).
+fn build_summary(app: &App, width: usize) -> Vec<Line<'static>> {
+    let theme = app.theme;
+    // Case 1: the scan found nothing at all. (The list can also be empty
+    // because its last entry was just deleted, which is case 3.)
+    if app.entries.is_empty() && !app.has_delete_history() {
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "  No node_modules found in the specified path.",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.warning),
             )),
         ];
+        // Everything found may have been hidden by `--sensitive hide`.
+        if app.hidden_sensitive > 0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  ({} in sensitive locations hidden by --sensitive hide)",
+                    format_count(app.hidden_sensitive)
+                ),
+                theme.dim,
+            )));
+        }
+        // …or left out by `--min-size`.
+        if let Some(min) = app.options.min_size.filter(|_| app.hidden_small > 0) {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  ({} smaller than {} hidden by --min-size)",
+                    format_count(app.hidden_small),
+                    format_bytes(min)
+                ),
+                theme.dim,
+            )));
+        }
+        // …or left out by `--older-than`.
+        if app.hidden_recent > 0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  ({} modified too recently hidden by --older-than)",
+                    format_count(app.hidden_recent)
+                ),
+                theme.dim,
+            )));
+        }
+        lines.extend(scan_line(app));
+        return lines;
     }
 
     // Case 2: scan found things but the user didn't delete any of them.
-    if app.delete_total == 0 {
-        return vec![
+    if !app.has_delete_history() {
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "  No directories were deleted.",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.warning),
             )),
         ];
+        lines.extend(scan_line(app));
+        return lines;
     }
 
     // Case 3: at least one deletion was attempted.
-    let freed_str = format_size(app.delete_freed, DECIMAL);
-    let success = app.delete_total - app.delete_errors.len();
+    // Under `--no-size` nothing was measured, so the freed total is unknown
+    // rather than zero.
+    let freed_str = if app.sizes_known() {
+        format_bytes(app.delete_freed)
+    } else {
+        "not measured (--no-size)".to_string()
+    };
+    let success = app.delete_removed;
 
-    vec![
+    // "Moved to trash" when everything went to the trash, "Removed" when
+    // anything was deleted for good — with a note of how many were trashed
+    // if the mode was switched part-way through the session.
+    let all_trashed = success > 0 && app.delete_trashed == success;
+    let trashed_note = if app.delete_trashed > 0 && !all_trashed {
+        format!("  ({} moved to trash)", format_count(app.delete_trashed))
+    } else {
+        String::new()
+    };
+
+    let mut lines = vec![
         Line::from(""),
         // "Removed  N  directories" — count in green, surrounding text dimmed.
         Line::from(vec![
             Span::styled(
-                "  Removed  ",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
+                if all_trashed {
+                    "  Moved to trash  "
+                } else {
+                    "  Removed  "
+                },
+                theme.dim,
             ),
             Span::styled(
-                success.to_string(),
+                format_count(success),
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.success)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 // Proper singular/plural so "1 directory" reads correctly.
-                format!("  director{}", if success == 1 { "y" } else { "ies" }),
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
+                if success == 1 {
+                    "  directory"
+                } else {
+                    "  directories"
+                },
+                theme.dim,
             ),
+            Span::styled(trashed_note, theme.dim),
         ]),
-        // "Freed    X.X GB" — size in cyan to match the SIZE column in the list.
+        // "Freed    X.X GB" — size in cyan, kept apart from the green/yellow/red
+        // scale of the list so it reads as a total rather than a warning.
+        // With `--trash` it is "Trashed" until the trash is emptied, since
+        // the space hasn't actually come back yet.
         Line::from(vec![
             Span::styled(
-                "  Freed    ",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
+                if app.trashed.is_empty() {
+                    "  Freed    "
+                } else {
+                    "  Trashed  "
+                },
+                theme.dim,
             ),
             Span::styled(
                 freed_str,
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(trash_note(app), theme.dim),
+        ]),
+    ];
+
+    // "Restored  N  directories  (back where they were)"
+    if app.delete_restored > 0 {
+        lines.push(Line::from(vec![
+            Span::styled("  Restored  ", theme.dim),
+            Span::styled(
+                format_count(app.delete_restored),
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {}  (back where they were)",
+                    if app.delete_restored == 1 {
+                        "directory"
+                    } else {
+                        "directories"
+                    }
+                ),
+                theme.dim,
+            ),
+        ]));
+    }
+
+    // "Free space  46.1 GB → 48.4 GB" — measured, so it also reflects
+    // anything else written or removed on the disk meanwhile.
+    if let (Some(before), Some(now)) = (app.disk_free_before, app.disk_space) {
+        lines.push(Line::from(vec![
+            Span::styled("  Free space  ", theme.dim),
+            Span::styled(format_bytes(before), Style::default().fg(theme.text)),
+            Span::styled(" → ", theme.dim),
+            Span::styled(
+                format_bytes(now.available),
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    // "Failed   N  (see errors below)" — only included when there were errors.
+    if !app.delete_errors.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  Failed   ", theme.dim),
+            Span::styled(
+                format_count(app.delete_errors.len()),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.danger)
                     .add_modifier(Modifier::BOLD),
             ),
-        ]),
-        // "Failed   N  (see errors below)" — only included when there were errors.
-        if app.delete_errors.is_empty() {
-            Line::from("")
+            Span::styled("  (see errors below)", theme.dim),
+        ]));
+    }
+
+    // "Skipped  N  (kept by a safety option)" — yellow rather than red,
+    // because nothing went wrong: these were left alone on purpose.
+    if !app.delete_skipped.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  Skipped  ", theme.dim),
+            Span::styled(
+                format_count(app.delete_skipped.len()),
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  (kept by a safety option)", theme.dim),
+        ]));
+    }
+
+    // "Slowest  4.2 s  …/big-app/node_modules"
+    if let Some((path, took)) = &app.delete_slowest {
+        let slow = *took >= SLOW_DELETE;
+        let took = format_duration(*took);
+        let note = if slow {
+            "  — a network mount or antivirus?"
         } else {
-            Line::from(vec![
-                Span::styled(
-                    "  Failed   ",
-                    Style::default()
-                        .fg(Color::Reset)
-                        .add_modifier(Modifier::DIM),
-                ),
-                Span::styled(
-                    app.delete_errors.len().to_string(),
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    "  (see errors below)",
+            ""
+        };
+        let used = "  Slowest  ".len() + took.chars().count() + 2 + note.chars().count();
+        lines.push(Line::from(vec![
+            Span::styled("  Slowest  ", theme.dim),
+            Span::styled(
+                took,
+                if slow {
                     Style::default()
-                        .fg(Color::Reset)
-                        .add_modifier(Modifier::DIM),
-                ),
-            ])
-        },
-    ]
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                },
+            ),
+            Span::styled(
+                format!("  {}", truncate_left(path, width.saturating_sub(used))),
+                theme.dim,
+            ),
+            Span::styled(note, theme.dim),
+        ]));
+    }
+
+    lines.extend(scan_line(app));
+    lines
+}
+
+/// Returns the "Scanned  1.8 s  (40 found, 22.2 per second)" line, or
+/// `None` if no scan has finished. Helps tell a slow filesystem from a slow
+/// tool: a few directories a second means the disk is the bottleneck.
+fn scan_line(app: &App) -> Option<Line<'static>> {
+    let theme = app.theme;
+    let took = app.scan_duration()?;
+    let rate = app
+        .scan_rate()
+        .map(|rate| format!(", {rate:.1} per second"))
+        .unwrap_or_default();
+    Some(Line::from(vec![
+        Span::styled("  Scanned  ", theme.dim),
+        Span::styled(format_duration(took), Style::default().fg(theme.text)),
+        Span::styled(
+            format!("  ({} found{rate})", format_count(app.scan_found)),
+            theme.dim,
+        ),
+    ]))
 }
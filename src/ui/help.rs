@@ -0,0 +1,124 @@
+//! # Help overlay
+//!
+//! A popup opened with `?` on any screen, listing every key that works
+//! there. The help bars at the bottom of each screen only show as many
+//! hints as fit in one row; this shows them all, one per line.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ┌─ Keys  ·  List ──────────────────────────────┐
+//! │  ↑↓ / jk       Navigate                       │
+//! │  ?             Help                           │
+//! │  PgUp / PgDn   Page                           │
+//! │  …                                            │
+//! │                                               │
+//! │  Any key closes this.                         │
+//! └───────────────────────────────────────────────┘
+//! ```
+//!
+//! The list comes from the same functions that build each screen's help
+//! bar, so the two never disagree. It is drawn last, over whatever the
+//! screen drew, and swallows the next keypress.
+
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::{
+    breakdown, centered_rect, countdown, deleting, detail, done, inner_area, list, review,
+    scanning, welcome,
+};
+use crate::app::{App, Screen};
+
+/// Returns every key that works on the current screen with what it does,
+/// exactly as that screen's help bar lists them.
+pub fn screen_hints(app: &App) -> Vec<(String, String)> {
+    let fixed: &[(&str, &str)] = match app.screen {
+        Screen::Welcome => welcome::HINTS,
+        Screen::Scanning => scanning::HINTS,
+        Screen::List => return list::hints(app),
+        Screen::Confirming => &list::confirm_hints(app),
+        Screen::SensitiveReview => review::HINTS,
+        Screen::Countdown => countdown::HINTS,
+        Screen::Breakdown => breakdown::HINTS,
+        Screen::Detail => detail::HINTS,
+        Screen::Deleting => deleting::hints(app),
+        Screen::Done => done::hints(app),
+    };
+    fixed
+        .iter()
+        .map(|(k, d)| (k.to_string(), d.to_string()))
+        .collect()
+}
+
+/// A short name for the current screen, for the overlay's title.
+fn screen_name(screen: &Screen) -> &'static str {
+    match screen {
+        Screen::Welcome => "Welcome",
+        Screen::Scanning => "Scanning",
+        Screen::List => "List",
+        Screen::Confirming => "Confirm",
+        Screen::SensitiveReview => "Sensitive review",
+        Screen::Countdown => "Countdown",
+        Screen::Breakdown => "Breakdown",
+        Screen::Detail => "Detail",
+        Screen::Deleting => "Deleting",
+        Screen::Done => "Done",
+    }
+}
+
+/// Renders the help overlay for the current screen on top of whatever was
+/// already drawn.
+pub fn render_help(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
+    let hints = screen_hints(app);
+
+    // One row per key, a blank row and the closing note, plus the border.
+    let popup_area = centered_rect(50, hints.len() as u16 + 4, area);
+    f.render_widget(Clear, popup_area);
+
+    // ── Popup border ──────────────────────────────────────────────────────────
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.info))
+        .title(Span::styled(
+            format!(" Keys  ·  {} ", screen_name(&app.screen)),
+            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+        ));
+    f.render_widget(popup_block, popup_area);
+
+    // ── Keys ──────────────────────────────────────────────────────────────────
+    //
+    // Keys are padded to the widest one so the actions line up.
+    let key_w = hints
+        .iter()
+        .map(|(k, _)| k.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<Line> = hints
+        .iter()
+        .map(|(key, desc)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {key:<key_w$}"),
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("   {desc}"), Style::default().fg(theme.text)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Any key closes this.",
+        theme.dim,
+    )));
+    f.render_widget(Paragraph::new(lines), inner_area(popup_area));
+}
@@ -19,9 +19,9 @@
 //! │  [✓]   …/my-app/node_modules                   3d ago        450 MB  │
 //! │  [✓]   …/old-project/node_modules              2mo ago       210 MB  │
 //! │  [ ]   ⚠ …/.config/app/node_modules            1y ago         80 MB  │
-//! │  [ ]   …/work/api/node_modules                just now       120 MB  │
-//! │  ...                                                                  │
-//! ├───────────────────────────────────────────────────────────────────────┤
+//! │  [ ]   …/work/api/node_modules                just now       120 MB █│
+//! │  ...                                                                 ║│
+//! ├─────────────────────────────────────────────────────────── ▼ 23 more ─┤
 //! │  [↑↓/jk] Navigate  [Space] Toggle  [a] All safe  [A] All + ⚠  [Q] Quit │
 //! └───────────────────────────────────────────────────────────────────────┘
 //! ```
@@ -31,9 +31,22 @@
 //! | Column | Width | Notes |
 //! |--------|-------|-------|
 //! | SEL | 6 chars | `[✓]` green = selected safe, `[✓]` yellow = selected sensitive, `[ ]` = unselected |
-//! | PATH | remaining | `⚠ ` prefix in red for sensitive entries; left-truncated so the tail is always visible |
-//! | MODIFIED | 10 chars | human-friendly age: "just now", "3d ago", "2mo ago", etc. |
-//! | SIZE | 10 chars | formatted with SI decimal units (KB, MB, GB) |
+//! | PATH | remaining | `⚠ ` prefix in red for sensitive entries, `→ ` in cyan for symlinks; left-truncated so the tail is always visible; `.` switches to paths relative to the scan root |
+//! | MODIFIED | fit to content | human-friendly age: "just now", "3d ago", "2mo ago", etc. |
+//! | SIZE | fit to content, hidden with `--no-size` | formatted with SI decimal units (KB, MB, GB); green < 100 MB, yellow < 1 GB, red ≥ 1 GB |
+//!
+//! ## Compact layout
+//!
+//! `C` switches to a denser layout for narrow terminals and split panes. The
+//! checkbox shrinks to a single `✓` or `·`, and MODIFIED is dropped, leaving
+//! the path everything but the size:
+//!
+//! ```text
+//! ┌─ 💥 killnode  ·  14 found ───────────┐
+//! │ ✓ PATH                        SIZE ↓ │
+//! │ ✓ …/my-app/node_modules       450 MB │
+//! │ · ⚠ …/.config/app/node_modules 80 MB │
+//! ```
 //!
 //! ## Confirmation popup layout
 //!
@@ -41,7 +54,7 @@
 //! ┌─ Confirm Deletion ──────────────────────────┐
 //! │  Delete  3 directories  freeing ~660 MB?     │
 //! │  ⚠  Warning: sensitive paths are selected!  │  ← only shown when relevant
-//! │                                              │
+//! │  ⚠  Size exceeds disk capacity — …           │  ← only shown when relevant
 //! ├──────────────────────────────────────────────┤
 //! │  [Y / Enter] Confirm      [N / Esc] Cancel   │
 //! └──────────────────────────────────────────────┘
@@ -49,20 +62,43 @@
 //!
 //! The warning line appears whenever one or more sensitive entries are among
 //! the selected directories, giving the user a final chance to reconsider
-//! before an irreversible deletion of a system-adjacent path.
+//! before an irreversible deletion of a system-adjacent path. The second
+//! warning appears when the selected total is larger than the disk holding
+//! the scan root, which means the sizes are double-counted and the "freeing"
+//! figure shouldn't be trusted.
 
-use humansize::{DECIMAL, format_size};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, ListItem, Paragraph},
+    widgets::{
+        Block, Borders, Clear, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState,
+    },
+};
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
 };
-use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::{centered_rect, help_bar, inner_area, truncate_left};
-use crate::app::{App, Screen};
+use super::{
+    SIZE_LARGE, SIZE_SMALL, SPINNER, Theme, border_style, centered_rect, count_noun, format_bytes,
+    format_count, help_bar, inner_area, truncate_left,
+};
+use crate::{
+    app::{App, CONFIRM_WORD, ListRow, ProjectGroup, Screen, SortMode},
+    deleter::DeleteMode,
+    scanner::SensitiveReason,
+};
+
+/// How many columns at the left of each row belong to the checkbox: the
+/// range gutter and `[✓]`. A click there toggles the row.
+pub const CHECKBOX_COLUMNS: u16 = 4;
+
+/// [`CHECKBOX_COLUMNS`] in the [compact](App::compact) layout: the gutter
+/// and a single `✓`.
+pub const COMPACT_CHECKBOX_COLUMNS: u16 = 2;
 
 /// Renders the list screen (and optionally the confirmation popup) into `f`.
 ///
@@ -73,7 +109,10 @@ use crate::app::{App, Screen};
 ///
 /// 2. **Scrollable list** — one row per discovered `node_modules` directory.
 ///    Ratatui's stateful `List` widget handles scrolling; the scroll position
-///    is stored in `app.list_state`.
+///    is stored in `app.list_state`. When the list overflows, "▲ N more" at
+///    the right of the header and "▼ N more" just below the last row say how
+///    many entries are out of view, and a scrollbar down the right edge
+///    shows where the cursor is in the whole list.
 ///    Every entry is selectable, including sensitive ones. Sensitive entries
 ///    are distinguished by a red `⚠ ` prefix in the PATH column rather than
 ///    a locked checkbox state. Selecting a sensitive entry turns its checkbox
@@ -83,28 +122,94 @@ use crate::app::{App, Screen};
 ///    is selected the Enter hint changes to show the count and size that would
 ///    be deleted, so the user knows the consequences before confirming.
 ///    Two distinct "select all" actions are always shown: `a` for safe entries
-///    only, and `A` for everything including sensitive ones.
+///    only, and `A` for everything including sensitive ones. While a range
+///    is marked, the `v` hint says how to finish it.
 ///
 /// If `app.screen` is [`Screen::Confirming`], [`render_confirm_popup`] is
 /// called after the list to draw the dialog on top of it.
 pub fn render_list(f: &mut Frame, app: &mut App) {
     let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
     let is_confirming = matches!(app.screen, Screen::Confirming);
 
     let count = app.entries.len();
-    let total_size_str = format_size(app.total_size(), DECIMAL);
+    let show_sizes = app.sizes_known();
 
     // ── Outer border ──────────────────────────────────────────────────────────
     //
     // The title summarises the scan results at a glance: how many directories
     // were found and how much space they occupy in total.
-    let outer = Block::default()
+    //
+    // The right-hand side of the top border carries a small legend for the
+    // colour scale used in the SIZE column.
+    //
+    // With `--no-size` there are no sizes to total or grade, so both the
+    // total and the legend are left out. Entries hidden by `--sensitive hide`
+    // are counted so they don't silently disappear, a scan cut short by
+    // `--max-results` says so, and `--profile` reports how it went. Entries
+    // hidden with `e` are counted the same way, and drop out of the total.
+    // A `/` filter is shown with how many entries it lets through.
+    // Under `--disk-usage` the total says it is space on disk.
+    let mut title = if app.scan_truncated {
+        format!(
+            " 💥 killnode  ·  showing first {} (scan truncated) ",
+            format_count(count)
+        )
+    } else {
+        format!(" 💥 killnode  ·  {} found ", format_count(count))
+    };
+    if app.hidden_sensitive > 0 {
+        title.push_str(&format!(
+            " ·  {} sensitive hidden ",
+            format_count(app.hidden_sensitive)
+        ));
+    }
+    if let Some(min) = app.options.min_size.filter(|_| app.hidden_small > 0) {
+        title.push_str(&format!(
+            " ·  {} under {} hidden ",
+            format_count(app.hidden_small),
+            format_bytes(min)
+        ));
+    }
+    if app.hidden_recent > 0 {
+        title.push_str(&format!(
+            " ·  {} recent hidden ",
+            format_count(app.hidden_recent)
+        ));
+    }
+    if app.excluded > 0 {
+        title.push_str(&format!(" ·  {} excluded ", format_count(app.excluded)));
+    }
+    if let Some(note) = &app.profile_note {
+        title.push_str(&format!(" ·  {note} "));
+    }
+    if app.list_filter_editing || !app.list_filter.is_empty() {
+        let cursor = if app.list_filter_editing { "▏" } else { "" };
+        title.push_str(&format!(
+            " ·  /{}{cursor} ({} shown) ",
+            app.list_filter,
+            format_count(app.visible_indices().len())
+        ));
+    }
+    if show_sizes {
+        let total_size_str = format_bytes(app.total_size());
+        title.push_str(&format!(" ·  {total_size_str} total{} ", on_disk_note(app)));
+    }
+    let mut outer = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Reset))
+        .border_style(border_style(&theme, &app.screen))
         .title(Span::styled(
-            format!(" 💥 killnode  ·  {count} found  ·  {total_size_str} total "),
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            title,
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
         ));
+    if show_sizes {
+        outer = outer.title(size_legend(&theme).right_aligned());
+    }
     f.render_widget(outer, area);
 
     // Work inside the border so content doesn't overlap the box outline.
@@ -113,37 +218,121 @@ pub fn render_list(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // column header row
-            Constraint::Min(0),    // scrollable list — expands to fill available height
+            Constraint::Length(1),                         // column header row
+            Constraint::Min(0), // scrollable list — expands to fill available height
+            Constraint::Length(u16::from(app.show_stats)), // stats line, when toggled on
             Constraint::Length(3), // help bar (1 line of hints + top border + padding)
         ])
         .split(inner);
 
+    // ── Column values ─────────────────────────────────────────────────────────
+    //
+    // The MODIFIED and SIZE cells are formatted up front so the columns can be
+    // sized to their widest value. On a typical scan every age is something
+    // like "3d ago" and every size fits in "120 MB", so fitting the columns
+    // hands several cells back to PATH instead of padding them with blanks.
+    let now_secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let cells: Vec<(String, String)> = app
+        .entries
+        .iter()
+        .map(|entry| {
+            let modified_str = entry
+                .last_modified
+                .map(|ts| format_age(now_secs.saturating_sub(ts)))
+                .unwrap_or_else(|| "?".to_string());
+            // An entry being re-measured with `R` shows a spinner in place
+            // of its (stale) size until the new one arrives.
+            let size_str = if app.is_refreshing(&entry.path) {
+                format!(
+                    "{} measuring",
+                    SPINNER[(app.ticker as usize / 2) % SPINNER.len()]
+                )
+            } else {
+                format_bytes(entry.size)
+            };
+            (modified_str, size_str)
+        })
+        .collect();
+
     // ── Column header ─────────────────────────────────────────────────────────
     //
     // Column widths are computed dynamically from the terminal width so the
-    // layout stays correct on any screen size. PATH gets whatever is left after
-    // the fixed-width columns and their separators are accounted for.
-    let header_style = Style::default()
-        .fg(Color::Reset)
-        .add_modifier(Modifier::BOLD);
+    // layout stays correct on any screen size. MODIFIED and SIZE are as wide
+    // as their longest value (never narrower than the header label), and PATH
+    // gets whatever is left after those and their separators are accounted for.
+    // SIZE (and its separator) takes no width at all under `--no-size`.
+    //
+    // The column the list is sorted by gets an arrow: ↓ for descending
+    // (largest or newest first), ↑ for ascending. Smart sort mixes two
+    // columns, so it marks neither.
+    //
+    // The compact layout has no MODIFIED column at all, and a checkbox
+    // column just wide enough for its one glyph.
+    let compact = app.compact;
+    let header_style = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+    let (path_label, modified_label, size_label) = match app.sort_mode {
+        SortMode::Size => (" PATH", "MODIFIED", "SIZE ↓"),
+        SortMode::SizeAscending => (" PATH", "MODIFIED", "SIZE ↑"),
+        SortMode::Newest => (" PATH", "MODIFIED ↓", "SIZE"),
+        SortMode::Oldest => (" PATH", "MODIFIED ↑", "SIZE"),
+        SortMode::Path => (" PATH ↑", "MODIFIED", "SIZE"),
+        SortMode::Smart => (" PATH", "MODIFIED", "SIZE"),
+    };
+    // A project header's size is the total of its entries, which can be
+    // wider than any one of them.
+    let rows = app.list_rows();
+    let group_size =
+        |group: &ProjectGroup| -> u64 { group.entries.iter().map(|&i| app.entries[i].size).sum() };
+    let group_sizes: Vec<String> = rows
+        .iter()
+        .filter_map(|row| match row {
+            ListRow::Group(group) => Some(format_bytes(group_size(group))),
+            ListRow::Entry(_) => None,
+        })
+        .collect();
     let list_width = chunks[1].width.saturating_sub(2) as usize;
-    let size_col_w: usize = 10;
-    let modified_col_w: usize = 10;
-    let checkbox_col_w: usize = 6;
-    let path_col_w = list_width.saturating_sub(size_col_w + modified_col_w + checkbox_col_w + 3);
-
-    f.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled(format!("{:<checkbox_col_w$}", " SEL"), header_style),
-            Span::styled(format!("{:<path_col_w$}", " PATH"), header_style),
+    let modified_col_w = if compact {
+        0
+    } else {
+        column_width(modified_label, cells.iter().map(|(m, _)| m.as_str()))
+    };
+    let modified_part_w = if compact { 0 } else { modified_col_w + 1 };
+    let size_col_w = column_width(
+        size_label,
+        cells
+            .iter()
+            .map(|(_, s)| s.as_str())
+            .chain(group_sizes.iter().map(String::as_str)),
+    );
+    let size_part_w = if show_sizes { size_col_w + 1 } else { 0 };
+    let (checkbox_col_w, checkbox_label, path_label) = if compact {
+        (3, " ✓", path_label.trim_start())
+    } else {
+        (6, " SEL", path_label)
+    };
+    let path_col_w = list_width.saturating_sub(checkbox_col_w + 1 + modified_part_w + size_part_w);
+
+    let mut header = vec![
+        Span::styled(format!("{checkbox_label:<checkbox_col_w$}"), header_style),
+        Span::styled(format!("{:<path_col_w$}", path_label), header_style),
+    ];
+    if !compact {
+        header.extend([
             Span::raw(" "),
-            Span::styled(format!("{:>modified_col_w$}", "MODIFIED"), header_style),
+            Span::styled(format!("{:>modified_col_w$}", modified_label), header_style),
+        ]);
+    }
+    if show_sizes {
+        header.extend([
             Span::raw(" "),
-            Span::styled(format!("{:>size_col_w$}", "SIZE"), header_style),
-        ])),
-        chunks[0],
-    );
+            Span::styled(format!("{:>size_col_w$}", size_label), header_style),
+        ]);
+    }
+    f.render_widget(Paragraph::new(Line::from(header)), chunks[0]);
 
     // ── List items ────────────────────────────────────────────────────────────
     //
@@ -157,134 +346,293 @@ pub fn render_list(f: &mut Frame, app: &mut App) {
     //
     // Sensitive entries have a ⚠ prefix rendered in red directly before the
     // path text, so the PATH column is self-labelling without needing a
-    // separate checkbox state.
-    let now_secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-
-    let items: Vec<ListItem> = app
-        .entries
-        .iter()
-        .map(|entry| {
-            // Checkbox: reflects selection state for all entries.
-            // Sensitive + selected uses yellow instead of green as a visual
-            // reminder that something unusual is about to be deleted.
-            let (checkbox, checkbox_style) = if entry.selected {
-                if entry.sensitive {
-                    (
-                        "[✓]",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                } else {
-                    (
-                        "[✓]",
-                        Style::default()
-                            .fg(Color::Green)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                }
+    // separate checkbox state. Symlinked node_modules get a cyan → the same
+    // way; deleting one only removes the link. Entries on the always-keep
+    // list (`p`) get a green ⚑ instead of the warning.
+    //
+    // The row a range selection was started from (`v`) gets a ▸ in the gutter
+    // before its checkbox until the range is finished.
+    //
+    // Only rows matching the `/` filter are drawn. Grouped by project (`g`),
+    // each entry is indented under its project's header and shows its path
+    // from the project down.
+    let entry_item = |i: usize| -> ListItem {
+        let entry = &app.entries[i];
+        let (modified_str, size_str) = &cells[i];
+        // Checkbox: reflects selection state for all entries.
+        // Sensitive + selected uses yellow instead of green as a visual
+        // reminder that something unusual is about to be deleted.
+        let (ticked, unticked) = if compact {
+            ("✓", "·")
+        } else {
+            ("[✓]", "[ ]")
+        };
+        let (checkbox, checkbox_style) = if entry.selected {
+            if entry.sensitive.is_some() {
+                (
+                    ticked,
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD),
+                )
             } else {
                 (
-                    "[ ]",
+                    ticked,
                     Style::default()
-                        .fg(Color::Reset)
-                        .add_modifier(Modifier::DIM),
+                        .fg(theme.success)
+                        .add_modifier(Modifier::BOLD),
                 )
-            };
-
-            // Path text is always white and readable — sensitive entries are
-            // no longer dimmed since they are now fully selectable.
-            let path_style = Style::default().fg(Color::Reset);
+            }
+        } else {
+            (unticked, theme.dim)
+        };
 
-            // Sensitive entries reserve 2 characters at the start of the path
-            // column for the "⚠ " prefix. The actual path is truncated to the
-            // remaining width so the MODIFIED and SIZE columns still line up.
-            let (warn_prefix, path_available) = if entry.sensitive {
-                ("⚠ ", path_col_w.saturating_sub(2))
-            } else {
-                ("", path_col_w)
-            };
+        // Path text is always white and readable — sensitive entries are
+        // no longer dimmed since they are now fully selectable.
+        let path_style = Style::default().fg(theme.text);
 
-            let path_trunc = truncate_left(&entry.path, path_available);
-            // Pad to the full column width so alignment is preserved regardless
-            // of whether the ⚠ prefix is present.
-            let path_padded = format!("{:<path_col_w$}", format!("{warn_prefix}{path_trunc}"));
-            let size_str = format_size(entry.size, DECIMAL);
-            let modified_str = entry
-                .last_modified
-                .map(|ts| format_age(now_secs.saturating_sub(ts)))
-                .unwrap_or_else(|| "?".to_string());
+        // Markers shown before the path: a red "⚠ " for sensitive entries
+        // and a cyan "→ " for symlinks. Each takes 2 characters at the start
+        // of the path column, and the path is truncated to whatever
+        // remains so the MODIFIED and SIZE columns still line up.
+        let mut markers: Vec<Span> = Vec::new();
+        // A kept entry is sensitive only by the user's choice, so it gets
+        // its own marker below instead of the warning.
+        if entry.sensitive.is_some() && !entry.is_kept() {
+            markers.push(Span::styled("⚠ ", Style::default().fg(theme.danger)));
+        }
+        if entry.symlink {
+            markers.push(Span::styled("→ ", Style::default().fg(theme.info)));
+        }
+        if entry.is_kept() {
+            markers.push(Span::styled("⚑ ", Style::default().fg(theme.success)));
+        }
+        let indent = if app.grouped { "  " } else { "" };
+        let path_available = path_col_w.saturating_sub(2 * markers.len() + indent.len());
 
-            // Build the path cell as two spans when sensitive so the ⚠ prefix
-            // can be coloured red while the path text stays white.
-            let path_spans: Vec<Span> = if entry.sensitive {
-                vec![
-                    Span::styled("⚠ ", Style::default().fg(Color::Red)),
-                    Span::styled(format!("{:<path_available$}", path_trunc), path_style),
-                ]
-            } else {
-                vec![Span::styled(path_padded, path_style)]
-            };
+        let path_shown = if app.grouped {
+            relative_path(&entry.path, app.project_of(&entry.path))
+        } else if let Some(root) = app.root_of(&entry.path).filter(|_| app.relative_paths) {
+            relative_path(&entry.path, root)
+        } else {
+            entry.path.clone()
+        };
+        let path_trunc = truncate_left(&path_shown, path_available);
 
-            let mut spans = vec![Span::styled(format!(" {checkbox} "), checkbox_style)];
-            spans.extend(path_spans);
+        // The anchor gets an arrow, and the rest of the range being marked
+        // a bar, so it's clear what the second `v` will toggle.
+        let gutter = if app.anchor == Some(i) {
+            "▸"
+        } else if app.in_marked_range(i) {
+            "┃"
+        } else {
+            " "
+        };
+        let mut spans = vec![
+            Span::styled(gutter, Style::default().fg(theme.info)),
+            Span::styled(format!("{checkbox} "), checkbox_style),
+            Span::raw(indent),
+        ];
+        spans.extend(markers);
+        // Pad to the remaining width so alignment is preserved regardless
+        // of how many markers are present.
+        spans.push(Span::styled(
+            format!("{:<path_available$}", path_trunc),
+            path_style,
+        ));
+        if !compact {
             spans.extend([
                 Span::raw(" "),
                 Span::styled(
                     format!("{:>modified_col_w$}", modified_str),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
+            ]);
+        }
+        if show_sizes {
+            spans.extend([
                 Span::raw(" "),
                 Span::styled(
                     format!("{:>size_col_w$}", size_str),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.size_color(entry.size)),
+                ),
+            ]);
+        }
+
+        ListItem::new(Line::from(spans))
+    };
+
+    // ── Project headers ───────────────────────────────────────────────────────
+    //
+    // "[~] ▾ /work/monorepo  (12)   1.4 GB" — the checkbox is ticked when all
+    // of the project's entries are selected and shows ~ when only some are.
+    // ▾ marks an open project and ▸ a collapsed one; the size is the total.
+    let group_item = |group: &ProjectGroup| -> ListItem {
+        let selected = group
+            .entries
+            .iter()
+            .filter(|&&i| app.entries[i].selected)
+            .count();
+        let (ticked, partly, unticked) = if compact {
+            ("✓", "~", "·")
+        } else {
+            ("[✓]", "[~]", "[ ]")
+        };
+        let (checkbox, checkbox_style) = match selected {
+            0 => (unticked, theme.dim),
+            n if n == group.entries.len() => (
+                ticked,
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            _ => (
+                partly,
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        };
+        let fold = if group.collapsed { "▸ " } else { "▾ " };
+        let dir = match app.root_of(&group.dir).filter(|_| app.relative_paths) {
+            Some(root) => relative_path(&group.dir, root),
+            None => group.dir.clone(),
+        };
+        let count = format!("  ({})", format_count(group.entries.len()));
+        let header_w = path_col_w.saturating_sub(2);
+        let dir_available = header_w.saturating_sub(count.chars().count());
+        let header_text = format!("{}{count}", truncate_left(&dir, dir_available));
+        let mut spans = vec![
+            Span::raw(" "),
+            Span::styled(format!("{checkbox} "), checkbox_style),
+            Span::styled(fold, Style::default().fg(theme.info)),
+            Span::styled(
+                format!("{header_text:<header_w$}"),
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" ".repeat(modified_part_w)),
+        ];
+        if show_sizes {
+            let size = group_size(group);
+            spans.extend([
+                Span::raw(" "),
+                Span::styled(
+                    format!("{:>size_col_w$}", format_bytes(size)),
+                    Style::default()
+                        .fg(theme.size_color(size))
+                        .add_modifier(Modifier::BOLD),
                 ),
             ]);
+        }
+        ListItem::new(Line::from(spans))
+    };
 
-            ListItem::new(Line::from(spans))
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            ListRow::Group(group) => group_item(group),
+            ListRow::Entry(i) => entry_item(*i),
         })
         .collect();
 
     let list = ratatui::widgets::List::new(items)
         .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
-    f.render_stateful_widget(list, chunks[1], &mut app.list_state);
+    // `list_state` points into `entries`; the widget wants a row in the
+    // filtered view, so it draws from a copy and hands the scroll back.
+    let mut view_state = ListState::default()
+        .with_offset(app.list_state.offset())
+        .with_selected(app.cursor_row(&rows));
+    f.render_stateful_widget(list, chunks[1], &mut view_state);
+    *app.list_state.offset_mut() = view_state.offset();
+    app.list_page = chunks[1].height as usize;
+    app.list_area = chunks[1];
 
-    // ── Help bar ──────────────────────────────────────────────────────────────
+    // ── Stats line ────────────────────────────────────────────────────────────
     //
-    // The Enter hint is context-sensitive: when at least one entry is selected
-    // it shows the count and combined size so the user knows exactly what will
-    // happen before they commit. When nothing is selected, Enter isn't offered
-    // at all because there is nothing to delete.
-    let selected_count = app.selected_count();
-    let selected_size_str = format_size(app.selected_size(), DECIMAL);
+    // Average, median and largest size across the whole list, each coloured
+    // on the same scale as the SIZE column. Only laid out when toggled on.
+    if let (true, Some(stats)) = (app.show_stats, app.size_stats()) {
+        let mut spans = Vec::new();
+        for (label, bytes) in [
+            ("  Average  ", stats.average),
+            ("   Median  ", stats.median),
+            ("   Largest  ", stats.largest),
+        ] {
+            spans.push(Span::styled(label, theme.dim));
+            spans.push(Span::styled(
+                format_bytes(bytes),
+                Style::default()
+                    .fg(theme.size_color(bytes))
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), chunks[2]);
+    }
 
-    if selected_count > 0 {
-        let delete_label = format!("Delete {selected_count} ({selected_size_str})");
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, &hints(app)), chunks[3]);
+
+    // ── Scroll hints ──────────────────────────────────────────────────────────
+    //
+    // Rendering the list scrolled `list_state` so the cursor is in view, so
+    // its offset now says how many rows are hidden above. The hints
+    // are drawn over the right end of the PATH header and of the help bar's
+    // top border, so they never cover an entry.
+    let above = app.list_state.offset().min(rows.len());
+    let below = rows.len().saturating_sub(above + chunks[1].height as usize);
+    let hint_style = Style::default().fg(theme.info).add_modifier(Modifier::BOLD);
+    if above > 0 {
+        let hint = format!("▲ {} more ", format_count(above));
+        // Only when it fits beside the " PATH" label.
+        if path_col_w >= hint.chars().count() + 6 {
+            let path_header = Rect {
+                x: chunks[0].x + checkbox_col_w as u16,
+                width: path_col_w as u16,
+                ..chunks[0]
+            }
+            .intersection(chunks[0]);
+            f.render_widget(
+                Paragraph::new(Line::from(Span::styled(hint, hint_style)).right_aligned()),
+                path_header,
+            );
+        }
+    }
+    if below > 0 {
+        let border_row = Rect {
+            height: chunks[3].height.min(1),
+            ..chunks[3]
+        };
         f.render_widget(
-            help_bar(&[
-                ("↑↓ / jk", "Navigate"),
-                ("Space", "Toggle"),
-                ("a", "All safe"),
-                ("A", "All + ⚠"),
-                ("Enter", &delete_label),
-                ("Q", "Quit"),
-            ]),
-            chunks[2],
+            Paragraph::new(
+                Line::from(Span::styled(
+                    format!(" ▼ {} more ", format_count(below)),
+                    hint_style,
+                ))
+                .right_aligned(),
+            ),
+            border_row,
         );
-    } else {
-        f.render_widget(
-            help_bar(&[
-                ("↑↓ / jk", "Navigate"),
-                ("Space", "Toggle"),
-                ("a", "All safe"),
-                ("A", "All + ⚠"),
-                ("Q", "Quit"),
-            ]),
-            chunks[2],
+    }
+
+    // ── Scrollbar ─────────────────────────────────────────────────────────────
+    //
+    // Only when the list overflows. Rows stop a few columns short of the
+    // right edge, so the bar never covers a size. The thumb follows the
+    // cursor rather than the scroll offset, and the ▲/▼ hints above already
+    // do the job of end arrows.
+    if above + below > 0 {
+        let mut scroll_state = ScrollbarState::new(rows.len())
+            .viewport_content_length(chunks[1].height as usize)
+            .position(view_state.selected().unwrap_or(above));
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_style(theme.dim)
+                .thumb_style(Style::default().fg(theme.info)),
+            chunks[1],
+            &mut scroll_state,
         );
     }
 
@@ -299,6 +647,31 @@ pub fn render_list(f: &mut Frame, app: &mut App) {
 
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
+/// Builds the colour legend shown on the right of the list's top border:
+/// one coloured block per band of the [`Theme::size_color`] scale.
+fn size_legend(theme: &Theme) -> Line<'static> {
+    let small = format_bytes(SIZE_SMALL);
+    let large = format_bytes(SIZE_LARGE);
+    let dim = theme.dim;
+    Line::from(vec![
+        Span::raw(" "),
+        Span::styled("■", Style::default().fg(theme.success)),
+        Span::styled(format!(" <{small}  "), dim),
+        Span::styled("■", Style::default().fg(theme.warning)),
+        Span::styled(format!(" <{large}  "), dim),
+        Span::styled("■", Style::default().fg(theme.danger)),
+        Span::styled(format!(" ≥{large} "), dim),
+    ])
+}
+
+/// Returns the width of a right-aligned column: the longest of `values`, or
+/// the header label if that is longer.
+fn column_width<'a>(header: &str, values: impl Iterator<Item = &'a str>) -> usize {
+    values
+        .map(|v| v.chars().count())
+        .fold(header.chars().count(), usize::max)
+}
+
 /// Formats a duration (given as seconds) as a human-friendly "time ago" string.
 ///
 /// The output uses the largest unit that gives a whole number, rounded down:
@@ -332,6 +705,30 @@ fn format_age(secs: u64) -> String {
     }
 }
 
+/// Returns `path` relative to `scan_root`, for the compact `.` display mode.
+///
+/// The comparison is done component-wise via [`Path::strip_prefix`], so
+/// trailing separators and a leading `./` on the root don't matter. Paths
+/// that aren't under the root (which can happen when the walk follows a
+/// symlink) are returned unchanged, as is the root itself.
+fn relative_path(path: &str, scan_root: &str) -> String {
+    match Path::new(path).strip_prefix(scan_root) {
+        Ok(rel) if !rel.as_os_str().is_empty() => rel.to_string_lossy().to_string(),
+        _ => path.to_string(),
+    }
+}
+
+/// Returns " on disk" under `--disk-usage`, to follow a total so it's
+/// clear which kind of size it adds up. Empty otherwise, or when nothing was
+/// measured.
+fn on_disk_note(app: &App) -> &'static str {
+    if app.options.disk_usage && app.sizes_known() {
+        " on disk"
+    } else {
+        ""
+    }
+}
+
 /// Renders the confirmation dialog as an overlay on top of the list.
 ///
 /// The dialog is centred on the screen and sized at 60% of the terminal width
@@ -344,24 +741,54 @@ fn format_age(secs: u64) -> String {
 /// The dialog has three regions:
 ///
 /// 1. **Summary line** — states exactly what will happen: how many directories
-///    will be deleted and how much space will be freed. The directory count is
-///    highlighted in red and the size in green to draw the eye to the key facts.
+///    will be deleted and how much space will be freed. This describes the
+///    queued set, which is the highlighted entry alone after `x`. The
+///    directory count is highlighted in red and the size uses the same colour
+///    scale as the SIZE column, to draw the eye to the key facts.
 ///
 /// 2. **Sensitive warning** (conditional) — if the user somehow has sensitive
 ///    entries selected (which the UI normally prevents, but is checked here as
-///    a safety net), a red warning is shown.
+///    a safety net), a red warning is shown. Entries on the always-keep list
+///    get a yellow one of their own.
+///
+/// 3. **Size sanity warning** (conditional) — if the selected total is larger
+///    than the filesystem itself, the figure can't be real. A yellow note
+///    says so, since the usual cause is hardlinks counted once per directory.
+///
+/// 4. **Typed confirmation** (conditional) — with anything sensitive queued,
+///    a single Y isn't accepted. [`CONFIRM_WORD`] has to be typed here, and
+///    a wrong word gets a red line saying so.
+///
+/// 5. **Help bar** — Y/Enter to confirm, T to switch between deleting and
+///    moving to the trash, N/Esc to cancel and go back to the list.
 ///
-/// 3. **Help bar** — Y/Enter to confirm, N/Esc to cancel and go back to the list.
+/// The summary reads "Trash N directories" instead of "Delete …" while
+/// [`DeleteMode::Trash`] is on. When the queued set mixes sensitive and
+/// safe entries, the count is split as "(2 safe + 1 sensitive)", the
+/// sensitive part in red, so the last gate says exactly what is at stake.
 fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect) {
-    let popup_area = centered_rect(60, 9, area);
+    let theme = app.theme;
+    let typed = app.needs_typed_confirm();
+    let popup_area = centered_rect(60, if typed { 11 } else { 9 }, area);
 
     // Erase whatever the list drew in this region so the popup has a clean
     // background rather than showing through to the rows behind it.
     f.render_widget(Clear, popup_area);
 
-    let selected_count = app.selected_count();
-    let selected_size_str = format_size(app.selected_size(), DECIMAL);
-    let has_sensitive_selected = app.entries.iter().any(|e| e.sensitive && e.selected);
+    // Everything here describes the queued set — normally the selection, or
+    // just the highlighted entry when the popup was opened with `x`.
+    let queued = app.queued_entries();
+    let selected_count = queued.len();
+    let selected_size = queued.iter().map(|e| e.size).sum::<u64>();
+    let selected_size_str = format_bytes(selected_size);
+    // Kept entries are sensitive too, but get a warning of their own.
+    let sensitive_queued: Vec<_> = queued
+        .iter()
+        .filter_map(|e| e.sensitive)
+        .filter(|&r| r != SensitiveReason::Kept)
+        .collect();
+    let kept_queued = queued.iter().filter(|e| e.is_kept()).count();
+    let size_implausible = app.queued_size_implausible();
 
     // ── Popup border ──────────────────────────────────────────────────────────
     //
@@ -372,13 +799,13 @@ fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect) {
         .borders(Borders::ALL)
         .border_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         )
         .title(Span::styled(
             " Confirm Deletion ",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         ));
     f.render_widget(popup_block, popup_area);
@@ -388,66 +815,249 @@ fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // summary line
-            Constraint::Length(2), // optional sensitive-path warning (empty if not needed)
+            Constraint::Length(1),                         // summary line
+            Constraint::Length(3), // optional warnings (empty if not needed)
+            Constraint::Length(if typed { 2 } else { 0 }), // typed confirmation
             Constraint::Min(0),    // spacer
-            Constraint::Length(1), // help bar
+            Constraint::Length(2), // help bar (1 line of hints + top border)
         ])
         .split(inner);
 
     // ── Summary ───────────────────────────────────────────────────────────────
-    f.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled(
-                "  Delete  ",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
-            ),
-            Span::styled(
-                format!("{selected_count} directories"),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
+    //
+    // The "freeing ~X" estimate is left out under `--no-size`, where there is
+    // no size to estimate from.
+    let dim = theme.dim;
+    let trash = app.options.delete_mode == DeleteMode::Trash;
+    let mut summary = vec![
+        Span::styled(if trash { "  Trash  " } else { "  Delete  " }, dim),
+        Span::styled(
+            count_noun(selected_count, "directory", "directories"),
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    let (safe, sensitive) = (app.selected_safe_count(), app.selected_sensitive_count());
+    if safe > 0 && sensitive > 0 {
+        summary.extend([
+            Span::styled(format!(" ({} safe + ", format_count(safe)), dim),
             Span::styled(
-                "  freeing ~",
+                format!("{} sensitive", format_count(sensitive)),
                 Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
+                    .fg(theme.danger)
+                    .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(")", dim),
+        ]);
+    }
+    if app.sizes_known() {
+        summary.extend([
+            Span::styled("  freeing ~", dim),
             Span::styled(
                 selected_size_str,
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.size_color(selected_size))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(
-                "?",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
-            ),
-        ])),
-        chunks[0],
-    );
+        ]);
+    }
+    summary.push(Span::styled(format!("{}?", on_disk_note(app)), dim));
+    f.render_widget(Paragraph::new(Line::from(summary)), chunks[0]);
 
-    // ── Sensitive warning ─────────────────────────────────────────────────────
+    // ── Warnings ──────────────────────────────────────────────────────────────
     //
     // Only shown when a sensitive entry is somehow selected. In normal usage
     // the UI prevents selecting sensitive entries, but this acts as a last-
     // chance safety warning just in case.
-    if has_sensitive_selected {
-        f.render_widget(
-            Paragraph::new(Line::from(Span::styled(
-                "  ⚠  Warning: sensitive paths are selected!",
-                Style::default().fg(Color::Red),
-            ))),
-            chunks[1],
-        );
+    //
+    // When every sensitive entry is sensitive for the same reason, the
+    // warning names it.
+    //
+    // The always-keep and size sanity warnings share the same rows, so any
+    // of them can be shown without growing the popup.
+    let mut warnings = Vec::new();
+    if let Some(&first) = sensitive_queued.first() {
+        let text = if sensitive_queued.iter().all(|&r| r == first) {
+            format!("  ⚠  Warning: sensitive — {}", first.description())
+        } else {
+            "  ⚠  Warning: sensitive paths are selected!".to_string()
+        };
+        warnings.push(Line::from(Span::styled(
+            text,
+            Style::default().fg(theme.danger),
+        )));
+    }
+    if kept_queued > 0 {
+        warnings.push(Line::from(Span::styled(
+            format!(
+                "  ⚑  Warning: {} on your always-keep list",
+                count_noun(kept_queued, "directory", "directories")
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    if size_implausible {
+        warnings.push(Line::from(Span::styled(
+            "  ⚠  Size exceeds disk capacity — likely double-counted hardlinks",
+            Style::default().fg(theme.warning),
+        )));
+    }
+    f.render_widget(Paragraph::new(warnings), chunks[1]);
+
+    // ── Typed confirmation ────────────────────────────────────────────────────
+    if typed {
+        let mut lines = vec![Line::from(vec![
+            Span::styled(format!("  Type {CONFIRM_WORD} to confirm: "), dim),
+            Span::styled(
+                format!("{}▏", app.confirm_typed),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+        if app.confirm_mismatch {
+            lines.push(Line::from(Span::styled(
+                format!("  ✗  That wasn't \"{CONFIRM_WORD}\" — nothing was deleted"),
+                Style::default().fg(theme.danger),
+            )));
+        }
+        f.render_widget(Paragraph::new(lines), chunks[2]);
     }
 
     // ── Help bar ──────────────────────────────────────────────────────────────
-    f.render_widget(
-        help_bar(&[("Y / Enter", "Confirm"), ("N / Esc", "Cancel")]),
-        chunks[3],
-    );
+    f.render_widget(help_bar(&theme, &confirm_hints(app)), chunks[4]);
+}
+
+// ─── Key hints ────────────────────────────────────────────────────────────────
+
+/// The keys that work on the list, for its help bar and the `?` overlay.
+/// The bar shows as many as fit; the overlay shows them all.
+///
+/// The Enter hint is context-sensitive: when at least one entry is selected
+/// it shows the count and combined size so the user knows exactly what will
+/// happen before they commit. When nothing is selected, Enter opens the
+/// detail view instead, which `I` already covers.
+pub fn hints(app: &App) -> Vec<(String, String)> {
+    // While the filter is being typed, only the keys that work then — `?`
+    // included, which is typed into the filter.
+    if app.list_filter_editing {
+        return owned(&[
+            ("↑↓", "Navigate"),
+            ("Enter", "Keep filter"),
+            ("Esc", "Clear filter"),
+            ("Backspace", "Erase"),
+        ]);
+    }
+
+    let show_sizes = app.sizes_known();
+    let selected_count = app.selected_count();
+    // The `.` hint names the mode the key switches *to*, not the current one.
+    let paths_label = if app.relative_paths {
+        "Absolute"
+    } else {
+        "Relative"
+    };
+    let range_hint = if app.anchor.is_some() {
+        ("v / Space / Enter", "Toggle range")
+    } else {
+        ("v", "Range")
+    };
+    let mut hints = owned(&[
+        ("↑↓ / jk", "Navigate"),
+        ("?", "Help"),
+        ("PgUp / PgDn", "Page"),
+        ("Home / End", "First / last"),
+        ("Space", "Toggle"),
+        range_hint,
+        ("a", "All safe"),
+        ("A", "All + ⚠"),
+        ("x / Del", "Delete one"),
+        ("X", "Delete one, no prompt"),
+        ("d", "Breakdown"),
+        ("I", "Detail"),
+        ("o", "Open folder"),
+        ("y", "Copy path"),
+        ("/", "Filter"),
+        ("e", "Exclude folder"),
+        ("p", "Always keep"),
+        ("P", "Save profile"),
+        (".", paths_label),
+        (
+            "C",
+            if app.compact {
+                "Full layout"
+            } else {
+                "Compact"
+            },
+        ),
+    ]);
+    hints.push(("s".into(), format!("Sort: {}", app.sort_mode.label())));
+    // Like `.`, `g` names what it switches to.
+    if app.grouped {
+        hints.extend(owned(&[("g", "Ungroup"), ("←→ / hl", "Fold / unfold")]));
+    } else {
+        hints.extend(owned(&[("g", "Group by project")]));
+    }
+    if show_sizes {
+        // With `c` on, the hint itself becomes the running total down to
+        // the cursor, so it updates as the cursor moves.
+        let cumulative_label = match app.cumulative_size() {
+            Some((n, bytes)) if app.show_cumulative => {
+                format!("Top {}: {}", format_count(n), format_bytes(bytes))
+            }
+            _ => "Running total".to_string(),
+        };
+        hints.extend(owned(&[("R", "Refresh size"), ("i", "Stats")]));
+        hints.push(("c".into(), cumulative_label));
+    }
+    if selected_count > 0 {
+        let delete_label = if show_sizes {
+            format!(
+                "Delete {} ({})",
+                format_count(selected_count),
+                format_bytes(app.selected_size())
+            )
+        } else {
+            format!("Delete {}", format_count(selected_count))
+        };
+        hints.push(("Enter".into(), delete_label));
+    }
+    hints.extend(owned(&[("Esc", "Back"), ("Q", "Quit")]));
+    hints
+}
+
+/// The keys that work on the confirmation popup, for its help bar and the
+/// `?` overlay. T — Tab while a word is being typed — offers whichever mode
+/// isn't current.
+pub fn confirm_hints(app: &App) -> [(&'static str, &'static str); 4] {
+    // While a word is wanted, letters are typed rather than pressed.
+    let typed = app.needs_typed_confirm();
+    let toggle = match (typed, app.options.delete_mode) {
+        (false, DeleteMode::Trash) => ("T", "Delete for good"),
+        (false, DeleteMode::Remove) => ("T", "Move to trash"),
+        (true, DeleteMode::Trash) => ("Tab", "Delete for good"),
+        (true, DeleteMode::Remove) => ("Tab", "Move to trash"),
+    };
+    if typed {
+        [
+            ("delete + Enter", "Confirm"),
+            toggle,
+            ("Esc", "Cancel"),
+            ("?", "Help"),
+        ]
+    } else {
+        [
+            ("Y / Enter", "Confirm"),
+            toggle,
+            ("N / Esc", "Cancel"),
+            ("?", "Help"),
+        ]
+    }
+}
+
+/// Copies borrowed hints into the owned form [`hints`] returns.
+fn owned(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, d)| (k.to_string(), d.to_string()))
+        .collect()
 }
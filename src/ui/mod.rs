@@ -12,8 +12,16 @@
 //! | [`welcome`] | [`Screen::Welcome`] — opening screen with scan root |
 //! | [`scanning`] | [`Screen::Scanning`] — spinner + live path readout |
 //! | [`list`] | [`Screen::List`] and [`Screen::Confirming`] — entry list + confirmation popup |
+//! | [`review`] | [`Screen::SensitiveReview`] — sensitive entries about to be deleted, over the list |
+//! | [`countdown`] | [`Screen::Countdown`] — seconds left before a sensitive deletion, over the list |
+//! | [`breakdown`] | [`Screen::Breakdown`] — largest children of one entry, over the list |
+//! | [`detail`] | [`Screen::Detail`] — everything known about one entry, over the list |
 //! | [`deleting`] | [`Screen::Deleting`] — progress gauge |
 //! | [`done`] | [`Screen::Done`] — summary and error list |
+//! | [`help`] | the `?` key list, over any screen |
+//!
+//! Colours come from [`theme`]: the active [`Theme`] lives on [`App`] and
+//! every renderer draws with it.
 //!
 //! [`Screen::Confirming`] is handled inside `list` rather than its own module
 //! because the confirmation dialog is an overlay rendered *on top of* the list —
@@ -24,29 +32,47 @@
 //! The bottom of this file contains a small set of helpers used across multiple
 //! screens:
 //!
+//! - [`border_style`] — the outer border colour for each screen, so the
+//!   frame edge reflects the current state.
 //! - [`help_bar`] — renders the row of `[Key] Action` hints at the bottom of
 //!   every screen.
+//! - [`render_status`] — draws [`App::status`] into the bottom border of
+//!   whatever screen is showing.
 //! - [`inner_area`] — shrinks a [`Rect`] by one cell on each side to account
 //!   for a border, so content doesn't overlap the box outline.
 //! - [`centered_rect`] — computes a centred rectangle for popup dialogs.
 //! - [`truncate_left`] — shortens a string from the left, keeping the *end*
 //!   visible. Used for paths, where the filename/tail is more useful than the
 //!   root prefix.
+//! - [`format_count`] and [`count_noun`] — every count shown on screen goes
+//!   through these, so big numbers are digit-grouped and nouns agree with
+//!   their numbers everywhere.
+//! - [`format_bytes`] — likewise for every size.
 
+pub mod breakdown;
+pub mod countdown;
 pub mod deleting;
+pub mod detail;
 pub mod done;
+pub mod help;
 pub mod list;
+pub mod review;
 pub mod scanning;
+pub mod theme;
 pub mod welcome;
 
+use std::{sync::OnceLock, time::Duration};
+
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
+pub use theme::Theme;
+
 use crate::app::{App, Screen};
 
 // ─── Constants ────────────────────────────────────────────────────────────────
@@ -59,6 +85,20 @@ use crate::app::{App, Screen};
 /// distracting.
 pub const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
+/// Sizes below this many bytes are drawn in [`Theme::size_small`].
+pub const SIZE_SMALL: u64 = 100_000_000;
+
+/// Sizes below this many bytes (and at least [`SIZE_SMALL`]) are drawn
+/// in [`Theme::size_medium`]; anything larger in [`Theme::size_large`].
+pub const SIZE_LARGE: u64 = 1_000_000_000;
+
+/// The narrowest terminal, in columns, that the screens are laid out for.
+/// Below this [`ui`] shows a short notice instead.
+pub const MIN_WIDTH: u16 = 30;
+
+/// The shortest terminal, in rows, that the screens are laid out for.
+pub const MIN_HEIGHT: u16 = 8;
+
 // ─── Dispatch ─────────────────────────────────────────────────────────────────
 
 /// Draws the current screen into `f`.
@@ -69,7 +109,25 @@ pub const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦"
 ///
 /// `app` is passed as `&mut` because Ratatui's list widget requires a mutable
 /// reference to [`ratatui::widgets::ListState`] when rendering a stateful list.
+///
+/// A zero-sized frame (which can happen for a moment while the terminal is
+/// being resized) draws nothing, and one smaller than [`MIN_WIDTH`] ×
+/// [`MIN_HEIGHT`] shows a "too small" notice, so the screen renderers never
+/// have to cope with a layout they can't fit.
 pub fn ui(f: &mut Frame, app: &mut App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        f.render_widget(
+            Paragraph::new("Terminal too small").style(Style::default().fg(theme.warning)),
+            area,
+        );
+        return;
+    }
+
     match &app.screen {
         Screen::Welcome => welcome::render_welcome(f, app),
         Screen::Scanning => scanning::render_scanning(f, app),
@@ -77,13 +135,91 @@ pub fn ui(f: &mut Frame, app: &mut App) {
         // the same renderer and it checks `app.screen` internally to decide
         // whether to draw the popup.
         Screen::List | Screen::Confirming => list::render_list(f, app),
+        Screen::SensitiveReview => {
+            list::render_list(f, app);
+            review::render_review(f, app);
+        }
+        Screen::Countdown => {
+            list::render_list(f, app);
+            countdown::render_countdown(f, app);
+        }
+        Screen::Breakdown => {
+            list::render_list(f, app);
+            breakdown::render_breakdown(f, app);
+        }
+        Screen::Detail => {
+            list::render_list(f, app);
+            detail::render_detail(f, app);
+        }
         Screen::Deleting => deleting::render_deleting(f, app),
         Screen::Done => done::render_done(f, app),
     }
+    render_status(f, app);
+    // The key list goes over whatever the screen drew.
+    if app.show_help {
+        help::render_help(f, app);
+    }
+}
+
+/// Returns the terminal window title for the current state, so a glance at
+/// a tab or taskbar shows what killnode is up to.
+///
+/// The binary sets it whenever it changes, and puts the user's own title
+/// back on exit.
+pub fn window_title(app: &App) -> String {
+    match app.screen {
+        Screen::Welcome => "killnode".to_string(),
+        Screen::Scanning => format!(
+            "killnode — scanning… ({} found)",
+            format_count(app.entries.len())
+        ),
+        Screen::List
+        | Screen::Confirming
+        | Screen::SensitiveReview
+        | Screen::Countdown
+        | Screen::Breakdown
+        | Screen::Detail => {
+            format!("killnode — {} found", format_count(app.entries.len()))
+        }
+        Screen::Deleting => format!(
+            "killnode — deleting {} / {}",
+            format_count(app.delete_done),
+            format_count(app.delete_total)
+        ),
+        Screen::Done => "killnode — done".to_string(),
+    }
 }
 
 // ─── Shared widgets ───────────────────────────────────────────────────────────
 
+/// Returns the style of the outer border on `screen`, so the frame edge says
+/// what state killnode is in at a glance:
+///
+/// | Screen | Border |
+/// |--------|--------|
+/// | Welcome, list (and its overlays) | [`Theme::text`] — nothing happening |
+/// | Scanning | [`Theme::warning`] — busy, nothing changed yet |
+/// | Deleting | [`Theme::danger`] — files are being removed |
+/// | Done | [`Theme::success`] |
+///
+/// Every screen's outer [`Block`] takes its border from here rather than
+/// choosing a colour itself. Popups keep their own borders.
+pub fn border_style(theme: &Theme, screen: &Screen) -> Style {
+    let color = match screen {
+        Screen::Welcome
+        | Screen::List
+        | Screen::Confirming
+        | Screen::SensitiveReview
+        | Screen::Countdown
+        | Screen::Breakdown
+        | Screen::Detail => theme.text,
+        Screen::Scanning => theme.warning,
+        Screen::Deleting => theme.danger,
+        Screen::Done => theme.success,
+    };
+    Style::default().fg(color)
+}
+
 /// Builds the help bar shown at the bottom of every screen.
 ///
 /// `pairs` is a slice of `(key, description)` tuples, for example:
@@ -95,10 +231,14 @@ pub fn ui(f: &mut Frame, app: &mut App) {
 ///
 /// The widget includes a top border line to visually separate the hint row
 /// from the content above it.
-pub fn help_bar<'a>(pairs: &[(&'a str, &'a str)]) -> Paragraph<'a> {
-    let mut spans: Vec<Span<'a>> = Vec::new();
+pub fn help_bar<K: AsRef<str>, D: AsRef<str>>(
+    theme: &Theme,
+    pairs: &[(K, D)],
+) -> Paragraph<'static> {
+    let mut spans: Vec<Span> = Vec::new();
 
     for (i, (key, desc)) in pairs.iter().enumerate() {
+        let (key, desc) = (key.as_ref(), desc.as_ref());
         if i > 0 {
             spans.push(Span::raw("   "));
         }
@@ -110,17 +250,51 @@ pub fn help_bar<'a>(pairs: &[(&'a str, &'a str)]) -> Paragraph<'a> {
         ));
         spans.push(Span::styled(
             format!(" {desc}"),
-            Style::default().fg(Color::Reset),
+            Style::default().fg(theme.text),
         ));
     }
 
     Paragraph::new(Line::from(spans)).block(
         Block::default()
             .borders(Borders::TOP)
-            .border_style(Style::default().fg(Color::Reset)),
+            .border_style(Style::default().fg(theme.text)),
     )
 }
 
+/// Draws [`App::status`], if there is one, into the bottom border of the
+/// frame — every screen has one — where it can't push anything around and
+/// goes away on its own. Any feature with something brief to say calls
+/// [`App::set_status`] and it shows up here, whichever screen is up.
+pub fn render_status(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let Some(status) = &app.status else {
+        return;
+    };
+    if area.width < 3 || area.height < 2 {
+        return;
+    }
+    // Cut off on the right if it's too long: the start says what happened.
+    let text: String = format!(" {status} ")
+        .chars()
+        .take(area.width as usize - 2)
+        .collect();
+    let row = Rect {
+        x: area.x + 1,
+        y: area.bottom() - 1,
+        width: text.chars().count() as u16,
+        height: 1,
+    };
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            text,
+            Style::default()
+                .fg(app.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        row,
+    );
+}
+
 // ─── Layout helpers ───────────────────────────────────────────────────────────
 
 /// Returns the area inside a one-cell border.
@@ -131,13 +305,18 @@ pub fn help_bar<'a>(pairs: &[(&'a str, &'a str)]) -> Paragraph<'a> {
 /// add 1 to `x` and `y`, subtract 2 from `width` and `height`.
 ///
 /// Using this consistently means content never visually overlaps the box
-/// outline, regardless of terminal size.
+/// outline, regardless of terminal size. An area too small to have an inside
+/// (less than 2 cells in either direction) yields an empty rect at its
+/// top-left corner rather than one that starts outside it.
 pub fn inner_area(area: Rect) -> Rect {
+    if area.width < 2 || area.height < 2 {
+        return Rect::new(area.x, area.y, 0, 0);
+    }
     Rect {
         x: area.x + 1,
         y: area.y + 1,
-        width: area.width.saturating_sub(2),
-        height: area.height.saturating_sub(2),
+        width: area.width - 2,
+        height: area.height - 2,
     }
 }
 
@@ -148,9 +327,12 @@ pub fn inner_area(area: Rect) -> Rect {
 /// centred both horizontally and vertically within `area`.
 ///
 /// Used by the confirmation popup in [`list`] to place the dialog in the
-/// middle of the screen regardless of terminal size.
+/// middle of the screen regardless of terminal size. The result always lies
+/// within `area`, even if `percent_x` is over 100 or `height` is taller than
+/// `area`.
 pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let w = area.width * percent_x / 100;
+    // Computed in u32 so a wide terminal can't overflow the multiplication.
+    let w = (u32::from(area.width) * u32::from(percent_x) / 100).min(u32::from(area.width)) as u16;
     let x = area.x + area.width.saturating_sub(w) / 2;
     let y = area.y + area.height.saturating_sub(height) / 2;
     Rect {
@@ -165,7 +347,8 @@ pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
 ///
 /// If the string fits within `max_width` it is returned unchanged. If it is
 /// too long, the leftmost characters are replaced with a single `…` so that
-/// the string is exactly `max_width` characters wide.
+/// the string is exactly `max_width` characters wide. A `max_width` of 0
+/// yields an empty string, since not even the `…` fits.
 ///
 /// Left-truncation is the right choice for file paths: the end of a path
 /// (the directory name) is almost always more meaningful than the root prefix.
@@ -176,6 +359,83 @@ pub fn truncate_left(s: &str, max_width: usize) -> String {
     if chars.len() <= max_width {
         return s.to_string();
     }
+    if max_width == 0 {
+        return String::new();
+    }
     let start = chars.len() - max_width + 1;
     format!("…{}", chars[start..].iter().collect::<String>())
 }
+
+// ─── Number formatting ────────────────────────────────────────────────────────
+
+/// Formats a count with its digits grouped in threes, using the separator
+/// the user's locale expects: `12,403` in English, `12.403` in German,
+/// `12 403` (with a non-breaking space) in French.
+pub fn format_count(n: usize) -> String {
+    group_digits(n, locale_separator())
+}
+
+/// Formats `n` followed by `singular` or `plural` to match it, with the
+/// number formatted by [`format_count`]: `1 directory`, `12,403 directories`.
+pub fn count_noun(n: usize, singular: &str, plural: &str) -> String {
+    let noun = if n == 1 { singular } else { plural };
+    format!("{} {noun}", format_count(n))
+}
+
+/// Formats a size in bytes for display: `48.21 kB`, `3.20 GB`.
+///
+/// Every size killnode shows — in the TUI and in headless reports — goes
+/// through here rather than calling [`humansize`] directly, so the units
+/// are the same everywhere and changing them is a one-line edit.
+pub fn format_bytes(bytes: u64) -> String {
+    humansize::format_size(bytes, humansize::DECIMAL)
+}
+
+/// Formats how long something took for display: `850 ms`, `4.2 s`,
+/// `2 min 05 s`.
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 60 {
+        format!("{} min {:02} s", secs / 60, secs % 60)
+    } else if d.as_millis() >= 1000 {
+        format!("{:.1} s", d.as_secs_f64())
+    } else {
+        format!("{} ms", d.as_millis())
+    }
+}
+
+/// Writes `n` with `sep` between each group of three digits.
+pub fn group_digits(n: usize, sep: char) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Returns the digit-group separator for the user's locale, read once from
+/// `LC_ALL`, `LC_NUMERIC` or `LANG` (the first one set, as the C library
+/// does).
+///
+/// Only the language part of the locale is looked at, and only languages
+/// whose convention is well settled are listed; everything else, including
+/// no locale at all, gets the English comma.
+fn locale_separator() -> char {
+    static SEP: OnceLock<char> = OnceLock::new();
+    *SEP.get_or_init(|| {
+        let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .find_map(|v| std::env::var(v).ok().filter(|v| !v.is_empty()))
+            .unwrap_or_default();
+        let lang = locale.split(['_', '.', '@', '-']).next().unwrap_or("");
+        match lang {
+            "de" | "nl" | "it" | "es" | "pt" | "da" | "id" | "tr" | "el" => '.',
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "uk" | "hu" => '\u{a0}',
+            _ => ',',
+        }
+    })
+}
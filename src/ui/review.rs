@@ -0,0 +1,120 @@
+//! # Sensitive review popup
+//!
+//! With `--interactive-sensitive-review`, confirming a deletion that includes
+//! sensitive entries doesn't start it straight away. This popup, drawn over
+//! the list, names every sensitive directory about to go and why it was
+//! flagged, and asks once more.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ┌─ Review sensitive directories ───────────────────────────────┐
+//! │  2 sensitive directories will be deleted:                     │
+//! │                                                               │
+//! │  ⚠ …/.config/app/node_modules                                 │
+//! │      app configuration in ~/.config                           │
+//! │  ⚠ /Applications/Foo.app/Contents/…/node_modules              │
+//! │      inside a macOS application bundle                        │
+//! ├───────────────────────────────────────────────────────────────┤
+//! │  [Y] I understand, delete   [S] Skip these   [N / Esc] Back   │
+//! └───────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! Enter is deliberately not one of the keys, so a user who has been
+//! pressing Enter to get through the confirmation can't also wave this
+//! through by accident.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::{centered_rect, count_noun, format_count, help_bar, inner_area, truncate_left};
+use crate::app::App;
+
+/// How many sensitive entries are listed by name. The rest are counted on
+/// one line.
+pub const REVIEW_TOP: usize = 6;
+
+/// Renders the review popup for [`App::sensitive_review`] on top of whatever
+/// was already drawn.
+pub fn render_review(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
+    let review = app.sensitive_review();
+    // Two rows per entry, the heading, the "more" line, and the help bar.
+    let popup_area = centered_rect(70, REVIEW_TOP as u16 * 2 + 7, area);
+    f.render_widget(Clear, popup_area);
+
+    // ── Popup border ──────────────────────────────────────────────────────────
+    //
+    // Red, unlike the yellow confirmation popup: the user has already said
+    // yes once, and this is about the entries most likely to break something.
+    let danger = Style::default()
+        .fg(theme.danger)
+        .add_modifier(Modifier::BOLD);
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(danger)
+        .title(Span::styled(" Review sensitive directories ", danger));
+    f.render_widget(popup_block, popup_area);
+
+    let inner = inner_area(popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // heading
+            Constraint::Min(0),    // entries
+            Constraint::Length(2), // help bar
+        ])
+        .split(inner);
+
+    // ── Heading ───────────────────────────────────────────────────────────────
+    let heading = Line::from(vec![
+        Span::raw("  "),
+        Span::styled(
+            count_noun(review.len(), "sensitive directory", "sensitive directories"),
+            danger,
+        ),
+        Span::styled(" will be deleted:", theme.dim),
+    ]);
+    f.render_widget(Paragraph::new(heading), chunks[0]);
+
+    // ── Entries ───────────────────────────────────────────────────────────────
+    let path_w = (chunks[1].width as usize).saturating_sub(4);
+    let mut lines = Vec::new();
+    for (path, reason) in review.iter().take(REVIEW_TOP) {
+        lines.push(Line::from(vec![
+            Span::styled("  ⚠ ", Style::default().fg(theme.danger)),
+            Span::styled(truncate_left(path, path_w), Style::default().fg(theme.text)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("      {reason}"),
+            theme.dim,
+        )));
+    }
+    if review.len() > REVIEW_TOP {
+        lines.push(Line::from(Span::styled(
+            format!("  + {} more", format_count(review.len() - REVIEW_TOP)),
+            theme.dim,
+        )));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+
+    // ── Help bar ──────────────────────────────────────────────────────────────
+    f.render_widget(help_bar(&theme, HINTS), chunks[2]);
+}
+
+/// The keys that work on the review, for its help bar and the `?` overlay.
+pub const HINTS: &[(&str, &str)] = &[
+    ("Y", "I understand, delete"),
+    ("S", "Skip these"),
+    ("N / Esc", "Back"),
+    ("?", "Help"),
+];
@@ -14,30 +14,41 @@
 //! │   Currently scanning:                                         │
 //! │   …/alice/projects/some-deep/nested/path                      │
 //! │                                                               │
-//! │                        (spacer)                               │
+//! │   Largest so far:                                             │
+//! │     1.20 GB  …/alice/projects/monorepo/node_modules           │
+//! │   412.87 MB  …/alice/projects/app/node_modules                │
 //! │                                                               │
 //! ├───────────────────────────────────────────────────────────────┤
-//! │  [Q] Quit                                                     │
+//! │  [Esc] Back   [Q] Quit   [?] Help                             │
 //! └───────────────────────────────────────────────────────────────┘
 //! ```
 //!
-//! The spinner in the title bar and the "currently scanning" path are the two
-//! live elements — everything else is static until the scan completes.
+//! The spinner in the title bar, the "currently scanning" path and the list
+//! below it are the live elements. The list is [`App::entries`] itself,
+//! which stays in order as results arrive (see [`crate::app::sorted_position`]),
+//! so the biggest offenders are at the top before the scan is over and the
+//! list screen opens without reshuffling.
+//!
+//! A scan that runs for more than about ten seconds (see
+//! [`crate::app::SLOW_SCAN_TICKS`]) gets a note under the path saying why that
+//! can happen and how to stop it, so a long scan doesn't look like a hang.
 
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
 };
 
-use super::{SPINNER, help_bar, inner_area, truncate_left};
-use crate::app::App;
+use super::{
+    SPINNER, border_style, format_bytes, format_count, help_bar, inner_area, truncate_left,
+};
+use crate::app::{App, SortMode};
 
 /// Renders the scanning screen into `f`.
 ///
-/// The layout has five vertical regions:
+/// The layout has six vertical regions:
 ///
 /// 1. **Found count** — shows how many `node_modules` directories have been
 ///    discovered so far. Updates on every frame as new results stream in from
@@ -51,12 +62,20 @@ use crate::app::App;
 ///    via [`truncate_left`] so the most meaningful part (the end of the path)
 ///    always stays visible.
 ///
-/// 4. **Spacer** — fills remaining vertical space so the help bar stays at the
-///    bottom of the screen.
+/// 4. **So far** — the entries found so far, in the list's order, as many as
+///    fit. Sizes are left off under `--no-size`.
+///
+/// 5. **Slow-scan note** — appears below the list once
+///    [`App::scan_is_slow`].
 ///
-/// 5. **Help bar** — only one action is available during a scan: quit.
+/// 6. **Help bar** — the keys that work during a scan: Esc back to the
+///    welcome screen, Q to quit and ? for help (see [`HINTS`]).
 pub fn render_scanning(f: &mut Frame, app: &App) {
     let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
 
     // Advance the spinner by dividing the ticker by 2 so each frame is held
     // for ~160 ms rather than the raw ~80 ms tick rate. Fast enough to look
@@ -65,14 +84,15 @@ pub fn render_scanning(f: &mut Frame, app: &App) {
     let count = app.entries.len();
 
     // Outer border. The title turns yellow and shows a spinner while scanning,
-    // making it visually distinct from the red title on the Welcome screen.
+    // making it visually distinct from the red title on the Welcome screen,
+    // and the border follows (see `border_style`).
     let outer = ratatui::widgets::Block::default()
         .borders(ratatui::widgets::Borders::ALL)
-        .border_style(Style::default().fg(Color::Reset))
+        .border_style(border_style(&theme, &app.screen))
         .title(Span::styled(
             format!(" 💥 killnode  {}  Scanning… ", spinner),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         ));
     f.render_widget(outer, area);
@@ -86,7 +106,8 @@ pub fn render_scanning(f: &mut Frame, app: &App) {
             Constraint::Length(2), // found count (1 line + breathing room)
             Constraint::Length(1), // "Currently scanning:" label
             Constraint::Length(1), // live path
-            Constraint::Min(0),    // spacer — expands to fill available height
+            Constraint::Min(0),    // entries so far — expands to fill available height
+            Constraint::Length(if app.scan_is_slow() { 3 } else { 0 }), // slow-scan note
             Constraint::Length(3), // help bar (1 line of hints + top border + padding)
         ])
         .split(inner);
@@ -97,24 +118,14 @@ pub fn render_scanning(f: &mut Frame, app: &App) {
     // surrounding text is dimmed so the number is the clear focal point.
     f.render_widget(
         Paragraph::new(Line::from(vec![
+            Span::styled("  Found  ", theme.dim),
             Span::styled(
-                "  Found  ",
+                format_count(count),
                 Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
-            ),
-            Span::styled(
-                count.to_string(),
-                Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.success)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(
-                "  node_modules so far…",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
-            ),
+            Span::styled("  node_modules so far…", theme.dim),
         ])),
         chunks[0],
     );
@@ -125,12 +136,7 @@ pub fn render_scanning(f: &mut Frame, app: &App) {
     // line rather than inline with the path so the path has the full width of
     // the terminal available.
     f.render_widget(
-        Paragraph::new(Line::from(Span::styled(
-            "  Currently scanning:",
-            Style::default()
-                .fg(Color::Reset)
-                .add_modifier(Modifier::DIM),
-        ))),
+        Paragraph::new(Line::from(Span::styled("  Currently scanning:", theme.dim))),
         chunks[1],
     );
 
@@ -146,13 +152,80 @@ pub fn render_scanning(f: &mut Frame, app: &App) {
     f.render_widget(
         Paragraph::new(Line::from(Span::styled(
             format!("  {display_path}"),
-            Style::default()
-                .fg(Color::Reset)
-                .add_modifier(Modifier::DIM),
+            theme.dim,
         ))),
         chunks[2],
     );
 
+    // ── So far ────────────────────────────────────────────────────────────────
+    //
+    // As many entries as fit under a blank line and the heading. Sizes are
+    // right-aligned to the widest one shown so the paths line up.
+    let rows = (chunks[3].height as usize).saturating_sub(2);
+    if count > 0 && rows > 0 {
+        let shown = &app.entries[..count.min(rows)];
+        let sizes: Vec<String> = shown
+            .iter()
+            .map(|e| {
+                if app.sizes_known() {
+                    format_bytes(e.size)
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+        let size_w = sizes.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+        let path_w = (inner.width as usize).saturating_sub(size_w + 6);
+        let heading = match app.sort_mode {
+            SortMode::Size | SortMode::SizeAscending if !app.sizes_known() => "  So far:",
+            SortMode::Size => "  Largest so far:",
+            SortMode::SizeAscending => "  Smallest so far:",
+            SortMode::Newest => "  Newest so far:",
+            SortMode::Oldest => "  Oldest so far:",
+            SortMode::Path => "  So far, by path:",
+            SortMode::Smart => "  So far, most worth deleting first:",
+        };
+        let mut lines = vec![Line::raw(""), Line::from(Span::styled(heading, theme.dim))];
+        lines.extend(shown.iter().zip(sizes).map(|(entry, size)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {size:>size_w$}  "),
+                    Style::default().fg(theme.size_color(entry.size)),
+                ),
+                Span::styled(
+                    truncate_left(&entry.path, path_w),
+                    Style::default().fg(theme.text),
+                ),
+            ])
+        }));
+        f.render_widget(Paragraph::new(lines), chunks[3]);
+    }
+
+    // ── Slow-scan note ────────────────────────────────────────────────────────
+    //
+    // The usual causes are a huge tree or a slow (often network) filesystem;
+    // the path above shows which. Either way the scan is still going, and
+    // the user should know they can stop it.
+    if app.scan_is_slow() {
+        f.render_widget(
+            Paragraph::new(vec![
+                Line::raw(""),
+                Line::from(Span::styled(
+                    "  Still going — deep trees and slow or network filesystems take a while.",
+                    Style::default().fg(theme.warning),
+                )),
+                Line::from(Span::styled(
+                    "  Press Esc to stop and go back, or Q to quit.",
+                    theme.dim,
+                )),
+            ]),
+            chunks[4],
+        );
+    }
+
     // ── Help bar ──────────────────────────────────────────────────────────────
-    f.render_widget(help_bar(&[("Q", "Quit")]), chunks[4]);
+    f.render_widget(help_bar(&theme, HINTS), chunks[5]);
 }
+
+/// The keys that work while scanning, for the help bar and the `?` overlay.
+pub const HINTS: &[(&str, &str)] = &[("Esc", "Back"), ("Q", "Quit"), ("?", "Help")];
@@ -0,0 +1,132 @@
+//! # Colour themes
+//!
+//! Every colour the UI draws with comes from a [`Theme`], so switching
+//! palettes is a matter of picking a different one at startup rather than
+//! hunting down `Color::` literals across the screens.
+//!
+//! Colours are named by *role* (danger, warning, …) rather than by hue, so a
+//! theme can make a role any colour it likes — or no colour at all — and
+//! every screen follows along.
+//!
+//! ## Built-in themes
+//!
+//! | Theme | Chosen with | Notes |
+//! |-------|-------------|-------|
+//! | [`Theme::DEFAULT`] | (default) | The terminal's own foreground, with red/yellow/green/cyan accents and dimmed labels |
+//! | [`Theme::HIGH_CONTRAST`] | `--theme high-contrast` | Bright white text and bright accents; labels are never dimmed |
+//! | [`Theme::MONO`] | `--theme mono` | No colours at all — just bold, dim and reverse video |
+
+use ratatui::style::{Color, Modifier, Style};
+
+use super::{SIZE_LARGE, SIZE_SMALL};
+use crate::cli::ThemeName;
+
+/// The palette the UI is drawn with. Held by [`crate::app::App`] and read by
+/// every renderer.
+#[derive(Clone, Copy, Debug)]
+pub struct Theme {
+    /// Ordinary text and box outlines.
+    pub text: Color,
+
+    /// Secondary text: labels, hints and anything that should recede behind
+    /// the values it describes.
+    pub dim: Style,
+
+    /// Destructive actions, failures and sensitive paths. Also the app title.
+    pub danger: Color,
+
+    /// Things that deserve attention but aren't failures: skipped paths,
+    /// the scanning title, modification ages.
+    pub warning: Color,
+
+    /// Selected entries, counts and completion.
+    pub success: Color,
+
+    /// Neutral highlights: the scan root, freed totals, symlink markers.
+    pub info: Color,
+
+    /// Sizes below [`SIZE_SMALL`].
+    pub size_small: Color,
+
+    /// Sizes from [`SIZE_SMALL`] up to [`SIZE_LARGE`].
+    pub size_medium: Color,
+
+    /// Sizes of [`SIZE_LARGE`] and up.
+    pub size_large: Color,
+}
+
+impl Theme {
+    /// The standard palette. Uses the terminal's default foreground for text
+    /// so it works on both light and dark backgrounds.
+    pub const DEFAULT: Self = Self {
+        text: Color::Reset,
+        dim: Style::new().fg(Color::Reset).add_modifier(Modifier::DIM),
+        danger: Color::Red,
+        warning: Color::Yellow,
+        success: Color::Green,
+        info: Color::Cyan,
+        size_small: Color::Green,
+        size_medium: Color::Yellow,
+        size_large: Color::Red,
+    };
+
+    /// Bright colours and undimmed labels, for terminals (or eyes) where the
+    /// default palette is hard to read. Assumes a dark background.
+    pub const HIGH_CONTRAST: Self = Self {
+        text: Color::White,
+        dim: Style::new().fg(Color::White),
+        danger: Color::LightRed,
+        warning: Color::LightYellow,
+        success: Color::LightGreen,
+        info: Color::LightCyan,
+        size_small: Color::LightGreen,
+        size_medium: Color::LightYellow,
+        size_large: Color::LightRed,
+    };
+
+    /// No colour at all. Emphasis still comes through bold, dim and reverse
+    /// video, which every terminal supports.
+    pub const MONO: Self = Self {
+        text: Color::Reset,
+        dim: Style::new().fg(Color::Reset).add_modifier(Modifier::DIM),
+        danger: Color::Reset,
+        warning: Color::Reset,
+        success: Color::Reset,
+        info: Color::Reset,
+        size_small: Color::Reset,
+        size_medium: Color::Reset,
+        size_large: Color::Reset,
+    };
+
+    /// Returns the built-in theme called `name`.
+    pub fn named(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Default => Self::DEFAULT,
+            ThemeName::HighContrast => Self::HIGH_CONTRAST,
+            ThemeName::Mono => Self::MONO,
+        }
+    }
+
+    /// Returns the colour for a size of `bytes`, graded by magnitude so the
+    /// biggest offenders stand out: [`Self::size_small`] below [`SIZE_SMALL`]
+    /// (100 MB), [`Self::size_medium`] below [`SIZE_LARGE`] (1 GB),
+    /// [`Self::size_large`] from there up.
+    ///
+    /// Used for every per-entry or to-be-freed size so the scale means the
+    /// same thing wherever it appears.
+    pub fn size_color(&self, bytes: u64) -> Color {
+        if bytes < SIZE_SMALL {
+            self.size_small
+        } else if bytes < SIZE_LARGE {
+            self.size_medium
+        } else {
+            self.size_large
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -1,9 +1,11 @@
 //! # Welcome screen
 //!
 //! The first thing the user sees when killnode starts. It is intentionally
-//! minimal — just the scan root path and two key hints. The goal is to give
-//! the user a chance to confirm they are about to scan the right directory
-//! before any filesystem work begins.
+//! minimal — just the scan root paths, the free space on their disk, and
+//! two key hints. The goal is to give the user a chance to confirm they are
+//! about to scan the right directories before any filesystem work begins.
+//! With several roots, each gets its own line in the box, titled "Scan
+//! roots".
 //!
 //! ## Layout
 //!
@@ -12,6 +14,7 @@
 //! │ ┌─ Scan root ───────────────────────────────────────────┐  │
 //! │ │  /home/alice/projects                                 │  │
 //! │ └───────────────────────────────────────────────────────┘  │
+//! │   Free space  48.20 GB of 512.11 GB                         │
 //! │                                                             │
 //! │                        (spacer)                             │
 //! │                                                             │
@@ -23,76 +26,129 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
-use super::{help_bar, inner_area};
+use super::{border_style, format_bytes, format_count, help_bar, inner_area};
 use crate::app::App;
 
 /// Renders the welcome screen into `f`.
 ///
-/// The layout has three vertical regions:
+/// The layout has four vertical regions:
 ///
 /// 1. **Scan root box** — a small bordered widget showing the directory that
 ///    will be scanned. Displayed in cyan so it stands out as the one piece of
 ///    information the user should verify before pressing Enter.
 ///
-/// 2. **Spacer** — fills the remaining vertical space so the help bar is
+/// 2. **Free space** — how much room is left on the disk holding the roots
+///    (see [`crate::diskspace`]). Left blank if the OS couldn't say.
+///
+/// 3. **Spacer** — fills the remaining vertical space so the help bar is
 ///    pushed to the bottom rather than floating in the middle of the screen.
 ///
-/// 3. **Help bar** — shows the two available actions: start the scan or quit.
+/// 4. **Help bar** — shows the two available actions: start the scan or quit.
 pub fn render_welcome(f: &mut Frame, app: &App) {
     let area = f.area();
+    if area.is_empty() {
+        return;
+    }
+    let theme = app.theme;
 
     // Outer border with the app title in the top-left corner.
     let outer = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Reset))
+        .border_style(border_style(&theme, &app.screen))
         .title(Span::styled(
             " 💥 killnode ",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
         ));
     f.render_widget(outer, area);
 
     // Work inside the border so content doesn't overlap the box outline.
     let inner = inner_area(area);
 
+    // One line per root, but never so many that the help bar is squeezed
+    // out; the rest are cut off with a count.
+    let max_lines = inner.height.saturating_sub(6).max(1) as usize;
+    let roots_height = (app.scan_roots.len().min(max_lines) + 2) as u16;
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // scan root box (1 line of text + top/bottom border)
-            Constraint::Min(0),    // spacer — expands to fill available height
-            Constraint::Length(3), // help bar (1 line of hints + top border + padding)
+            Constraint::Length(roots_height), // scan root box (1 line per root + top/bottom border)
+            Constraint::Length(1),            // free space
+            Constraint::Min(0),               // spacer — expands to fill available height
+            Constraint::Length(3),            // help bar (1 line of hints + top border + padding)
         ])
         .split(inner);
 
     // ── Scan root ─────────────────────────────────────────────────────────────
     //
-    // Shows the directory that will be scanned when the user presses Enter.
-    // Rendered with a border and a "Scan root" title so it reads like a
-    // labelled field rather than just a raw string floating on screen.
-    let root = Paragraph::new(Line::from(Span::styled(
-        format!("  {}", app.scan_root),
-        Style::default().fg(Color::Cyan),
-    )))
-    .block(
+    // Shows the directories that will be scanned when the user presses
+    // Enter, one per line. Rendered with a border and a "Scan root" title so
+    // it reads like a labelled field rather than raw strings floating on
+    // screen.
+    let roots = &app.scan_roots;
+    let mut lines: Vec<Line> = roots
+        .iter()
+        .take(max_lines)
+        .map(|r| {
+            Line::from(Span::styled(
+                format!("  {r}"),
+                Style::default().fg(theme.info),
+            ))
+        })
+        .collect();
+    if roots.len() > max_lines
+        && let Some(last) = lines.last_mut()
+    {
+        *last = Line::from(Span::styled(
+            format!("  … and {} more", format_count(roots.len() - max_lines + 1)),
+            theme.dim,
+        ));
+    }
+    let title = if roots.len() == 1 {
+        " Scan root "
+    } else {
+        " Scan roots "
+    };
+    let root = Paragraph::new(lines).block(
         Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Reset))
-            .title(Span::styled(
-                " Scan root ",
-                Style::default()
-                    .fg(Color::Reset)
-                    .add_modifier(Modifier::DIM),
-            )),
+            .border_style(Style::default().fg(theme.text))
+            .title(Span::styled(title, theme.dim)),
     );
     f.render_widget(root, chunks[0]);
 
+    // ── Free space ────────────────────────────────────────────────────────────
+    //
+    // Roots spread over several disks are added up, and the line says so.
+    if let Some(space) = app.disk_space {
+        let mut spans = vec![
+            Span::styled("  Free space  ", theme.dim),
+            Span::styled(
+                format_bytes(space.available),
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" of {}", format_bytes(space.total)), theme.dim),
+        ];
+        if space.filesystems > 1 {
+            spans.push(Span::styled(
+                format!("  (across {} filesystems)", format_count(space.filesystems)),
+                theme.dim,
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), chunks[1]);
+    }
+
     // ── Help bar ──────────────────────────────────────────────────────────────
-    f.render_widget(
-        help_bar(&[("Enter", "Start scan"), ("Q", "Quit")]),
-        chunks[2],
-    );
+    f.render_widget(help_bar(&theme, HINTS), chunks[3]);
 }
+
+/// The keys that work on the welcome screen, for its help bar and the `?`
+/// overlay.
+pub const HINTS: &[(&str, &str)] = &[("Enter", "Start scan"), ("Q / Esc", "Quit"), ("?", "Help")];
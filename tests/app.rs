@@ -0,0 +1,1454 @@
+//! Checks for [`App`] state transitions that don't need a terminal.
+
+use std::sync::{Arc, Mutex, atomic::AtomicBool, mpsc};
+
+use killnode::{
+    app::{
+        App, ListRow, MESSAGES_PER_TICK, NodeModuleEntry, Screen, SizeStats, SortMode,
+        group_entries, insert_sorted, sort_entries_by, sorted_position,
+    },
+    cli::{Options, SensitivePolicy},
+    deleter::{DeleteMode, DeleteMsg},
+    scanner::{ActiveScan, ScanMsg, SensitiveReason},
+};
+
+fn app_on(screen: Screen) -> App {
+    let mut app = App::new(Options::default());
+    app.screen = screen;
+    app
+}
+
+#[test]
+fn esc_goes_back_one_screen_at_a_time() {
+    let mut app = app_on(Screen::Confirming);
+    app.delete_target = Some("/x/node_modules".to_string());
+
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::List));
+    assert_eq!(app.delete_target, None);
+
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::Welcome));
+
+    assert!(app.go_back());
+}
+
+#[test]
+fn esc_while_scanning_stops_the_scan_thread() {
+    let mut app = app_on(Screen::Scanning);
+    let (_tx, rx) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Arc::clone(&stop),
+    });
+
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::Welcome));
+    assert!(app.scan.is_none());
+    assert!(stop.load(std::sync::atomic::Ordering::Relaxed));
+}
+
+#[test]
+fn esc_cancels_a_delete_and_ends_on_the_summary() {
+    let mut app = app_on(Screen::Deleting);
+    app.entries = vec![NodeModuleEntry::new(
+        "/p/b/node_modules".to_string(),
+        1,
+        None,
+        None,
+        false,
+    )];
+    let (tx, rx) = mpsc::channel();
+    app.delete_rx = Some(rx);
+    app.delete_stop = Some(Arc::new(AtomicBool::new(false)));
+
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::Deleting));
+    assert!(app.delete_cancelled());
+
+    tx.send(DeleteMsg::Done {
+        freed: 0,
+        deleted: Vec::new(),
+        skipped: vec!["/p/b/node_modules: cancelled".to_string()],
+        errors: Vec::new(),
+        timings: Vec::new(),
+    })
+    .unwrap();
+    app.process_delete_messages();
+    assert!(matches!(app.screen, Screen::Done));
+    assert_eq!(app.delete_skipped.len(), 1);
+    assert!(app.delete_stop.is_none());
+}
+
+#[test]
+fn progress_carries_the_bytes_freed_so_far() {
+    let mut app = app_on(Screen::Deleting);
+    let (tx, rx) = mpsc::channel();
+    app.delete_rx = Some(rx);
+
+    for (path, freed) in [("/p/a/node_modules", 0), ("/p/b/node_modules", 4000)] {
+        tx.send(DeleteMsg::Progress {
+            path: path.to_string(),
+            freed,
+        })
+        .unwrap();
+    }
+    app.process_delete_messages();
+    assert_eq!(app.delete_done, 0);
+    assert_eq!(app.delete_current, "/p/b/node_modules");
+    assert_eq!(app.delete_run_freed, 4000);
+
+    // Only a finished path moves the progress bar.
+    for freed in [4000, 9000] {
+        tx.send(DeleteMsg::Finished { freed }).unwrap();
+    }
+    app.process_delete_messages();
+    assert_eq!(app.delete_done, 2);
+    assert_eq!(app.delete_run_freed, 9000);
+}
+
+#[test]
+fn the_time_left_is_extrapolated_once_it_has_settled() {
+    use std::time::{Duration, Instant};
+    let mut app = app_on(Screen::Deleting);
+    app.delete_total = 6;
+    assert_eq!(app.delete_eta(), None);
+
+    // Nothing done yet, then too early to trust.
+    app.delete_started_at = Instant::now().checked_sub(Duration::from_secs(4));
+    assert_eq!(app.delete_eta(), None);
+    app.delete_done = 2;
+    let eta = app.delete_eta().unwrap();
+    assert!(eta >= Duration::from_secs(8) && eta < Duration::from_secs(9));
+    app.delete_started_at = Some(Instant::now());
+    assert_eq!(app.delete_eta(), None);
+
+    // Nothing to estimate once everything is done.
+    app.delete_started_at = Instant::now().checked_sub(Duration::from_secs(4));
+    app.delete_done = 6;
+    assert_eq!(app.delete_eta(), None);
+}
+
+#[test]
+fn the_slowest_removal_is_kept_across_runs() {
+    use std::time::Duration;
+    let mut app = app_on(Screen::Deleting);
+    for timings in [
+        vec![("/p/a".to_string(), 5), ("/p/b".to_string(), 30)],
+        vec![("/p/c".to_string(), 20)],
+    ] {
+        let (tx, rx) = mpsc::channel();
+        app.delete_rx = Some(rx);
+        tx.send(DeleteMsg::Done {
+            freed: 0,
+            deleted: Vec::new(),
+            skipped: Vec::new(),
+            errors: Vec::new(),
+            timings: timings
+                .into_iter()
+                .map(|(p, secs)| (p, Duration::from_secs(secs)))
+                .collect(),
+        })
+        .unwrap();
+        app.process_delete_messages();
+    }
+    assert_eq!(
+        app.delete_slowest,
+        Some(("/p/b".to_string(), Duration::from_secs(30)))
+    );
+}
+
+#[test]
+fn the_queued_set_is_counted_as_safe_and_sensitive() {
+    let mut app = app_on(Screen::List);
+    app.entries = [
+        ("/p/a/node_modules", None),
+        ("/p/b/node_modules", None),
+        (
+            "/home/u/.config/x/node_modules",
+            Some(SensitiveReason::XdgConfig),
+        ),
+    ]
+    .into_iter()
+    .map(|(p, reason)| NodeModuleEntry::new(p.into(), 1, reason, None, false))
+    .collect();
+    for entry in &mut app.entries {
+        entry.selected = true;
+    }
+    assert_eq!(app.selected_safe_count(), 2);
+    assert_eq!(app.selected_sensitive_count(), 1);
+
+    // A single entry queued with `x` is all that counts.
+    app.delete_target = Some("/p/b/node_modules".to_string());
+    assert_eq!(app.selected_safe_count(), 1);
+    assert_eq!(app.selected_sensitive_count(), 0);
+}
+
+#[test]
+fn sensitive_deletions_want_the_word_typed_out() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![NodeModuleEntry::new(
+        "/home/u/.config/x/node_modules".into(),
+        1,
+        Some(SensitiveReason::XdgConfig),
+        None,
+        false,
+    )];
+    app.entries[0].selected = true;
+    app.request_delete();
+    assert!(app.needs_typed_confirm());
+
+    for c in "delte".chars() {
+        app.push_confirm_typed(c);
+    }
+    app.submit_confirm_typed();
+    assert!(app.confirm_mismatch && app.confirm_typed.is_empty());
+    assert!(matches!(app.screen, Screen::Confirming));
+
+    for c in "Delete".chars() {
+        app.push_confirm_typed(c);
+    }
+    assert!(!app.confirm_mismatch);
+    app.submit_confirm_typed();
+    assert!(matches!(app.screen, Screen::Countdown));
+}
+
+#[test]
+fn clicks_highlight_then_toggle_and_the_wheel_stops_at_the_ends() {
+    let mut app = app_on(Screen::List);
+    app.entries = (0..5)
+        .map(|i| NodeModuleEntry::new(format!("/p/{i}/node_modules"), 1, None, None, false))
+        .collect();
+    for e in &mut app.entries {
+        e.selected = false;
+    }
+    app.list_state.select(Some(0));
+    app.list_area = ratatui::layout::Rect::new(1, 3, 60, 3);
+
+    // A click on a row's path highlights it; a second click toggles it.
+    app.click_list(30, 4);
+    assert_eq!(app.list_state.selected(), Some(1));
+    assert!(!app.entries[1].selected);
+    app.click_list(30, 4);
+    assert!(app.entries[1].selected);
+
+    // A click on a checkbox toggles straight away.
+    app.click_list(2, 5);
+    assert!(app.entries[2].selected);
+
+    // Outside the rows, nothing happens.
+    app.click_list(30, 6);
+    assert_eq!(app.list_state.selected(), Some(2));
+
+    app.jump_to_end();
+    app.scroll_down();
+    assert_eq!(app.list_state.selected(), Some(4));
+    app.jump_to_start();
+    app.scroll_up();
+    app.scroll_down();
+    assert_eq!(app.list_state.selected(), Some(1));
+}
+
+#[test]
+fn r_on_done_retries_only_the_failed_paths_and_merges_the_results() {
+    let dir = tempfile::tempdir().unwrap();
+    let unlocked = dir.path().join("a/node_modules");
+    std::fs::create_dir_all(&unlocked).unwrap();
+    let unlocked = unlocked.to_string_lossy().to_string();
+    let missing = dir
+        .path()
+        .join("b/node_modules")
+        .to_string_lossy()
+        .to_string();
+
+    let mut app = app_on(Screen::Done);
+    app.entries = [&unlocked, &missing]
+        .iter()
+        .map(|p| NodeModuleEntry::new(p.to_string(), 7, None, None, false))
+        .collect();
+    app.delete_removed = 3;
+    app.delete_skipped = vec!["/c/node_modules: cancelled".to_string()];
+    app.delete_errors = vec![
+        (unlocked.clone(), format!("{unlocked}: locked")),
+        (missing.clone(), format!("{missing}: locked")),
+    ];
+    app.error_filter = "locked".to_string();
+
+    app.retry_failed();
+    assert!(matches!(app.screen, Screen::Deleting));
+    assert_eq!(app.delete_total, 2);
+    assert!(app.delete_errors.is_empty() && app.error_filter.is_empty());
+    while app.delete_rx.is_some() {
+        app.process_delete_messages();
+    }
+
+    // Back on the summary, with one more removed and only the path that
+    // failed again still an error.
+    assert!(matches!(app.screen, Screen::Done));
+    assert_eq!(app.delete_removed, 4);
+    assert_eq!(app.delete_freed, 7);
+    assert_eq!(app.delete_skipped.len(), 1);
+    assert_eq!(app.delete_errors.len(), 1);
+    assert_eq!(app.delete_errors[0].0, missing);
+    assert_eq!(app.entries.len(), 1);
+
+    // Nothing failed, nothing to retry.
+    app.delete_errors.clear();
+    app.retry_failed();
+    assert!(matches!(app.screen, Screen::Done));
+}
+
+#[test]
+fn esc_on_done_clears_the_filter_before_quitting() {
+    let mut app = app_on(Screen::Done);
+    app.delete_errors = vec![
+        ("/a".to_string(), "/a: denied".to_string()),
+        ("/b".to_string(), "/b: busy".to_string()),
+    ];
+    app.begin_error_filter();
+    app.push_error_filter('b');
+    assert_eq!(app.visible_errors().len(), 1);
+
+    assert!(!app.go_back());
+    assert!(!app.error_filter_editing);
+    assert_eq!(app.visible_errors().len(), 2);
+
+    assert!(app.go_back());
+}
+
+#[test]
+fn smart_sort_puts_big_old_directories_first() {
+    const DAY: u64 = 86_400;
+    let now = 1000 * DAY;
+    let entry = |path: &str, size, age_days| {
+        NodeModuleEntry::new(
+            path.to_string(),
+            size,
+            None,
+            Some(now - age_days * DAY),
+            false,
+        )
+    };
+    let mut entries = vec![
+        entry("big-active", 1_000, 1),
+        entry("small-old", 100, 400),
+        entry("big-old", 1_000, 400),
+    ];
+
+    sort_entries_by(&mut entries, SortMode::Smart, now);
+    let order: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(order, ["big-old", "small-old", "big-active"]);
+
+    sort_entries_by(&mut entries, SortMode::Size, now);
+    assert_eq!(entries[2].path, "small-old");
+}
+
+#[test]
+fn cycling_the_sort_keeps_the_cursor_on_its_entry() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![
+        NodeModuleEntry::new("big-new".to_string(), 1_000, None, None, false),
+        NodeModuleEntry::new("small-old".to_string(), 10, None, Some(0), false),
+    ];
+    app.list_state.select(Some(1));
+
+    app.cycle_sort();
+
+    assert_eq!(app.sort_mode, SortMode::SizeAscending);
+    assert_eq!(app.entries[0].path, "small-old");
+    assert_eq!(app.list_state.selected(), Some(0));
+}
+
+#[test]
+fn every_sort_mode_orders_and_places_entries_consistently() {
+    let entry = |path: &str, size, modified| {
+        NodeModuleEntry::new(path.to_string(), size, None, modified, false)
+    };
+    let all = || {
+        vec![
+            entry("/b", 30, Some(100)),
+            entry("/A", 10, None),
+            entry("/c", 20, Some(300)),
+        ]
+    };
+    let cases = [
+        (SortMode::Size, ["/b", "/c", "/A"]),
+        (SortMode::SizeAscending, ["/A", "/c", "/b"]),
+        (SortMode::Newest, ["/c", "/b", "/A"]),
+        (SortMode::Oldest, ["/b", "/c", "/A"]),
+        (SortMode::Path, ["/A", "/b", "/c"]),
+    ];
+    for (mode, expected) in cases {
+        let mut entries = all();
+        sort_entries_by(&mut entries, mode, 1_000);
+        let order: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(order, expected, "{mode:?}");
+
+        // Inserting one at a time gives the same order as sorting.
+        let mut placed = Vec::new();
+        for e in all() {
+            let slot = sorted_position(&placed, &e, mode, 1_000);
+            placed.insert(slot, e);
+        }
+        let order: Vec<&str> = placed.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(order, expected, "{mode:?} placed");
+    }
+
+    // The cycle visits every mode before coming back round.
+    let mut mode = SortMode::Size;
+    for _ in 0..6 {
+        mode = mode.next();
+    }
+    assert_eq!(mode, SortMode::Size);
+}
+
+#[test]
+fn force_quit_needs_two_presses() {
+    let mut app = app_on(Screen::Deleting);
+    assert!(!app.press_force_quit());
+    assert!(app.force_quit_armed);
+    assert!(app.press_force_quit());
+}
+
+#[test]
+fn size_stats_summarise_every_entry() {
+    let mut app = app_on(Screen::List);
+    assert_eq!(app.size_stats(), None);
+
+    for size in [40, 10, 30, 20] {
+        let path = format!("/p{size}/node_modules");
+        app.entries
+            .push(NodeModuleEntry::new(path, size, None, None, false));
+    }
+    assert_eq!(
+        app.size_stats(),
+        Some(SizeStats {
+            average: 25,
+            median: 25,
+            largest: 40,
+        })
+    );
+
+    app.entries.pop();
+    assert_eq!(app.size_stats().map(|s| s.median), Some(30));
+}
+
+#[test]
+fn navigation_wraps_at_both_ends_by_default() {
+    let mut app = app_on(Screen::List);
+    app.entries = (0..3)
+        .map(|i| NodeModuleEntry::new(format!("/{i}/node_modules"), 10, None, None, false))
+        .collect();
+    assert!(app.wrap_navigation);
+
+    app.list_state.select(Some(0));
+    app.navigate_up();
+    assert_eq!(app.list_state.selected(), Some(2));
+    app.navigate_down();
+    assert_eq!(app.list_state.selected(), Some(0));
+}
+
+#[test]
+fn no_wrap_stops_the_cursor_at_both_ends() {
+    let mut app = App::new(Options {
+        no_wrap: true,
+        ..Default::default()
+    });
+    app.screen = Screen::List;
+    app.entries = (0..3)
+        .map(|i| NodeModuleEntry::new(format!("/{i}/node_modules"), 10, None, None, false))
+        .collect();
+    assert!(!app.wrap_navigation);
+
+    app.list_state.select(Some(0));
+    app.navigate_up();
+    assert_eq!(app.list_state.selected(), Some(0));
+    app.navigate_down();
+    app.navigate_down();
+    app.navigate_down();
+    assert_eq!(app.list_state.selected(), Some(2));
+    app.navigate_up();
+    assert_eq!(app.list_state.selected(), Some(1));
+}
+
+#[test]
+fn v_marks_a_range_and_toggles_it_as_one_block() {
+    let mut app = app_on(Screen::List);
+    app.entries = (0..5)
+        .map(|i| {
+            let sensitive = (i == 2).then_some(SensitiveReason::DotDirectory);
+            NodeModuleEntry::new(format!("/{i}/node_modules"), 10, sensitive, None, false)
+        })
+        .collect();
+    app.list_state.select(Some(3));
+
+    app.mark_range();
+    assert_eq!(app.anchor, Some(3));
+    app.navigate_up();
+    app.navigate_up();
+    app.navigate_up();
+    let marked: Vec<bool> = (0..5).map(|i| app.in_marked_range(i)).collect();
+    assert_eq!(marked, vec![true, true, true, true, false]);
+    // Space finishes the range too. Row 2 is sensitive and unselected, so
+    // the whole block gets selected, sensitive row included.
+    app.toggle_selected();
+    assert_eq!(app.anchor, None);
+    assert!(app.entries[..4].iter().all(|e| e.selected));
+
+    // Backwards from an anchor works the same; now everything is selected,
+    // so the block is deselected.
+    app.mark_range();
+    app.navigate_down();
+    app.mark_range();
+    let selected: Vec<bool> = app.entries.iter().map(|e| e.selected).collect();
+    assert_eq!(selected, vec![false, false, true, true, true]);
+}
+
+#[test]
+fn grouping_gathers_projects_under_headers_that_fold_and_toggle() {
+    let mut app = app_on(Screen::List);
+    app.entries = [
+        ("/r/a/node_modules", 50),
+        ("/s/node_modules", 40),
+        ("/r/b/node_modules", 30),
+    ]
+    .into_iter()
+    .map(|(path, size)| NodeModuleEntry::new(path.to_string(), size, None, None, false))
+    .collect();
+    for (path, project) in [
+        ("/r/a/node_modules", "/r"),
+        ("/r/b/node_modules", "/r"),
+        ("/s/node_modules", "/s"),
+    ] {
+        app.projects.insert(path.to_string(), project.to_string());
+    }
+    app.list_state.select(Some(0));
+
+    app.toggle_grouping();
+    let paths: Vec<&str> = app.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        ["/r/a/node_modules", "/r/b/node_modules", "/s/node_modules"]
+    );
+    let rows = app.list_rows();
+    assert_eq!(rows.len(), 5);
+    assert!(matches!(&rows[0], ListRow::Group(g) if g.dir == "/r" && g.entries == [0, 1]));
+
+    // Up from the first entry lands on its project's header, where Space
+    // toggles the whole project.
+    app.navigate_up();
+    assert_eq!(app.highlighted_group(), Some("/r"));
+    app.toggle_selected();
+    let selected: Vec<bool> = app.entries.iter().map(|e| e.selected).collect();
+    assert_eq!(selected, [false, false, true]);
+
+    // Folding hides the entries but keeps the header highlighted.
+    app.collapse_group();
+    assert_eq!(app.list_rows().len(), 3);
+    assert_eq!(app.visible_indices(), [2]);
+    app.navigate_down();
+    assert_eq!(app.highlighted_group(), Some("/s"));
+    app.navigate_up();
+    app.expand_group();
+    assert_eq!(app.list_rows().len(), 5);
+
+    // Ungrouping from a header moves the cursor to its first entry.
+    app.toggle_grouping();
+    assert_eq!(app.list_state.selected(), Some(0));
+    assert_eq!(app.list_rows().len(), 3);
+}
+
+#[test]
+fn esc_drops_a_marked_range_before_leaving_the_list() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![NodeModuleEntry::new(
+        "/a/node_modules".into(),
+        1,
+        None,
+        None,
+        false,
+    )];
+    app.list_state.select(Some(0));
+    app.mark_range();
+
+    assert!(!app.go_back());
+    assert_eq!(app.anchor, None);
+    assert!(matches!(app.screen, Screen::List));
+}
+
+#[test]
+fn profiles_save_the_selection_and_restore_it_by_path() {
+    let config = tempfile::tempdir().unwrap();
+    let entries = || {
+        vec![
+            NodeModuleEntry::new("/a/node_modules".into(), 3, None, None, false),
+            NodeModuleEntry::new("/b/node_modules".into(), 2, None, None, false),
+            NodeModuleEntry::new(
+                "/c/node_modules".into(),
+                1,
+                Some(SensitiveReason::DotDirectory),
+                None,
+                false,
+            ),
+        ]
+    };
+    let mut app = app_on(Screen::List);
+    app.options.profile = Some("work".to_string());
+    app.config_dir = Some(config.path().to_path_buf());
+
+    // Nothing saved yet: the default selection stays.
+    app.entries = entries();
+    app.apply_profile();
+    assert!(app.entries[0].selected && app.entries[1].selected);
+
+    app.entries[1].selected = false;
+    app.entries[2].selected = true;
+    app.save_profile();
+
+    // A fresh scan, plus a saved path that no longer exists.
+    let mut saved = killnode::config::load_profile(config.path(), "work").unwrap();
+    saved.push("/gone/node_modules".to_string());
+    killnode::config::save_profile(config.path(), "work", &saved).unwrap();
+    app.entries = entries();
+    app.apply_profile();
+
+    let selected: Vec<bool> = app.entries.iter().map(|e| e.selected).collect();
+    assert_eq!(selected, vec![true, false, true]);
+    assert_eq!(
+        app.profile_note.as_deref(),
+        Some("profile work: 1 saved not found")
+    );
+}
+
+#[test]
+fn sorted_position_breaks_ties_by_path() {
+    let entries: Vec<NodeModuleEntry> = [("/e", 5), ("/b", 3), ("/d", 3), ("/a", 1)]
+        .iter()
+        .map(|&(path, size)| NodeModuleEntry::new(path.into(), size, None, None, false))
+        .collect();
+    let new = |path: &str, size| NodeModuleEntry::new(path.into(), size, None, None, false);
+    assert_eq!(
+        sorted_position(&entries, &new("/z", 9), SortMode::Size, 0),
+        0
+    );
+    assert_eq!(
+        sorted_position(&entries, &new("/a", 3), SortMode::Size, 0),
+        1
+    );
+    assert_eq!(
+        sorted_position(&entries, &new("/c", 3), SortMode::Size, 0),
+        2
+    );
+    assert_eq!(
+        sorted_position(&entries, &new("/z", 3), SortMode::Size, 0),
+        3
+    );
+    assert_eq!(
+        sorted_position(&entries, &new("/z", 0), SortMode::Size, 0),
+        4
+    );
+}
+
+#[test]
+fn grouped_insertion_matches_sorting_and_grouping_at_once() {
+    // Two projects and a loose entry; /r/b outgrows everything in /s, so
+    // finding it late has to carry /r up past /s.
+    let found = [
+        ("/s/a/node_modules", 5),
+        ("/r/a/node_modules", 2),
+        ("/t/node_modules", 4),
+        ("/s/b/node_modules", 1),
+        ("/r/b/node_modules", 9),
+    ];
+    let projects = found
+        .iter()
+        .filter(|(path, _)| !path.starts_with("/t"))
+        .map(|(path, _)| (path.to_string(), path[..2].to_string()))
+        .collect();
+    let entry =
+        |&(path, size): &(&str, u64)| NodeModuleEntry::new(path.into(), size, None, None, false);
+
+    let mut at_once: Vec<NodeModuleEntry> = found.iter().map(entry).collect();
+    sort_entries_by(&mut at_once, SortMode::Size, 0);
+    group_entries(&mut at_once, &projects);
+    let expected: Vec<&str> = at_once.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(expected[..2], ["/r/b/node_modules", "/r/a/node_modules"]);
+
+    let mut placed = Vec::new();
+    for found in &found {
+        insert_sorted(
+            &mut placed,
+            entry(found),
+            Some(&projects),
+            SortMode::Size,
+            0,
+        );
+    }
+    let order: Vec<&str> = placed.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(order, expected);
+}
+
+#[test]
+fn entries_found_in_any_order_end_up_in_the_same_order() {
+    let found = [("/c", 2), ("/a", 2), ("/b", 7), ("/d", 2)];
+    let mut orders = Vec::new();
+    for rotation in 0..found.len() {
+        let (tx, rx) = mpsc::channel();
+        let mut app = app_on(Screen::Scanning);
+        app.scan = Some(ActiveScan {
+            rx,
+            current_path: Arc::new(Mutex::new(String::new())),
+            done: false,
+            stop: Default::default(),
+        });
+        let mut arrival = found.to_vec();
+        arrival.rotate_left(rotation);
+        for (path, size) in arrival {
+            tx.send(ScanMsg::Found {
+                path: path.to_string(),
+                size,
+                sensitive: None,
+                last_modified: None,
+                symlink: false,
+            })
+            .unwrap();
+        }
+        app.process_scan_messages();
+        let order: Vec<String> = app.entries.iter().map(|e| e.path.clone()).collect();
+        orders.push(order);
+    }
+    assert!(orders.iter().all(|o| *o == ["/b", "/a", "/c", "/d"]));
+}
+
+#[test]
+fn a_refreshed_size_moves_its_entry_and_the_cursor_follows() {
+    let mut app = app_on(Screen::List);
+    app.entries = [30, 20, 10]
+        .iter()
+        .map(|&size| NodeModuleEntry::new(format!("/{size}"), size, None, None, false))
+        .collect();
+    app.list_state.select(Some(2));
+    app.anchor = Some(0);
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    app.refreshing.push(("/10".to_string(), rx));
+    tx.send(25).unwrap();
+    app.process_refresh_messages();
+
+    let order: Vec<&str> = app.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(order, vec!["/30", "/10", "/20"]);
+    assert_eq!(app.entries[1].size, 25);
+    assert_eq!(app.list_state.selected(), Some(1));
+    assert_eq!(app.anchor, Some(0));
+}
+
+#[test]
+fn enter_with_nothing_selected_explains_itself_then_the_note_expires() {
+    let mut app = app_on(Screen::List);
+
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::List));
+    assert!(app.status.as_deref().unwrap().contains("Space"));
+
+    for _ in 0..killnode::app::STATUS_TICKS {
+        app.tick();
+    }
+    assert_eq!(app.status, None);
+}
+
+#[test]
+fn enter_with_nothing_selected_shows_the_detail_instead() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![NodeModuleEntry::new(
+        "/a/node_modules".into(),
+        1,
+        Some(SensitiveReason::DotDirectory),
+        None,
+        false,
+    )];
+    app.list_state.select(Some(0));
+
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::Detail));
+    assert_eq!(app.detail_entry().unwrap().path, "/a/node_modules");
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::List));
+
+    app.toggle_all_force();
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::Confirming));
+}
+
+#[test]
+fn yes_skips_the_confirmation_unless_something_sensitive_is_selected() {
+    let mut app = app_on(Screen::List);
+    app.options.yes = true;
+    app.entries = vec![NodeModuleEntry::new(
+        "/a/node_modules".into(),
+        1,
+        None,
+        None,
+        false,
+    )];
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::Deleting));
+
+    let mut app = app_on(Screen::List);
+    app.options.yes = true;
+    app.entries = vec![NodeModuleEntry::new(
+        "/a/node_modules".into(),
+        1,
+        Some(SensitiveReason::DotDirectory),
+        None,
+        false,
+    )];
+    app.entries[0].selected = true;
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::Confirming));
+}
+
+#[test]
+fn sensitive_review_asks_again_and_can_skip_the_risky_entries() {
+    let entries = || {
+        let mut entries = vec![
+            NodeModuleEntry::new("/a/node_modules".into(), 1, None, None, false),
+            NodeModuleEntry::new(
+                "/Applications/X.app/node_modules".into(),
+                1,
+                Some(SensitiveReason::MacAppBundle),
+                None,
+                false,
+            ),
+        ];
+        entries[1].selected = true;
+        entries
+    };
+
+    // Without the flag, confirming deletes after the countdown.
+    let mut app = app_on(Screen::Confirming);
+    app.entries = entries();
+    app.confirm_delete();
+    assert!(matches!(app.screen, Screen::Countdown));
+
+    let mut app = app_on(Screen::Confirming);
+    app.options.sensitive_review = true;
+    app.entries = entries();
+    app.confirm_delete();
+    assert!(matches!(app.screen, Screen::SensitiveReview));
+    assert_eq!(
+        app.sensitive_review(),
+        vec![(
+            "/Applications/X.app/node_modules",
+            "inside a macOS application bundle"
+        )]
+    );
+
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::List));
+
+    app.confirm_delete();
+    app.skip_sensitive();
+    assert!(matches!(app.screen, Screen::Deleting));
+    assert!(!app.entries[1].selected);
+    assert_eq!(app.delete_total, 1);
+}
+
+#[test]
+fn deleting_anything_sensitive_counts_down_first() {
+    use std::time::{Duration, Instant};
+    let entries = || {
+        let mut entries = vec![
+            NodeModuleEntry::new("/a/node_modules".into(), 1, None, None, false),
+            NodeModuleEntry::new(
+                "/Applications/X.app/node_modules".into(),
+                1,
+                Some(SensitiveReason::MacAppBundle),
+                None,
+                false,
+            ),
+        ];
+        entries[0].selected = true;
+        entries
+    };
+
+    // Nothing sensitive: no countdown.
+    let mut app = app_on(Screen::Confirming);
+    app.entries = entries();
+    app.confirm_delete();
+    assert!(matches!(app.screen, Screen::Deleting));
+
+    let mut app = app_on(Screen::Confirming);
+    app.entries = entries();
+    app.entries[1].selected = true;
+    app.confirm_delete();
+    assert!(matches!(app.screen, Screen::Countdown));
+    let remaining = app.countdown_remaining().unwrap();
+    assert!(remaining > Duration::ZERO && remaining <= killnode::app::SENSITIVE_COUNTDOWN);
+    // Still counting: nothing starts.
+    app.process_countdown();
+    assert!(matches!(app.screen, Screen::Countdown));
+
+    // Esc backs out with nothing deleted.
+    assert!(!app.go_back());
+    assert!(matches!(app.screen, Screen::List));
+    assert_eq!(app.countdown_remaining(), None);
+    assert_eq!(app.delete_total, 0);
+
+    // Once it runs out, the deletion starts.
+    app.confirm_delete();
+    app.countdown_until = Some(Instant::now());
+    app.process_countdown();
+    assert!(matches!(app.screen, Screen::Deleting));
+    assert_eq!(app.delete_total, 2);
+}
+
+#[test]
+fn a_long_scan_is_flagged_as_slow() {
+    let mut app = app_on(Screen::Welcome);
+    app.ticker = 500;
+    app.begin_scan();
+    assert!(!app.scan_is_slow());
+    for _ in 0..killnode::app::SLOW_SCAN_TICKS {
+        app.tick();
+    }
+    assert!(app.scan_is_slow());
+}
+
+#[test]
+fn a_burst_of_scan_results_is_spread_over_several_ticks() {
+    let (tx, rx) = mpsc::channel();
+    let mut app = app_on(Screen::Scanning);
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Default::default(),
+    });
+    for i in 0..MESSAGES_PER_TICK + 10 {
+        tx.send(ScanMsg::Found {
+            path: format!("/p/{i}/node_modules"),
+            size: 1,
+            sensitive: None,
+            last_modified: None,
+            symlink: false,
+        })
+        .unwrap();
+    }
+    tx.send(ScanMsg::Done { truncated: false }).unwrap();
+
+    app.process_scan_messages();
+    assert_eq!(app.entries.len(), MESSAGES_PER_TICK);
+    assert!(matches!(app.screen, Screen::Scanning));
+
+    app.process_scan_messages();
+    assert_eq!(app.entries.len(), MESSAGES_PER_TICK + 10);
+    assert!(matches!(app.screen, Screen::List));
+}
+
+#[test]
+fn the_scan_is_timed_from_start_to_done_and_a_rescan_starts_afresh() {
+    use std::time::{Duration, Instant};
+    let (tx, rx) = mpsc::channel();
+    let mut app = app_on(Screen::Scanning);
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Default::default(),
+    });
+    app.scan_started_at = Some(Instant::now() - Duration::from_secs(2));
+    for i in 0..4 {
+        tx.send(ScanMsg::Found {
+            path: format!("/p/{i}/node_modules"),
+            size: 1,
+            sensitive: None,
+            last_modified: None,
+            symlink: false,
+        })
+        .unwrap();
+    }
+    assert_eq!(app.scan_duration(), None);
+    tx.send(ScanMsg::Done { truncated: false }).unwrap();
+    app.process_scan_messages();
+
+    let took = app.scan_duration().unwrap();
+    assert!(took >= Duration::from_secs(2) && took < Duration::from_secs(60));
+    assert_eq!(app.scan_found, 4);
+    assert!(
+        app.scan_rate()
+            .is_some_and(|rate| rate > 0.0 && rate <= 2.0)
+    );
+
+    // Deleting afterwards doesn't change what the scan found.
+    app.entries.clear();
+    assert_eq!(app.scan_found, 4);
+
+    // A rescan forgets the old time until it finishes in turn.
+    app.begin_scan();
+    assert_eq!(app.scan_duration(), None);
+    assert_eq!(app.scan_rate(), None);
+}
+
+#[test]
+fn o_opens_the_project_and_a_failed_launch_only_sets_the_status() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![NodeModuleEntry::new(
+        "/work/app/node_modules".into(),
+        1,
+        None,
+        None,
+        false,
+    )];
+    assert_eq!(app.highlighted_parent(), None);
+    app.list_state.select(Some(0));
+    assert_eq!(app.highlighted_parent().as_deref(), Some("/work/app"));
+
+    let (tx, rx) = mpsc::channel();
+    app.open_rx = Some(rx);
+    app.process_open_messages();
+    assert!(app.open_rx.is_some());
+
+    tx.send(("/work/app".to_string(), Err("no launcher".to_string())))
+        .unwrap();
+    app.process_open_messages();
+    assert!(app.open_rx.is_none());
+    assert_eq!(
+        app.status.as_deref(),
+        Some("Couldn't open /work/app: no launcher")
+    );
+    assert!(matches!(app.screen, Screen::List));
+}
+
+#[test]
+fn y_copies_the_highlighted_path_or_error_and_always_says_how_it_went() {
+    let mut app = app_on(Screen::List);
+    app.entries = vec![NodeModuleEntry::new(
+        "/work/app/node_modules".into(),
+        1,
+        None,
+        None,
+        false,
+    )];
+    assert_eq!(app.copy_target(), None);
+    app.list_state.select(Some(0));
+    assert_eq!(app.copy_target().as_deref(), Some("/work/app/node_modules"));
+
+    // Whether or not this machine has a clipboard, copying never panics and
+    // always leaves word in the status line.
+    app.copy_highlighted();
+    assert!(app.status.is_some());
+
+    app.screen = Screen::Done;
+    assert_eq!(app.copy_target(), None);
+    app.delete_errors = vec![
+        ("/a".to_string(), "/a: denied".to_string()),
+        ("/b".to_string(), "/b: busy".to_string()),
+    ];
+    assert_eq!(app.copy_target().as_deref(), Some("/a: denied"));
+    app.error_list_state.select(Some(1));
+    assert_eq!(app.copy_target().as_deref(), Some("/b: busy"));
+
+    // The highlight counts within the filtered errors.
+    app.error_filter = "busy".to_string();
+    app.error_list_state.select(Some(0));
+    assert_eq!(app.copy_target().as_deref(), Some("/b: busy"));
+}
+
+#[test]
+fn e_hides_every_project_in_the_highlighted_entrys_folder() {
+    let mut app = App::new(Options {
+        roots: vec!["/work".to_string()],
+        ..Default::default()
+    });
+    app.screen = Screen::List;
+    app.entries = [
+        "/work/keep/node_modules",
+        "/work/legacy/a/node_modules",
+        "/work/legacy/b/node_modules",
+        "/work/zzz/node_modules",
+    ]
+    .iter()
+    .map(|p| NodeModuleEntry::new(p.to_string(), 1, None, None, false))
+    .collect();
+    app.list_state.select(Some(1));
+
+    app.exclude_parent_of_selected();
+    let left: Vec<&str> = app.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(left, ["/work/keep/node_modules", "/work/zzz/node_modules"]);
+    assert_eq!(app.excluded, 2);
+    assert_eq!(app.list_state.selected(), Some(1));
+
+    // Directly under the root, only the project itself goes.
+    app.exclude_parent_of_selected();
+    assert_eq!(app.entries.len(), 1);
+    assert_eq!(app.excluded, 3);
+
+    // The last entry is never hidden.
+    app.exclude_parent_of_selected();
+    assert_eq!(app.entries.len(), 1);
+    assert!(app.status.is_some());
+}
+
+#[test]
+fn e_leaves_the_cursor_on_a_row_that_is_shown() {
+    let mut app = App::new(Options {
+        roots: vec!["/work".to_string()],
+        ..Default::default()
+    });
+    app.screen = Screen::List;
+    app.entries = [
+        "/work/legacy/a/node_modules",
+        "/work/legacy/b/node_modules",
+        "/work/other/node_modules",
+        "/work/was-legacy/node_modules",
+    ]
+    .iter()
+    .map(|p| NodeModuleEntry::new(p.to_string(), 1, None, None, false))
+    .collect();
+    app.list_filter = "legacy".to_string();
+    app.list_state.select(Some(0));
+
+    // The row below the hidden ones is filtered out, so the cursor skips it.
+    app.exclude_parent_of_selected();
+    let highlighted = app
+        .list_state
+        .selected()
+        .map(|i| app.entries[i].path.as_str());
+    assert_eq!(highlighted, Some("/work/was-legacy/node_modules"));
+}
+
+#[test]
+fn cumulative_size_adds_up_everything_down_to_the_cursor() {
+    let mut app = app_on(Screen::List);
+    app.entries = [5, 3, 1]
+        .iter()
+        .map(|&size| NodeModuleEntry::new(format!("/{size}/node_modules"), size, None, None, false))
+        .collect();
+    assert_eq!(app.cumulative_size(), None);
+
+    app.list_state.select(Some(1));
+    assert_eq!(app.cumulative_size(), Some((2, 8)));
+
+    app.toggle_cumulative();
+    assert!(app.show_cumulative);
+    app.options.no_size = true;
+    app.toggle_cumulative();
+    assert!(app.show_cumulative, "--no-size leaves the toggle alone");
+}
+
+#[test]
+fn trashed_paths_are_remembered_until_the_trash_is_emptied() {
+    let mut app = App::new(Options {
+        delete_mode: DeleteMode::Trash,
+        ..Default::default()
+    });
+    app.screen = Screen::Deleting;
+    app.entries = vec![NodeModuleEntry::new(
+        "/p/a/node_modules".to_string(),
+        10,
+        None,
+        None,
+        false,
+    )];
+    let (tx, rx) = mpsc::channel();
+    tx.send(DeleteMsg::Done {
+        freed: 10,
+        deleted: vec!["/p/a/node_modules".to_string()],
+        skipped: Vec::new(),
+        errors: Vec::new(),
+        timings: Vec::new(),
+    })
+    .unwrap();
+    app.delete_rx = Some(rx);
+    app.process_delete_messages();
+    assert_eq!(app.trashed, vec!["/p/a/node_modules"]);
+    assert_eq!((app.delete_removed, app.delete_trashed), (1, 1));
+
+    // A failed purge keeps them, with the reason…
+    let (tx, rx) = mpsc::channel();
+    tx.send(Err("no trash here".to_string())).unwrap();
+    app.purge_rx = Some(rx);
+    app.process_purge_messages();
+    assert_eq!(app.purge_error.as_deref(), Some("no trash here"));
+    assert_eq!(app.trashed.len(), 1);
+
+    // …and a successful one forgets them.
+    let (tx, rx) = mpsc::channel();
+    tx.send(Ok(1)).unwrap();
+    app.purge_rx = Some(rx);
+    app.process_purge_messages();
+    assert!(app.trashed.is_empty() && app.purge_rx.is_none());
+}
+
+#[test]
+fn restoring_from_the_trash_takes_the_directories_off_the_totals() {
+    let mut app = App::new(Options {
+        delete_mode: DeleteMode::Trash,
+        ..Default::default()
+    });
+    app.screen = Screen::Deleting;
+    let (tx, rx) = mpsc::channel();
+    tx.send(DeleteMsg::Done {
+        freed: 30,
+        deleted: vec![
+            "/p/a/node_modules".to_string(),
+            "/p/b/node_modules".to_string(),
+        ],
+        skipped: Vec::new(),
+        errors: Vec::new(),
+        timings: Vec::new(),
+    })
+    .unwrap();
+    app.delete_rx = Some(rx);
+    app.process_delete_messages();
+    assert_eq!((app.delete_freed, app.trashed_size), (30, 30));
+
+    let (tx, rx) = mpsc::channel();
+    tx.send(Ok(2)).unwrap();
+    app.restore_rx = Some(rx);
+    assert!(!app.can_empty_trash(), "one trash job at a time");
+    app.process_restore_messages();
+    assert!(app.trashed.is_empty() && app.restore_rx.is_none());
+    assert_eq!((app.delete_removed, app.delete_freed), (0, 0));
+    assert_eq!(app.delete_restored, 2);
+    assert!(app.has_delete_history());
+}
+
+#[test]
+fn the_confirm_popup_switches_between_removing_and_trashing() {
+    let mut app = app_on(Screen::Confirming);
+    assert_eq!(app.options.delete_mode, DeleteMode::Remove);
+    app.toggle_delete_mode();
+    assert_eq!(app.options.delete_mode, DeleteMode::Trash);
+    app.toggle_delete_mode();
+    assert_eq!(app.options.delete_mode, DeleteMode::Remove);
+}
+
+#[test]
+fn kept_entries_stay_unselected_on_the_next_scan() {
+    let config = tempfile::tempdir().unwrap();
+    let mut app = app_on(Screen::List);
+    app.config_dir = Some(config.path().to_path_buf());
+    app.entries = vec![
+        NodeModuleEntry::new("/p/demo/node_modules".into(), 2, None, None, false),
+        NodeModuleEntry::new("/p/old/node_modules".into(), 1, None, None, false),
+    ];
+    app.list_state.select(Some(0));
+    app.toggle_keep();
+    assert!(app.entries[0].is_kept() && !app.entries[0].selected);
+    assert_eq!(app.entries[0].sensitive, Some(SensitiveReason::Kept));
+
+    // `a` leaves kept entries alone.
+    app.toggle_all();
+    app.toggle_all();
+    assert!(!app.entries[0].selected && app.entries[1].selected);
+
+    // A later scan reads the saved list back, and the scanner reports what
+    // is on it as kept. Even `--sensitive include` doesn't select it.
+    let mut app = App::new(Options {
+        sensitive: SensitivePolicy::Include,
+        ..Default::default()
+    });
+    app.screen = Screen::Scanning;
+    app.config_dir = Some(config.path().to_path_buf());
+    app.keep = killnode::config::load_keep(config.path()).unwrap();
+    assert_eq!(app.keep, ["/p/demo/node_modules"]);
+    let (tx, rx) = mpsc::channel();
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Default::default(),
+    });
+    for (path, sensitive) in [
+        ("/p/demo/node_modules", Some(SensitiveReason::Kept)),
+        ("/p/old/node_modules", None),
+    ] {
+        tx.send(ScanMsg::Found {
+            path: path.to_string(),
+            size: 1,
+            sensitive,
+            last_modified: None,
+            symlink: false,
+        })
+        .unwrap();
+    }
+    app.process_scan_messages();
+    let kept: Vec<(bool, bool)> = app
+        .entries
+        .iter()
+        .map(|e| (e.is_kept(), e.selected))
+        .collect();
+    assert_eq!(kept, vec![(true, false), (false, true)]);
+
+    // Pressing `p` again takes it off the list, and it goes back to what
+    // its location makes it.
+    app.list_state.select(Some(0));
+    app.toggle_keep();
+    assert_eq!(app.entries[0].sensitive, None);
+    assert!(
+        killnode::config::load_keep(config.path())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn kept_entries_are_never_bulk_selected_or_deleted_unasked() {
+    let mut app = App::new(Options {
+        yes: true,
+        ..Default::default()
+    });
+    app.screen = Screen::List;
+    app.entries = vec![
+        NodeModuleEntry::new("/p/demo/node_modules".into(), 2, None, None, false),
+        NodeModuleEntry::new("/p/old/node_modules".into(), 1, None, None, false),
+    ];
+    app.entries[0].sensitive = Some(SensitiveReason::Kept);
+    app.entries[0].selected = false;
+
+    // Neither `A` nor a range reaches the kept entry.
+    app.toggle_all_force();
+    app.toggle_all_force();
+    assert!(!app.entries[0].selected && app.entries[1].selected);
+    app.toggle_range(0, 1);
+    app.toggle_range(0, 1);
+    assert!(!app.entries[0].selected);
+
+    // Selected by hand, it still gets the popup despite `--yes`.
+    app.entries[0].selected = true;
+    app.request_delete();
+    assert!(matches!(app.screen, Screen::Confirming));
+}
+
+#[test]
+fn entries_under_min_size_are_left_out_and_counted() {
+    let mut app = App::new(Options {
+        min_size: Some(1_000),
+        ..Default::default()
+    });
+    app.screen = Screen::Scanning;
+    let (tx, rx) = mpsc::channel();
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Default::default(),
+    });
+    for (path, size) in [("/p/a/node_modules", 999), ("/p/b/node_modules", 1_000)] {
+        tx.send(ScanMsg::Found {
+            path: path.to_string(),
+            size,
+            sensitive: None,
+            last_modified: None,
+            symlink: false,
+        })
+        .unwrap();
+    }
+    tx.send(ScanMsg::Done { truncated: false }).unwrap();
+    app.process_scan_messages();
+
+    let paths: Vec<&str> = app.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, vec!["/p/b/node_modules"]);
+    assert_eq!(app.hidden_small, 1);
+}
+
+#[test]
+fn entries_newer_than_older_than_are_left_out_and_counted() {
+    let mut app = App::new(Options {
+        older_than: Some(86_400),
+        ..Default::default()
+    });
+    app.screen = Screen::Scanning;
+    let (tx, rx) = mpsc::channel();
+    app.scan = Some(ActiveScan {
+        rx,
+        current_path: Arc::new(Mutex::new(String::new())),
+        done: false,
+        stop: Default::default(),
+    });
+    for (path, last_modified) in [
+        ("/p/old/node_modules", Some(0)),
+        ("/p/new/node_modules", Some(u64::MAX)),
+        ("/p/undated/node_modules", None),
+    ] {
+        tx.send(ScanMsg::Found {
+            path: path.to_string(),
+            size: 1,
+            sensitive: None,
+            last_modified,
+            symlink: false,
+        })
+        .unwrap();
+    }
+    tx.send(ScanMsg::Done { truncated: false }).unwrap();
+    app.process_scan_messages();
+
+    let paths: Vec<&str> = app.entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, vec!["/p/old/node_modules"]);
+    assert_eq!(app.hidden_recent, 2);
+}
+
+#[test]
+fn paging_moves_by_the_drawn_height_without_wrapping() {
+    let mut app = app_on(Screen::List);
+    app.entries = (0..25)
+        .map(|i| NodeModuleEntry::new(format!("/p/{i}/node_modules"), 1, None, None, false))
+        .collect();
+    app.list_state.select(Some(0));
+    app.list_page = 10;
+
+    app.page_down();
+    assert_eq!(app.list_state.selected(), Some(10));
+    app.page_down();
+    app.page_down();
+    assert_eq!(app.list_state.selected(), Some(24));
+    app.page_up();
+    assert_eq!(app.list_state.selected(), Some(14));
+    app.jump_to_start();
+    assert_eq!(app.list_state.selected(), Some(0));
+    app.page_up();
+    assert_eq!(app.list_state.selected(), Some(0));
+    app.jump_to_end();
+    assert_eq!(app.list_state.selected(), Some(24));
+}
+
+#[test]
+fn the_list_filter_narrows_navigation_and_bulk_selection() {
+    let mut app = app_on(Screen::List);
+    app.entries = [
+        "/w/App/node_modules",
+        "/w/lib/node_modules",
+        "/w/app2/node_modules",
+    ]
+    .map(|p| NodeModuleEntry::new(p.to_string(), 1, None, None, false))
+    .into();
+    app.list_state.select(Some(1));
+
+    app.begin_list_filter();
+    for c in "APP".chars() {
+        app.push_list_filter(c);
+    }
+    app.end_list_filter();
+    assert_eq!(app.visible_indices(), vec![0, 2]);
+    // The cursor was on a hidden row, so it moved to the first shown one.
+    assert_eq!(app.list_state.selected(), Some(0));
+    app.navigate_down();
+    assert_eq!(app.list_state.selected(), Some(2));
+    app.navigate_down();
+    assert_eq!(app.list_state.selected(), Some(0));
+
+    // `a` deselects only what is shown, but the totals cover everything.
+    app.toggle_all();
+    assert_eq!(app.selected_count(), 1);
+    assert!(app.entries[1].selected);
+
+    // Esc clears the filter before it leaves the list.
+    assert!(!app.go_back());
+    assert!(app.list_filter.is_empty());
+    assert!(matches!(app.screen, Screen::List));
+    assert_eq!(app.visible_indices().len(), 3);
+}
@@ -0,0 +1,420 @@
+//! Checks for command-line parsing.
+
+use killnode::{
+    app::NodeModuleEntry,
+    cli::{Command, Options, SensitivePolicy, ThemeName, parse},
+    scanner::SensitiveReason,
+};
+
+fn run_options(args: &[&str]) -> Options {
+    match parse(args.iter().map(|a| a.to_string())) {
+        Ok(Command::Run(options)) => *options,
+        Ok(_) => panic!("{args:?} did not parse as a run"),
+        Err(e) => panic!("{args:?} failed to parse: {e}"),
+    }
+}
+
+#[test]
+fn no_arguments_means_default_options() {
+    let options = run_options(&[]);
+    assert!(options.roots.is_empty());
+    assert!(!options.exclude_dirty_git);
+    assert!(!options.no_size);
+    assert_eq!(options.sensitive, SensitivePolicy::Auto);
+    assert_eq!(options.max_results, None);
+    assert_eq!(options.theme, ThemeName::Default);
+    assert_eq!(options.root_marker, None);
+}
+
+#[test]
+fn positional_arguments_are_the_roots() {
+    assert_eq!(run_options(&["~/projects"]).roots, vec!["~/projects"]);
+    assert_eq!(run_options(&["--", "-weird"]).roots, vec!["-weird"]);
+    assert_eq!(
+        run_options(&["/mnt/a", "--no-size", "/mnt/b"]).roots,
+        vec!["/mnt/a", "/mnt/b"]
+    );
+}
+
+#[test]
+fn scan_roots_are_absolute_and_distinct() {
+    let roots = run_options(&["/mnt/a", "/mnt/b", "/mnt/a"]).scan_roots();
+    assert_eq!(roots, vec!["/mnt/a", "/mnt/b"]);
+    assert_eq!(run_options(&[]).scan_roots().len(), 1);
+}
+
+#[test]
+fn two_spellings_of_one_root_are_one_root() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("q")).unwrap();
+    let plain = dir.path().to_string_lossy().to_string();
+    let dotted = dir.path().join("q/..").to_string_lossy().to_string();
+    let roots = run_options(&[&plain, &dotted]).scan_roots();
+    assert_eq!(roots.len(), 1);
+    assert!(!roots[0].contains(".."));
+}
+
+#[test]
+fn flags_are_recognised() {
+    let options = run_options(&[
+        "--exclude-git-repos-with-uncommitted-changes",
+        "--no-size",
+        "--prune-empty-parents",
+        "--progress-json",
+        "--detach",
+        "-y",
+        "dir",
+    ]);
+    assert!(options.exclude_dirty_git);
+    assert!(options.no_size);
+    assert!(options.prune_empty_parents);
+    assert!(options.progress_json);
+    assert!(options.detach);
+    assert!(options.yes);
+    assert_eq!(options.roots, vec!["dir"]);
+}
+
+#[test]
+fn disk_usage_reaches_the_scan_and_the_delete() {
+    let options = run_options(&["--disk-usage"]);
+    assert!(options.scan_options().disk_usage);
+    assert!(options.delete_options(&[]).disk_usage);
+    assert!(!run_options(&[]).scan_options().disk_usage);
+}
+
+#[test]
+fn sensitive_policy_takes_a_value() {
+    assert_eq!(
+        run_options(&["--sensitive", "hide"]).sensitive,
+        SensitivePolicy::Hide
+    );
+    assert_eq!(
+        run_options(&["--sensitive=include", "dir"]).sensitive,
+        SensitivePolicy::Include
+    );
+    assert!(parse(["--sensitive"].map(String::from)).is_err());
+    assert!(parse(["--sensitive", "sometimes"].map(String::from)).is_err());
+    assert!(parse(["--no-size=1"].map(String::from)).is_err());
+}
+
+#[test]
+fn sensitive_policy_has_shorthands_and_the_last_one_wins() {
+    assert_eq!(
+        run_options(&["--exclude-sensitive"]).sensitive,
+        SensitivePolicy::Hide
+    );
+    assert_eq!(
+        run_options(&["--include-sensitive"]).sensitive,
+        SensitivePolicy::Include
+    );
+    assert_eq!(
+        run_options(&["--exclude-sensitive", "--sensitive", "auto"]).sensitive,
+        SensitivePolicy::Auto
+    );
+    assert_eq!(
+        run_options(&["--sensitive=hide", "--include-sensitive"]).sensitive,
+        SensitivePolicy::Include
+    );
+}
+
+#[test]
+fn max_results_must_be_positive() {
+    assert_eq!(run_options(&["--max-results", "50"]).max_results, Some(50));
+    assert!(parse(["--max-results", "0"].map(String::from)).is_err());
+    assert!(parse(["--max-results=lots"].map(String::from)).is_err());
+}
+
+#[test]
+fn the_list_filters_reach_the_scan_options_for_max_results() {
+    let scan = run_options(&[
+        "--max-results=5",
+        "--min-size=1KB",
+        "--older-than=1d",
+        "--sensitive=hide",
+    ])
+    .scan_options();
+    assert!(scan.hide_sensitive);
+    assert!(scan.min_size.is_some());
+    assert!(scan.older_than.is_some());
+    assert!(!run_options(&[]).scan_options().hide_sensitive);
+}
+
+#[test]
+fn max_depth_must_be_positive() {
+    assert_eq!(run_options(&["--max-depth", "3"]).max_depth, Some(3));
+    assert_eq!(run_options(&[]).max_depth, None);
+    assert!(parse(["--max-depth", "0"].map(String::from)).is_err());
+    assert!(parse(["--max-depth=-1"].map(String::from)).is_err());
+}
+
+#[test]
+fn jobs_must_be_positive() {
+    assert_eq!(run_options(&["--jobs", "8"]).jobs, Some(8));
+    assert_eq!(run_options(&[]).delete_options(&[]).jobs, 1);
+    assert!(parse(["--jobs", "0"].map(String::from)).is_err());
+    assert!(parse(["--jobs=many"].map(String::from)).is_err());
+}
+
+#[test]
+fn min_size_takes_decimal_units_in_any_case() {
+    assert_eq!(
+        run_options(&["--min-size", "50MB"]).min_size,
+        Some(50_000_000)
+    );
+    assert_eq!(
+        run_options(&["--min-size=1gb"]).min_size,
+        Some(1_000_000_000)
+    );
+    assert_eq!(run_options(&["--min-size", "512"]).min_size, Some(512));
+    assert!(parse(["--min-size", "big"].map(String::from)).is_err());
+    assert!(parse(["--min-size", "50XB"].map(String::from)).is_err());
+    assert!(parse(["--min-size", "1MB", "--no-size"].map(String::from)).is_err());
+}
+
+#[test]
+fn older_than_takes_an_age_and_drops_undated_entries_by_default() {
+    const DAY: u64 = 86_400;
+    let options = run_options(&["--older-than", "30d"]);
+    assert_eq!(options.older_than, Some(30 * DAY));
+    assert!(options.too_recent(Some(100 * DAY), 110 * DAY));
+    assert!(!options.too_recent(Some(70 * DAY), 110 * DAY));
+    assert!(options.too_recent(None, 110 * DAY));
+    assert!(!run_options(&["--older-than=6MO", "--include-undated"]).too_recent(None, 0));
+    assert!(!run_options(&[]).too_recent(Some(0), 0));
+
+    assert!(parse(["--older-than", "soon"].map(String::from)).is_err());
+    assert!(parse(["--include-undated"].map(String::from)).is_err());
+}
+
+#[test]
+fn headless_runs_list_what_the_filters_leave_in() {
+    const DAY: u64 = 86_400;
+    let now = 100 * DAY;
+    let entry = |size, sensitive, age| {
+        NodeModuleEntry::new("x".into(), size, sensitive, Some(now - age * DAY), false)
+    };
+    let options = run_options(&[
+        "--min-size",
+        "1KB",
+        "--older-than",
+        "30d",
+        "--exclude-sensitive",
+    ]);
+
+    assert!(options.lists(&entry(5_000, None, 60), now));
+    assert!(!options.lists(&entry(500, None, 60), now));
+    assert!(!options.lists(&entry(5_000, None, 10), now));
+    assert!(!options.lists(&entry(5_000, Some(SensitiveReason::DotDirectory), 60), now));
+    assert!(run_options(&[]).lists(&entry(0, Some(SensitiveReason::DotDirectory), 0), now));
+}
+
+#[test]
+fn json_takes_an_output_file() {
+    assert_eq!(
+        run_options(&["--json", "out.json", "dir"]).json.as_deref(),
+        Some("out.json")
+    );
+    assert_eq!(run_options(&["dir"]).json, None);
+    assert!(parse(["--json"].map(String::from)).is_err());
+}
+
+#[test]
+fn targets_are_a_list_of_names() {
+    assert_eq!(
+        run_options(&["--targets", "node_modules, target,.venv"]).targets,
+        vec!["node_modules", "target", ".venv"]
+    );
+    assert!(run_options(&[]).targets.is_empty());
+    assert!(parse(["--targets", "a/b"].map(String::from)).is_err());
+    assert!(parse(["--targets=node_modules,"].map(String::from)).is_err());
+}
+
+#[test]
+fn theme_is_chosen_by_name() {
+    assert_eq!(
+        run_options(&["--theme", "high-contrast"]).theme,
+        ThemeName::HighContrast
+    );
+    assert_eq!(run_options(&["--theme=mono"]).theme, ThemeName::Mono);
+    assert!(parse(["--theme", "neon"].map(String::from)).is_err());
+}
+
+#[test]
+fn sort_sets_the_starting_order() {
+    use killnode::app::SortMode;
+    assert_eq!(run_options(&[]).sort, SortMode::Size);
+    assert_eq!(run_options(&["--sort", "age"]).sort, SortMode::Oldest);
+    assert_eq!(run_options(&["--sort=age-asc"]).sort, SortMode::Newest);
+    assert_eq!(
+        run_options(&["--sort", "size-asc"]).sort,
+        SortMode::SizeAscending
+    );
+    assert_eq!(run_options(&["--sort", "name"]).sort, SortMode::Path);
+    let err = parse(["--sort", "name-desc"].map(String::from)).err();
+    assert!(err.is_some_and(|e| e.contains("size-asc")));
+    assert!(parse(["--sort", "biggest"].map(String::from)).is_err());
+}
+
+#[test]
+fn force_only_goes_with_apply() {
+    let options = run_options(&["--apply", "found.json", "--force"]);
+    assert_eq!(options.apply.as_deref(), Some("found.json"));
+    assert!(options.force);
+    assert!(!run_options(&["--apply=found.json"]).force);
+    assert!(parse(["--force"].map(String::from)).is_err());
+    assert!(parse(["--apply"].map(String::from)).is_err());
+}
+
+#[test]
+fn headless_modes_cannot_be_combined() {
+    let conflicts: [&[&str]; 5] = [
+        &["--json", "found.json", "--stats-only"],
+        &["--du-format", "--progress-json"],
+        &["--apply", "found.json", "--run-plan", "plan.txt"],
+        &["--stats-only", "--du-format"],
+        &["--json", "found.json", "--apply", "found.json"],
+    ];
+    for args in conflicts {
+        let Err(error) = parse(args.iter().map(|a| a.to_string())) else {
+            panic!("{args:?} should be rejected");
+        };
+        assert!(error.contains("can't be used with"), "{args:?}: {error}");
+    }
+}
+
+#[test]
+fn progress_json_streams_an_apply() {
+    let options = run_options(&["--apply", "found.json", "--progress-json"]);
+    assert_eq!(options.apply.as_deref(), Some("found.json"));
+    assert!(options.progress_json);
+}
+
+#[test]
+fn root_marker_is_a_plain_name() {
+    assert_eq!(
+        run_options(&["--root-marker", ".git"])
+            .root_marker
+            .as_deref(),
+        Some(".git")
+    );
+    assert!(parse(["--root-marker", "a/b"].map(String::from)).is_err());
+    assert!(parse(["--root-marker="].map(String::from)).is_err());
+}
+
+#[test]
+fn profile_is_a_plain_name() {
+    assert_eq!(
+        run_options(&["--profile=work"]).profile.as_deref(),
+        Some("work")
+    );
+    assert!(parse(["--profile", "../x"].map(String::from)).is_err());
+    assert!(parse(["--profile", ".."].map(String::from)).is_err());
+}
+
+#[test]
+fn preselect_takes_a_rule() {
+    assert!(run_options(&["--preselect", "age>90d"]).preselect.is_some());
+    assert!(run_options(&[]).preselect.is_none());
+    assert!(parse(["--preselect=size>lots"].map(String::from)).is_err());
+}
+
+#[test]
+fn help_and_version_stop_parsing() {
+    assert!(matches!(
+        parse(["dir", "--help"].map(String::from)),
+        Ok(Command::Help)
+    ));
+    assert!(matches!(
+        parse(["-V"].map(String::from)),
+        Ok(Command::Version)
+    ));
+}
+
+#[test]
+fn bad_arguments_are_rejected() {
+    assert!(parse(["--nope"].map(String::from)).is_err());
+    assert!(parse(["--nope", "--help"].map(String::from)).is_err());
+}
+
+#[test]
+fn one_file_system_reaches_the_scan_options() {
+    assert!(!run_options(&[]).scan_options().one_file_system);
+    assert!(
+        run_options(&["--one-file-system"])
+            .scan_options()
+            .one_file_system
+    );
+}
+
+#[test]
+fn wrapping_is_on_unless_no_wrap_is_given() {
+    assert!(!run_options(&[]).no_wrap);
+    assert!(run_options(&["--no-wrap"]).no_wrap);
+}
+
+#[test]
+fn sensitive_review_is_opt_in() {
+    assert!(!run_options(&[]).sensitive_review);
+    assert!(run_options(&["--interactive-sensitive-review"]).sensitive_review);
+}
+
+#[test]
+fn du_unit_defaults_to_kibibytes() {
+    use killnode::du::DuUnit;
+    let options = run_options(&["--du-format"]);
+    assert!(options.du_format);
+    assert_eq!(options.du_unit, DuUnit::Kibibytes);
+    assert_eq!(run_options(&["--du-unit=m"]).du_unit, DuUnit::Mebibytes);
+    assert!(parse(["--du-unit", "T"].map(String::from)).is_err());
+}
+
+#[test]
+fn color_follows_the_flag_then_no_color_then_the_terminal() {
+    use killnode::cli::{ColorChoice, color_enabled};
+    assert_eq!(run_options(&["--color=never"]).color, ColorChoice::Never);
+    assert_eq!(run_options(&["--no-color"]).color, ColorChoice::Never);
+    assert!(parse(["--color", "sometimes"].map(String::from)).is_err());
+
+    assert!(color_enabled(ColorChoice::Auto, false, true));
+    assert!(!color_enabled(ColorChoice::Auto, true, true));
+    assert!(!color_enabled(ColorChoice::Auto, false, false));
+    assert!(color_enabled(ColorChoice::Always, true, false));
+    assert!(!color_enabled(ColorChoice::Never, false, true));
+}
+
+#[test]
+fn exclude_is_repeatable() {
+    assert_eq!(
+        run_options(&["--exclude", "**/vendor/", "--exclude=/mnt/*"]).exclude,
+        vec!["**/vendor", "/mnt/*"]
+    );
+    assert!(run_options(&[]).exclude.is_empty());
+    assert!(parse(["--exclude"].map(String::from)).is_err());
+    // A pattern that isn't a glob is reported before anything runs.
+    let err = parse(["--exclude", "/mnt/[a"].map(String::from)).err();
+    assert!(err.is_some_and(|e| e.contains("'--exclude'") && e.contains("/mnt/[a")));
+}
+
+#[test]
+fn ignore_file_patterns_are_read_at_parse_time() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("ignore");
+    std::fs::write(&file, "# shared drives\n/mnt/share/*\n\n  vendor/  \n").unwrap();
+    let file = file.to_string_lossy().to_string();
+
+    assert_eq!(
+        run_options(&["--ignore-file", &file]).ignore,
+        vec!["/mnt/share/*", "vendor"]
+    );
+    // Repeating the flag adds to the list.
+    assert_eq!(
+        run_options(&["--ignore-file", &file, &format!("--ignore-file={file}")])
+            .ignore
+            .len(),
+        4
+    );
+
+    let missing = dir.path().join("missing").to_string_lossy().to_string();
+    let err = parse(["--ignore-file".to_string(), missing.clone()]).err();
+    assert!(err.is_some_and(|e| e.contains(&missing)));
+}
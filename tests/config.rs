@@ -0,0 +1,61 @@
+//! Checks for saved state: profile files, the keep list and pattern matching.
+
+use killnode::config::{
+    load_keep, load_profile, normalize_path, pattern_matches, save_keep, save_profile,
+};
+
+#[test]
+fn profiles_round_trip_and_ignore_comments() {
+    let dir = tempfile::tempdir().unwrap();
+    let paths = vec!["/a/node_modules".to_string(), "/b/*".to_string()];
+
+    save_profile(dir.path(), "work", &paths).unwrap();
+    assert_eq!(load_profile(dir.path(), "work").unwrap(), paths);
+
+    let err = load_profile(dir.path(), "other").unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn patterns_match_exactly_or_with_wildcards() {
+    assert!(pattern_matches("/a/node_modules", "/a/node_modules"));
+    assert!(!pattern_matches("/a/node_modules", "/a/node_modules2"));
+    assert!(pattern_matches("/work/*", "/work/x/y/node_modules"));
+    assert!(pattern_matches(
+        "*/app?/node_modules",
+        "/p/app1/node_modules"
+    ));
+    assert!(!pattern_matches(
+        "*/app?/node_modules",
+        "/p/app/node_modules"
+    ));
+    assert!(pattern_matches(
+        "/a/*/*/node_modules",
+        "/a/b/c/node_modules"
+    ));
+    assert!(!pattern_matches("/a/*/node_modules", "/b/c/node_modules"));
+}
+
+#[test]
+fn the_keep_list_round_trips_normalized() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(load_keep(dir.path()).unwrap().is_empty());
+
+    save_keep(dir.path(), &["/a/node_modules".to_string()]).unwrap();
+    assert_eq!(load_keep(dir.path()).unwrap().len(), 1);
+
+    // Hand-edited lines are normalized as they are read.
+    std::fs::write(
+        killnode::config::keep_path(dir.path()),
+        "# kept\n/a/node_modules\n/b/node_modules/\n",
+    )
+    .unwrap();
+    assert_eq!(
+        load_keep(dir.path()).unwrap(),
+        vec![
+            normalize_path("/a/node_modules"),
+            normalize_path("/b/node_modules")
+        ]
+    );
+    assert_eq!(normalize_path("/"), "/");
+}
@@ -0,0 +1,29 @@
+//! Checks for the disk-space lookup behind the welcome and done screens.
+
+use killnode::diskspace::of_roots;
+
+#[test]
+fn roots_on_one_filesystem_are_counted_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let a = dir.path().join("a");
+    let b = dir.path().join("b");
+    std::fs::create_dir_all(&a).unwrap();
+    std::fs::create_dir_all(&b).unwrap();
+    let root = |p: &std::path::Path| p.to_string_lossy().to_string();
+
+    let one = of_roots(&[root(&a)]).unwrap();
+    let both = of_roots(&[root(&a), root(&b)]).unwrap();
+    assert_eq!(both.filesystems, 1);
+    assert_eq!(both.total, one.total);
+    assert!(one.available <= one.total);
+}
+
+#[test]
+fn unreadable_roots_are_left_out() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing").to_string_lossy().to_string();
+    assert_eq!(of_roots(std::slice::from_ref(&missing)), None);
+
+    let here = dir.path().to_string_lossy().to_string();
+    assert_eq!(of_roots(&[missing, here]).unwrap().filesystems, 1);
+}
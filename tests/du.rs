@@ -0,0 +1,33 @@
+//! Checks for the `--du-format` line format.
+
+use killnode::{
+    app::NodeModuleEntry,
+    du::{self, DuUnit},
+};
+
+fn entry(size: u64) -> NodeModuleEntry {
+    NodeModuleEntry::new("/p/node_modules".into(), size, None, None, false)
+}
+
+#[test]
+fn lines_are_size_tab_path() {
+    assert_eq!(
+        du::line(&entry(2048), DuUnit::default()),
+        "2\t/p/node_modules"
+    );
+    assert_eq!(du::line(&entry(5), DuUnit::Bytes), "5\t/p/node_modules");
+}
+
+#[test]
+fn sizes_round_up_to_a_whole_unit_like_du() {
+    assert_eq!(du::line(&entry(1), DuUnit::Kibibytes), "1\t/p/node_modules");
+    assert_eq!(du::line(&entry(0), DuUnit::Kibibytes), "0\t/p/node_modules");
+    assert_eq!(
+        du::line(&entry((1 << 20) + 1), DuUnit::Mebibytes),
+        "2\t/p/node_modules"
+    );
+    assert_eq!(
+        du::line(&entry(1 << 30), DuUnit::Gibibytes),
+        "1\t/p/node_modules"
+    );
+}
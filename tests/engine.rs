@@ -0,0 +1,700 @@
+//! End-to-end checks for the headless [`Engine`] against a real directory
+//! tree in a temporary directory.
+
+use std::{fs, path::Path, process::Command};
+
+use killnode::{
+    config,
+    deleter::{DeleteMsg, DeleteOptions, is_in_use, start_delete},
+    engine::Engine,
+    scanner::{
+        ScanMsg, ScanOptions, SensitiveReason, sensitive_reason, start_breakdown, start_scan,
+    },
+};
+use tempfile::TempDir;
+
+/// Creates an empty temporary directory under the system's temporary
+/// directory with symlinks resolved (`/tmp` is a link on macOS), so paths
+/// built from it match the canonical ones a scan reports.
+fn temp_dir() -> TempDir {
+    let base = std::env::temp_dir().canonicalize().unwrap();
+    tempfile::tempdir_in(base).unwrap()
+}
+
+/// Creates `path` (and its parents) and writes `len` bytes into it.
+fn write_file(path: &Path, len: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![b'x'; len]).unwrap();
+}
+
+/// Builds a small fixture:
+///
+/// ```text
+/// big/node_modules/pkg/index.js          3000 bytes
+/// big/node_modules/pkg/node_modules/…    1000 bytes  (nested, not reported)
+/// small/node_modules/pkg/index.js         500 bytes
+/// small/src/main.js                       200 bytes  (not node_modules)
+/// ```
+fn fixture() -> TempDir {
+    let dir = temp_dir();
+    let root = dir.path();
+    write_file(&root.join("big/node_modules/pkg/index.js"), 3000);
+    write_file(
+        &root.join("big/node_modules/pkg/node_modules/dep/index.js"),
+        1000,
+    );
+    write_file(&root.join("small/node_modules/pkg/index.js"), 500);
+    write_file(&root.join("small/src/main.js"), 200);
+    dir
+}
+
+fn root_str(dir: &TempDir) -> String {
+    dir.path().to_string_lossy().to_string()
+}
+
+#[test]
+fn scan_finds_top_level_node_modules_largest_first() {
+    let dir = fixture();
+    let entries = Engine::new(root_str(&dir)).scan();
+
+    let found: Vec<(&str, u64)> = entries.iter().map(|e| (e.path.as_str(), e.size)).collect();
+    let big = dir.path().join("big/node_modules");
+    let small = dir.path().join("small/node_modules");
+    assert_eq!(
+        found,
+        vec![
+            (big.to_str().unwrap(), 4000),
+            (small.to_str().unwrap(), 500),
+        ]
+    );
+    assert!(entries.iter().all(|e| e.selected && e.sensitive.is_none()));
+    assert!(entries.iter().all(|e| e.last_modified.is_some()));
+}
+
+#[test]
+fn scan_of_empty_tree_finds_nothing() {
+    let dir = temp_dir();
+    write_file(&dir.path().join("project/index.js"), 10);
+
+    assert!(Engine::new(root_str(&dir)).scan().is_empty());
+}
+
+#[test]
+fn delete_removes_directories_and_reports_freed_bytes() {
+    let dir = fixture();
+    let engine = Engine::new(root_str(&dir));
+    let paths: Vec<String> = engine.scan().into_iter().map(|e| e.path).collect();
+
+    let report = engine.delete(paths.clone());
+
+    assert_eq!(report.freed, 4500);
+    assert_eq!(report.deleted, paths);
+    assert!(report.errors.is_empty());
+    assert!(!dir.path().join("big/node_modules").exists());
+    assert!(!dir.path().join("small/node_modules").exists());
+    assert!(dir.path().join("small/src/main.js").exists());
+    assert!(engine.scan().is_empty());
+
+    let timed: Vec<&String> = report.timings.iter().map(|(p, _)| p).collect();
+    assert_eq!(timed, paths.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn delete_reports_missing_paths_as_errors() {
+    let dir = fixture();
+    let engine = Engine::new(root_str(&dir));
+    let missing = dir.path().join("nope/node_modules");
+    let existing = dir.path().join("small/node_modules");
+
+    let report = engine.delete(vec![
+        missing.to_string_lossy().to_string(),
+        existing.to_string_lossy().to_string(),
+    ]);
+
+    assert_eq!(report.freed, 500);
+    assert_eq!(report.deleted, vec![existing.to_string_lossy().to_string()]);
+    assert_eq!(report.errors.len(), 1);
+    assert!(report.errors[0].starts_with(&*missing.to_string_lossy()));
+    // Failed removals are timed too.
+    assert_eq!(report.timings.len(), 2);
+}
+
+#[test]
+fn parallel_delete_reports_what_a_sequential_one_would() {
+    let dir = temp_dir();
+    let mut paths = Vec::new();
+    for i in 0..12 {
+        let nm = dir.path().join(format!("p{i}/node_modules"));
+        write_file(&nm.join("index.js"), 100 * (i + 1));
+        paths.push(nm.to_string_lossy().to_string());
+    }
+    let missing = dir.path().join("nope/node_modules");
+    paths.insert(5, missing.to_string_lossy().to_string());
+
+    let (rx, _) = start_delete(
+        paths.iter().map(|p| (p.clone(), None)).collect(),
+        DeleteOptions {
+            jobs: 4,
+            ..Default::default()
+        },
+    );
+    let messages: Vec<DeleteMsg> = rx.iter().collect();
+
+    // One Finished per path, and the last one carries the whole total.
+    let finished: Vec<u64> = messages
+        .iter()
+        .filter_map(|m| match m {
+            DeleteMsg::Finished { freed } => Some(*freed),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(finished.len(), paths.len());
+    assert_eq!(finished.iter().max(), Some(&7800));
+
+    let Some(DeleteMsg::Done {
+        freed,
+        deleted,
+        errors,
+        timings,
+        ..
+    }) = messages.last()
+    else {
+        panic!("no Done message");
+    };
+    assert_eq!(*freed, 7800);
+    let mut expected = paths.clone();
+    expected.remove(5);
+    assert_eq!(*deleted, expected);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, paths[5]);
+    assert!(errors[0].1.starts_with(&*missing.to_string_lossy()));
+    let timed: Vec<&String> = timings.iter().map(|(p, _)| p).collect();
+    assert_eq!(timed, paths.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn a_known_size_is_freed_without_measuring_again() {
+    let dir = fixture();
+    let big = dir.path().join("big/node_modules");
+    let small = dir.path().join("small/node_modules");
+
+    // The scan's size for `big` stands, stale or not; `small` has none, so
+    // it is measured.
+    let (rx, _) = start_delete(
+        vec![
+            (big.to_string_lossy().to_string(), Some(42)),
+            (small.to_string_lossy().to_string(), None),
+        ],
+        DeleteOptions::default(),
+    );
+    let Some(DeleteMsg::Done { freed, deleted, .. }) = rx.iter().last() else {
+        panic!("no Done message");
+    };
+    assert_eq!(freed, 542);
+    assert_eq!(deleted.len(), 2);
+    assert!(!big.exists() && !small.exists());
+}
+
+/// Runs `git` in `dir` with a throwaway identity, panicking on failure.
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn delete_skips_repos_with_uncommitted_changes() {
+    let dir = fixture();
+    for project in ["big", "small"] {
+        let repo = dir.path().join(project);
+        write_file(&repo.join(".gitignore"), 0);
+        fs::write(repo.join(".gitignore"), "node_modules\n").unwrap();
+        git(&repo, &["init", "-q"]);
+        git(&repo, &["add", ".gitignore"]);
+        git(&repo, &["commit", "-q", "-m", "init"]);
+    }
+    // Work in flight in "big" only.
+    fs::write(dir.path().join("big/.gitignore"), "node_modules\ndist\n").unwrap();
+
+    let engine = Engine::new(root_str(&dir)).with_delete_options(DeleteOptions {
+        skip_dirty_git: true,
+        ..Default::default()
+    });
+    let paths: Vec<String> = engine.scan().into_iter().map(|e| e.path).collect();
+    let report = engine.delete(paths);
+
+    let big = dir.path().join("big/node_modules");
+    let small = dir.path().join("small/node_modules");
+    assert_eq!(report.deleted, vec![small.to_string_lossy().to_string()]);
+    assert_eq!(report.skipped.len(), 1);
+    assert!(report.skipped[0].starts_with(&*big.to_string_lossy()));
+    assert!(report.errors.is_empty());
+    // Skipped paths are never touched, so never timed.
+    assert_eq!(report.timings.len(), 1);
+    assert!(big.exists());
+}
+
+#[test]
+fn skipping_sizes_still_finds_and_deletes_everything() {
+    let dir = fixture();
+    let engine = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            skip_sizes: true,
+            ..Default::default()
+        })
+        .with_delete_options(DeleteOptions {
+            skip_sizes: true,
+            ..Default::default()
+        });
+
+    let entries = engine.scan();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.size == 0));
+
+    let report = engine.delete(entries.into_iter().map(|e| e.path).collect());
+    assert_eq!(report.deleted.len(), 2);
+    assert_eq!(report.freed, 0);
+    assert!(!dir.path().join("big/node_modules").exists());
+}
+
+#[test]
+fn one_file_system_still_finds_everything_on_the_roots_filesystem() {
+    let dir = fixture();
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            one_file_system: true,
+            ..Default::default()
+        })
+        .scan();
+    assert_eq!(entries.len(), 2);
+}
+
+#[test]
+fn max_results_caps_the_scan() {
+    let dir = fixture();
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            max_results: Some(1),
+            ..Default::default()
+        })
+        .scan();
+    assert_eq!(entries.len(), 1);
+}
+
+/// Runs a scan of `dir` to the end and returns how many entries it found
+/// and whether it said it was truncated.
+fn scan_to_done(dir: &TempDir, options: ScanOptions) -> (usize, bool) {
+    let scan = start_scan(vec![root_str(dir)], options);
+    let mut found = 0;
+    for msg in scan.rx.iter() {
+        match msg {
+            ScanMsg::Found { .. } => found += 1,
+            ScanMsg::Done { truncated } => return (found, truncated),
+        }
+    }
+    panic!("the scan ended without a Done message");
+}
+
+#[test]
+fn max_results_only_truncates_when_something_is_left_out() {
+    let dir = fixture();
+    let capped = |max| ScanOptions {
+        max_results: Some(max),
+        ..Default::default()
+    };
+    // The fixture holds exactly two node_modules.
+    assert_eq!(scan_to_done(&dir, capped(2)), (2, false));
+    assert_eq!(scan_to_done(&dir, capped(1)), (1, true));
+}
+
+#[test]
+fn max_results_only_counts_what_the_list_would_show() {
+    let dir = fixture();
+    // Only `big` is large enough to list, so a cap of one is never hit —
+    // whichever order the walk finds them in — and `small` still comes
+    // through for the list to count as hidden.
+    let options = ScanOptions {
+        max_results: Some(1),
+        min_size: Some(1000),
+        ..Default::default()
+    };
+    assert_eq!(scan_to_done(&dir, options), (2, false));
+
+    let hiding_all = ScanOptions {
+        max_results: Some(1),
+        older_than: Some(u64::MAX),
+        ..Default::default()
+    };
+    assert_eq!(scan_to_done(&dir, hiding_all), (2, false));
+}
+
+#[test]
+fn a_relative_path_is_never_taken_for_a_safe_one() {
+    assert_eq!(
+        sensitive_reason("projects/app/node_modules"),
+        Some(SensitiveReason::RelativePath)
+    );
+    let dir = fixture();
+    assert_eq!(sensitive_reason(dir.path().join("big/node_modules")), None);
+}
+
+#[test]
+fn a_root_given_twice_is_scanned_once() {
+    let dir = fixture();
+    fs::create_dir(dir.path().join("q")).unwrap();
+    let dotted = dir.path().join("q/..").to_string_lossy().to_string();
+    let entries = Engine::with_roots(vec![root_str(&dir), dotted]).scan();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| !e.path.contains("..")));
+}
+
+#[test]
+fn kept_paths_are_reported_as_kept() {
+    let dir = fixture();
+    let big = dir.path().join("big/node_modules");
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            keep: vec![big.to_string_lossy().to_string()],
+            ..Default::default()
+        })
+        .scan();
+    let reasons: Vec<_> = entries.iter().map(|e| e.sensitive).collect();
+    assert_eq!(reasons, [Some(SensitiveReason::Kept), None]);
+    assert!(!entries[0].selected);
+}
+
+#[test]
+fn root_marker_limits_the_scan_to_marked_projects() {
+    let dir = fixture();
+    // "big" is a project; "small" is just a folder.
+    write_file(&dir.path().join("big/package.json"), 2);
+    let marked = ScanOptions {
+        root_marker: Some("package.json".to_string()),
+        ..Default::default()
+    };
+
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(marked.clone())
+        .scan();
+    let big = dir.path().join("big/node_modules");
+    let found: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(found, vec![big.to_str().unwrap()]);
+
+    // A marker above the scan root counts too.
+    let entries = Engine::new(dir.path().join("big").to_string_lossy())
+        .with_scan_options(marked)
+        .scan();
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn several_roots_are_scanned_into_one_list_without_repeats() {
+    let dir = fixture();
+    let other = fixture();
+    let big = dir.path().join("big").to_string_lossy().to_string();
+    let entries = Engine::with_roots(vec![big, root_str(&dir), root_str(&other)]).scan();
+
+    // `big` lies inside the first fixture, so its one entry isn't repeated.
+    assert_eq!(entries.len(), 4);
+    let mut paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+    paths.sort();
+    paths.dedup();
+    assert_eq!(paths.len(), 4);
+    assert!(
+        entries
+            .iter()
+            .any(|e| e.path.starts_with(&root_str(&other)))
+    );
+}
+
+#[test]
+fn nothing_below_max_depth_is_found() {
+    let dir = fixture();
+    let scan = |depth| {
+        Engine::new(root_str(&dir))
+            .with_scan_options(ScanOptions {
+                max_depth: Some(depth),
+                ..Default::default()
+            })
+            .scan()
+            .len()
+    };
+    // Both node_modules sit two levels down: `big/node_modules`.
+    assert_eq!(scan(1), 0);
+    assert_eq!(scan(2), 2);
+}
+
+#[test]
+fn ignored_directories_are_never_entered() {
+    let dir = fixture();
+    let scan = |ignore: &[String]| -> Vec<String> {
+        Engine::new(root_str(&dir))
+            .with_scan_options(ScanOptions {
+                ignore: config::ignore_set(ignore).unwrap(),
+                ..Default::default()
+            })
+            .scan()
+            .into_iter()
+            .map(|e| e.path)
+            .collect()
+    };
+    let small = dir.path().join("small/node_modules");
+
+    // A full path leaves out that directory and everything under it…
+    let by_path = dir.path().join("big").to_string_lossy().to_string();
+    assert_eq!(scan(&[by_path]), vec![small.to_string_lossy()]);
+    // …and a bare name applies at any depth.
+    assert_eq!(scan(&["b?g".to_string()]), vec![small.to_string_lossy()]);
+    assert_eq!(scan(&["node_modules".to_string()]), Vec::<String>::new());
+}
+
+#[test]
+fn excluded_paths_are_matched_in_full() {
+    let dir = fixture();
+    let scan = |exclude: &str| -> Vec<String> {
+        Engine::new(root_str(&dir))
+            .with_scan_options(ScanOptions {
+                exclude: config::exclude_set(&[exclude.to_string()]).unwrap(),
+                ..Default::default()
+            })
+            .scan()
+            .into_iter()
+            .map(|e| e.path)
+            .collect()
+    };
+    let small = dir.path().join("small/node_modules");
+
+    assert_eq!(scan("**/big"), vec![small.to_string_lossy()]);
+    assert_eq!(scan("**/big/node_*"), vec![small.to_string_lossy()]);
+    assert_eq!(scan("**/{big,small}").len(), 0);
+    // `*` stays within one component, so this only matches at the top.
+    assert_eq!(scan("*/big/node_modules").len(), 2);
+    // Unlike an ignore pattern, a bare name has to be the whole path.
+    assert_eq!(scan("big").len(), 2);
+}
+
+#[test]
+fn other_targets_are_found_and_not_walked_into() {
+    let dir = fixture();
+    write_file(&dir.path().join("crate/target/debug/app"), 700);
+    write_file(&dir.path().join("crate/target/node_modules/x.js"), 10);
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            targets: vec!["node_modules".to_string(), "target".to_string()],
+            ..Default::default()
+        })
+        .scan();
+
+    let target = dir
+        .path()
+        .join("crate/target")
+        .to_string_lossy()
+        .to_string();
+    assert_eq!(entries.len(), 3);
+    let found = entries.iter().find(|e| e.path == target).unwrap();
+    assert_eq!(found.size, 710);
+}
+
+#[test]
+fn pruning_removes_parents_left_empty_up_to_the_root() {
+    let dir = fixture();
+    write_file(&dir.path().join("a/shell/node_modules/pkg/index.js"), 10);
+    let engine = Engine::new(root_str(&dir)).with_delete_options(DeleteOptions {
+        prune_empty_parents: vec![dir.path().to_path_buf()],
+        ..Default::default()
+    });
+
+    let paths: Vec<String> = engine.scan().into_iter().map(|e| e.path).collect();
+    let report = engine.delete(paths);
+
+    assert!(report.errors.is_empty());
+    assert!(!dir.path().join("a").exists());
+    assert!(!dir.path().join("big").exists());
+    // Still holds src/, so it stays.
+    assert!(dir.path().join("small/src/main.js").exists());
+    assert!(dir.path().exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn disk_usage_counts_whole_blocks() {
+    let dir = fixture();
+    let entries = Engine::new(root_str(&dir))
+        .with_scan_options(ScanOptions {
+            disk_usage: true,
+            ..Default::default()
+        })
+        .scan();
+
+    // Two files in `big` and one in `small`, each taking at least a block.
+    // (A filesystem that stores tiny files inline may report 0 instead.)
+    for entry in &entries {
+        assert_eq!(entry.size % 512, 0, "{} isn't whole blocks", entry.path);
+    }
+    let big = entries.iter().find(|e| e.path.contains("big")).unwrap();
+    assert!(big.size == 0 || big.size >= 4096);
+}
+
+#[test]
+fn breakdown_measures_each_direct_child_largest_first() {
+    let dir = fixture();
+    write_file(&dir.path().join("big/node_modules/.package-lock.json"), 20);
+    let nm = dir.path().join("big/node_modules");
+
+    let children = start_breakdown(nm.to_string_lossy().to_string(), false)
+        .recv()
+        .unwrap();
+
+    assert_eq!(
+        children,
+        vec![
+            ("pkg".to_string(), 4000),
+            (".package-lock.json".to_string(), 20)
+        ]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_node_modules_is_listed_and_only_the_link_is_deleted() {
+    let dir = fixture();
+    let store = dir.path().join("store");
+    write_file(&store.join("pkg/index.js"), 700);
+    fs::create_dir_all(dir.path().join("linked")).unwrap();
+    let link = dir.path().join("linked/node_modules");
+    std::os::unix::fs::symlink(&store, &link).unwrap();
+
+    let engine = Engine::new(root_str(&dir));
+    let entries = engine.scan();
+    let linked = entries
+        .iter()
+        .find(|e| Path::new(&e.path) == link)
+        .expect("symlinked node_modules is listed");
+    assert!(linked.symlink);
+    assert_eq!(linked.size, 0);
+
+    let report = engine.delete(vec![linked.path.clone()]);
+
+    assert!(report.errors.is_empty());
+    assert_eq!(report.freed, 0);
+    assert!(fs::symlink_metadata(&link).is_err());
+    assert!(store.join("pkg/index.js").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn links_out_of_the_tree_are_neither_measured_nor_followed_when_deleting() {
+    use std::os::unix::fs::symlink;
+
+    // A shared store outside the scan root, as pnpm keeps one.
+    let store = temp_dir();
+    write_file(&store.path().join("pkg/index.js"), 700);
+    write_file(&store.path().join("lib.js"), 300);
+
+    let dir = temp_dir();
+    let modules = dir.path().join("app/node_modules");
+    write_file(&modules.join(".modules.yaml"), 50);
+    symlink(store.path().join("pkg"), modules.join("pkg")).unwrap();
+    symlink(store.path().join("lib.js"), modules.join("lib.js")).unwrap();
+    let linked = dir.path().join("shared/node_modules");
+    fs::create_dir_all(linked.parent().unwrap()).unwrap();
+    symlink(store.path(), &linked).unwrap();
+
+    let engine = Engine::new(root_str(&dir));
+    let entries = engine.scan();
+    let entry_at = |path: &Path| entries.iter().find(|e| Path::new(&e.path) == path).unwrap();
+    assert_eq!(entry_at(&modules).size, 50);
+    assert!(entry_at(&linked).symlink);
+
+    let report = engine.delete(vec![
+        modules.to_string_lossy().to_string(),
+        linked.to_string_lossy().to_string(),
+    ]);
+    assert!(report.errors.is_empty());
+    assert!(!modules.exists() && fs::symlink_metadata(&linked).is_err());
+    assert!(store.path().join("pkg/index.js").exists());
+    assert!(store.path().join("lib.js").exists());
+}
+
+#[test]
+fn a_held_lock_makes_the_delete_wait_its_turn() {
+    let dir = fixture();
+    let lock = dir.path().join("locks/delete.lock");
+    fs::create_dir_all(lock.parent().unwrap()).unwrap();
+    let held = fs::File::create(&lock).unwrap();
+    held.lock().unwrap();
+
+    let target = dir.path().join("small/node_modules");
+    let (rx, _) = start_delete(
+        vec![(target.to_string_lossy().to_string(), None)],
+        DeleteOptions {
+            lock: Some(lock),
+            ..Default::default()
+        },
+    );
+    assert!(matches!(rx.recv().unwrap(), DeleteMsg::Waiting));
+    assert!(target.exists());
+
+    drop(held);
+    let done = rx.iter().find(|m| matches!(m, DeleteMsg::Done { .. }));
+    assert!(done.is_some());
+    assert!(!target.exists());
+}
+
+#[test]
+fn a_cancelled_delete_skips_what_it_has_not_started() {
+    let dir = fixture();
+    let lock = dir.path().join("locks/delete.lock");
+    fs::create_dir_all(lock.parent().unwrap()).unwrap();
+    let held = fs::File::create(&lock).unwrap();
+    held.lock().unwrap();
+
+    // Holding the lock keeps the thread from starting until it is cancelled.
+    let paths: Vec<String> = ["big", "small"]
+        .map(|p| dir.path().join(p).join("node_modules"))
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    let (rx, stop) = start_delete(
+        paths.iter().map(|p| (p.clone(), None)).collect(),
+        DeleteOptions {
+            lock: Some(lock),
+            ..Default::default()
+        },
+    );
+    assert!(matches!(rx.recv().unwrap(), DeleteMsg::Waiting));
+    stop.store(true, std::sync::atomic::Ordering::Relaxed);
+
+    // Cancelling ends the wait while the lock is still held.
+    let Some(DeleteMsg::Done {
+        deleted, skipped, ..
+    }) = std::iter::from_fn(|| rx.recv_timeout(std::time::Duration::from_secs(5)).ok())
+        .find(|m| matches!(m, DeleteMsg::Done { .. }))
+    else {
+        panic!("no Done message while the lock was held");
+    };
+    drop(held);
+    assert!(deleted.is_empty());
+    assert_eq!(
+        skipped,
+        paths
+            .iter()
+            .map(|p| format!("{p}: cancelled"))
+            .collect::<Vec<_>>()
+    );
+    assert!(paths.iter().all(|p| Path::new(p).exists()));
+}
+
+#[test]
+fn files_held_open_elsewhere_are_recognised() {
+    use std::io::{Error, ErrorKind};
+    assert!(is_in_use(&Error::from(ErrorKind::ResourceBusy)));
+    assert!(!is_in_use(&Error::from(ErrorKind::NotFound)));
+    assert!(!is_in_use(&Error::from(ErrorKind::PermissionDenied)));
+}
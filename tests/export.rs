@@ -0,0 +1,127 @@
+//! Checks for the `--json` export.
+
+use killnode::{
+    app::NodeModuleEntry,
+    engine::DeleteReport,
+    export::{Exported, apply_summary, from_json, to_json},
+    scanner::SensitiveReason,
+};
+
+#[test]
+fn entries_become_a_pretty_array_of_objects() {
+    let entries = [
+        NodeModuleEntry::new("/p/a/node_modules".into(), 3_000, None, Some(1_700), false),
+        NodeModuleEntry::new(
+            "/p/b/node_modules".into(),
+            20,
+            Some(SensitiveReason::DotDirectory),
+            None,
+            false,
+        ),
+    ];
+    let text = to_json(&entries);
+    assert!(text.starts_with("[\n  {\n"));
+    assert!(text.ends_with("]\n"));
+
+    let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {
+                "path": "/p/a/node_modules",
+                "size": 3_000,
+                "sensitive": false,
+                "last_modified": 1_700,
+            },
+            {
+                "path": "/p/b/node_modules",
+                "size": 20,
+                "sensitive": true,
+                "last_modified": null,
+            },
+        ])
+    );
+}
+
+#[test]
+fn nothing_found_is_an_empty_array() {
+    assert_eq!(to_json(&[]), "[]\n");
+}
+
+#[test]
+fn an_export_reads_back_as_paths() {
+    let entries = [
+        NodeModuleEntry::new("/p/a/node_modules".into(), 3_000, None, Some(1_700), false),
+        NodeModuleEntry::new(
+            "/p/b/node_modules".into(),
+            20,
+            Some(SensitiveReason::DotDirectory),
+            None,
+            false,
+        ),
+    ];
+    assert_eq!(
+        from_json(&to_json(&entries), &[]).unwrap(),
+        vec![
+            Exported {
+                path: "/p/a/node_modules".into(),
+                sensitive: false,
+            },
+            Exported {
+                path: "/p/b/node_modules".into(),
+                sensitive: true,
+            },
+        ]
+    );
+    assert_eq!(from_json("[]", &[]).unwrap(), vec![]);
+}
+
+#[test]
+fn paths_that_look_sensitive_now_count_as_sensitive() {
+    let read = from_json(
+        r#"[{"path": "/Applications/X.app/node_modules", "sensitive": false}]"#,
+        &[],
+    );
+    assert!(read.unwrap()[0].sensitive);
+}
+
+#[test]
+fn paths_pinned_since_the_export_count_as_sensitive() {
+    let text = r#"[{"path": "/p/a/node_modules/", "sensitive": false}]"#;
+    let keep = ["/p/a/node_modules".to_string()];
+    assert!(from_json(text, &keep).unwrap()[0].sensitive);
+    assert!(!from_json(text, &[]).unwrap()[0].sensitive);
+}
+
+#[test]
+fn malformed_exports_are_rejected() {
+    assert!(from_json("not json", &[]).is_err());
+    assert!(from_json(r#"{"path": "/p"}"#, &[]).is_err());
+    let err = from_json(r#"[{"path": "/p"}, {"size": 1}]"#, &[]).unwrap_err();
+    assert!(err.contains("entry 2"));
+    assert!(from_json(r#"[{"path": "node_modules"}]"#, &[]).is_err());
+}
+
+#[test]
+fn the_apply_summary_fits_on_one_line() {
+    let report = DeleteReport {
+        freed: 1_200_000_000,
+        deleted: vec!["/a".into(), "/b".into(), "/c".into()],
+        ..DeleteReport::default()
+    };
+    assert_eq!(
+        apply_summary(&report, 0, true),
+        "Deleted 3 directories, freed 1.20 GB"
+    );
+
+    let report = DeleteReport {
+        deleted: vec!["/a".into()],
+        skipped: vec!["/b: dirty".into()],
+        errors: vec!["/c: denied".into(), "/d: denied".into()],
+        ..DeleteReport::default()
+    };
+    assert_eq!(
+        apply_summary(&report, 1, false),
+        "Deleted 1 directory; 1 skipped; 1 sensitive path left alone (needs --force); 2 errors"
+    );
+}
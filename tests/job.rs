@@ -0,0 +1,63 @@
+//! Checks for `--detach` plans: what the worker is told, and what running a
+//! plan deletes and reports.
+
+use std::fs;
+
+use killnode::{
+    cli::{Command, Options, parse},
+    deleter::DeleteMode,
+    job::{report_path, run_plan, worker_args, write_plan},
+};
+
+#[test]
+fn worker_args_carry_the_delete_options_and_roots() {
+    let options = Options {
+        exclude_dirty_git: true,
+        prune_empty_parents: true,
+        no_size: false,
+        disk_usage: true,
+        delete_mode: DeleteMode::Trash,
+        jobs: Some(4),
+        ..Default::default()
+    };
+    let roots = ["-odd root".to_string(), "/mnt/b".to_string()];
+    let args = worker_args("/tmp/j/x.plan".as_ref(), &options, &roots);
+
+    let Ok(Command::Run(parsed)) = parse(args) else {
+        panic!("worker arguments don't parse");
+    };
+    assert_eq!(parsed.run_plan.as_deref(), Some("/tmp/j/x.plan"));
+    assert!(parsed.exclude_dirty_git && parsed.prune_empty_parents && !parsed.no_size);
+    assert!(parsed.disk_usage);
+    assert_eq!(parsed.delete_mode, DeleteMode::Trash);
+    assert_eq!(parsed.jobs, Some(4));
+    assert_eq!(parsed.roots, roots);
+}
+
+#[test]
+fn running_a_plan_deletes_its_paths_and_writes_a_report() {
+    let dir = tempfile::tempdir().unwrap();
+    let nm = dir.path().join("app/node_modules");
+    fs::create_dir_all(&nm).unwrap();
+    fs::write(nm.join("index.js"), vec![b'x'; 100]).unwrap();
+    let missing = dir.path().join("gone/node_modules");
+    let paths = vec![
+        nm.to_string_lossy().to_string(),
+        missing.to_string_lossy().to_string(),
+    ];
+
+    let plan = write_plan(&dir.path().join("jobs"), &paths).unwrap();
+    let options = Options {
+        roots: vec![dir.path().to_string_lossy().to_string()],
+        ..Default::default()
+    };
+    let report = run_plan(&plan, &options).unwrap();
+
+    assert_eq!(report, report_path(&plan));
+    assert!(!plan.exists());
+    assert!(!nm.exists());
+    let text = fs::read_to_string(report).unwrap();
+    assert!(text.contains("freed 100\n"));
+    assert!(text.contains(&format!("deleted {}\n", paths[0])));
+    assert!(text.contains(&format!("error {}", paths[1])));
+}
@@ -0,0 +1,63 @@
+//! Checks for `--preselect` rule parsing and matching.
+
+use killnode::{app::NodeModuleEntry, preselect::Preselect};
+
+const DAY: u64 = 86_400;
+const NOW: u64 = 1_000 * DAY;
+
+fn entry(size: u64, age_days: Option<u64>) -> NodeModuleEntry {
+    let last_modified = age_days.map(|d| NOW - d * DAY);
+    NodeModuleEntry::new("/p/node_modules".into(), size, None, last_modified, false)
+}
+
+#[test]
+fn and_needs_every_clause() {
+    let rule = Preselect::parse("age>90d and size>200MB").unwrap();
+    assert!(rule.matches(&entry(300_000_000, Some(120)), NOW));
+    assert!(!rule.matches(&entry(300_000_000, Some(30)), NOW));
+    assert!(!rule.matches(&entry(100_000_000, Some(120)), NOW));
+}
+
+#[test]
+fn or_needs_one_alternative_and_binds_looser_than_and() {
+    let rule = Preselect::parse("size >= 1GB or age>1y and size>1KB").unwrap();
+    assert!(rule.matches(&entry(1_000_000_000, Some(1)), NOW));
+    assert!(rule.matches(&entry(2_000, Some(400)), NOW));
+    assert!(!rule.matches(&entry(500, Some(400)), NOW));
+}
+
+#[test]
+fn an_unknown_age_never_matches() {
+    let rule = Preselect::parse("age<1w").unwrap();
+    assert!(rule.matches(&entry(0, Some(2)), NOW));
+    assert!(!rule.matches(&entry(0, None), NOW));
+}
+
+#[test]
+fn units_and_spacing_are_flexible() {
+    assert_eq!(
+        Preselect::parse("SIZE>1.5gb").unwrap(),
+        Preselect::parse("size > 1500MB").unwrap()
+    );
+    assert_eq!(
+        Preselect::parse("age<=2w").unwrap(),
+        Preselect::parse("age <= 14d").unwrap()
+    );
+    assert!(Preselect::parse("size>200").is_ok());
+}
+
+#[test]
+fn bad_rules_are_rejected() {
+    for rule in [
+        "",
+        "size",
+        "size>",
+        "size>10XB",
+        "age>90",
+        "name>foo",
+        "size>1MB and",
+        "or age>1d",
+    ] {
+        assert!(Preselect::parse(rule).is_err(), "{rule:?} should not parse");
+    }
+}
@@ -0,0 +1,49 @@
+//! Checks for the `--progress-json` event lines, driven by the headless
+//! [`Engine`] against a temporary directory tree.
+
+use std::fs;
+
+use killnode::{engine::Engine, progress};
+use serde_json::{Value, json};
+
+/// Parses one progress line, checking it really is a single line.
+fn parse(line: &str) -> Value {
+    assert!(!line.contains('\n'), "{line:?} spans several lines");
+    serde_json::from_str(line).unwrap()
+}
+
+#[test]
+fn scan_and_delete_events_describe_each_step() {
+    let dir = tempfile::tempdir().unwrap();
+    let nm = dir.path().join("app/node_modules");
+    fs::create_dir_all(&nm).unwrap();
+    fs::write(nm.join("index.js"), vec![b'x'; 300]).unwrap();
+    let path = nm.to_string_lossy().to_string();
+
+    let engine = Engine::new(dir.path().to_string_lossy());
+    let mut lines = Vec::new();
+    let entries =
+        engine.scan_with_progress(|entry| lines.push(progress::found(lines.len(), entry)));
+    lines.push(progress::scan_done(&entries));
+    let report = engine.delete_with_progress(vec![path.clone()], |index, total, path| {
+        lines.push(progress::deleting(index, total, path));
+    });
+    lines.push(progress::delete_done(&report));
+
+    let events: Vec<Value> = lines.iter().map(|l| parse(l)).collect();
+    assert_eq!(
+        events,
+        vec![
+            json!({"event": "found", "index": 0, "path": path, "bytes": 300, "sensitive": false, "symlink": false}),
+            json!({"event": "scan_done", "total": 1, "bytes": 300}),
+            json!({"event": "deleting", "index": 0, "total": 1, "path": path}),
+            json!({"event": "delete_done", "deleted": 1, "skipped": 0, "errors": 0, "bytes": 300}),
+        ]
+    );
+}
+
+#[test]
+fn paths_are_escaped() {
+    let line = progress::deleting(2, 5, "C:\\Users\\\"odd\"\nname");
+    assert_eq!(parse(&line)["path"], "C:\\Users\\\"odd\"\nname");
+}
@@ -0,0 +1,67 @@
+//! Checks for finding the project an entry belongs to.
+
+use std::{fs, path::Path};
+
+use killnode::project::project_dir;
+
+fn s(p: &Path) -> String {
+    p.to_string_lossy().to_string()
+}
+
+#[test]
+fn the_repository_wins_over_each_package() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    let package = repo.join("packages/ui");
+    fs::create_dir_all(repo.join(".git")).unwrap();
+    fs::create_dir_all(package.join("node_modules")).unwrap();
+    fs::write(package.join("package.json"), "{}").unwrap();
+    fs::write(repo.join("package.json"), "{}").unwrap();
+
+    let root = s(dir.path());
+    assert_eq!(
+        project_dir(&s(&package.join("node_modules")), Some(&root)),
+        s(&repo)
+    );
+    assert_eq!(
+        project_dir(&s(&repo.join("node_modules")), Some(&root)),
+        s(&repo)
+    );
+}
+
+#[test]
+fn outside_a_repository_the_nearest_package_decides() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = dir.path().join("app");
+    fs::create_dir_all(app.join("tools/node_modules")).unwrap();
+    fs::write(app.join("package.json"), "{}").unwrap();
+
+    let root = s(dir.path());
+    assert_eq!(
+        project_dir(&s(&app.join("tools/node_modules")), Some(&root)),
+        s(&app)
+    );
+
+    // With neither, the directory holding it is its own project.
+    let loose = dir.path().join("loose/node_modules");
+    fs::create_dir_all(&loose).unwrap();
+    assert_eq!(
+        project_dir(&s(&loose), Some(&root)),
+        s(&dir.path().join("loose"))
+    );
+}
+
+#[test]
+fn the_search_stops_at_the_scan_root() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".git")).unwrap();
+    let root = dir.path().join("projects");
+    let modules = root.join("a/node_modules");
+    fs::create_dir_all(&modules).unwrap();
+
+    assert_eq!(
+        project_dir(&s(&modules), Some(&s(&root))),
+        s(&root.join("a"))
+    );
+    assert_eq!(project_dir(&s(&modules), None), s(dir.path()));
+}
@@ -0,0 +1,45 @@
+//! Checks for the `--stats-only` totals and report.
+
+use killnode::{
+    app::NodeModuleEntry,
+    scanner::SensitiveReason,
+    stats::{Totals, summary},
+};
+
+fn entry(size: u64, sensitive: bool) -> NodeModuleEntry {
+    let reason = sensitive.then_some(SensitiveReason::DotDirectory);
+    NodeModuleEntry::new("/p/node_modules".to_string(), size, reason, None, false)
+}
+
+#[test]
+fn totals_add_up_sensitive_entries_separately() {
+    let totals = Totals::of(&[entry(3_000, false), entry(500, true), entry(200, true)]);
+    assert_eq!(
+        totals,
+        Totals {
+            count: 3,
+            bytes: 3_700,
+            sensitive: 2,
+            sensitive_bytes: 700,
+        }
+    );
+}
+
+#[test]
+fn summary_gives_the_headline_numbers() {
+    let totals = Totals::of(&[entry(2_500_000_000, false), entry(700_000_000, true)]);
+    assert_eq!(
+        summary(&totals, true, false),
+        "2 node_modules directories totaling 3.20 GB\n1 of them in sensitive locations (700 MB)"
+    );
+    assert_eq!(
+        summary(&Totals::of(&[entry(0, false)]), false, false),
+        "1 node_modules directory (sizes not measured)"
+    );
+    assert_eq!(
+        summary(&Totals::default(), true, false),
+        "No node_modules directories found"
+    );
+    // Colour only adds escape codes around the same text.
+    assert!(summary(&totals, true, true).contains("\x1b["));
+}
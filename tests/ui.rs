@@ -0,0 +1,611 @@
+//! Renders every screen into a [`TestBackend`] at awkward sizes to check that
+//! none of them panic, however little room they are given.
+
+use killnode::{
+    app::{App, NodeModuleEntry, Screen},
+    cli::{Options, ThemeName},
+    scanner::SensitiveReason,
+    ui,
+};
+use ratatui::{Terminal, backend::TestBackend, style::Color};
+
+/// An app on `screen` with a couple of entries and an error, so every
+/// conditional region of every screen has something to draw.
+fn app_on(screen: Screen) -> App {
+    let mut app = App::new(Options {
+        roots: vec!["/tmp/projects".to_string()],
+        ..Default::default()
+    });
+    app.entries = vec![
+        NodeModuleEntry::new(
+            "/tmp/projects/a/node_modules".to_string(),
+            123_456_789,
+            None,
+            Some(0),
+            false,
+        ),
+        NodeModuleEntry::new(
+            "/tmp/projects/.config/b/node_modules".to_string(),
+            42,
+            Some(SensitiveReason::XdgConfig),
+            None,
+            true,
+        ),
+    ];
+    app.list_state.select(Some(0));
+    app.show_stats = true;
+    app.show_cumulative = true;
+    app.delete_removed = 1;
+    app.delete_total = 2;
+    app.delete_errors = vec![(
+        "/tmp/projects/c/node_modules".to_string(),
+        "/tmp/projects/c/node_modules: denied".to_string(),
+    )];
+    app.delete_skipped = vec!["/tmp/projects/d/node_modules: dirty".to_string()];
+    app.breakdown_path = Some("/tmp/projects/a/node_modules".to_string());
+    app.breakdown = Some(
+        (0..12)
+            .map(|i| (format!("pkg-{i}"), 1_000_000 / (i + 1)))
+            .collect(),
+    );
+    app.status = Some("A status message".to_string());
+    app.excluded = 3;
+    app.ticker = killnode::app::SLOW_SCAN_TICKS;
+    app.screen = screen;
+    app
+}
+
+fn screens() -> Vec<Screen> {
+    vec![
+        Screen::Welcome,
+        Screen::Scanning,
+        Screen::List,
+        Screen::Confirming,
+        Screen::SensitiveReview,
+        Screen::Countdown,
+        Screen::Breakdown,
+        Screen::Detail,
+        Screen::Deleting,
+        Screen::Done,
+    ]
+}
+
+#[test]
+fn tiny_and_empty_terminals_do_not_panic() {
+    for (width, height) in [(0, 0), (0, 10), (10, 0), (1, 1), (3, 2), (29, 7)] {
+        for screen in screens() {
+            let mut app = app_on(screen);
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        }
+    }
+}
+
+#[test]
+fn renderers_cope_with_cramped_areas_directly() {
+    // Below the minimum size `ui::ui` shows a notice instead of a screen, so
+    // call the renderers themselves to exercise their own guards.
+    for (width, height) in [(0, 0), (1, 1), (4, 3), (12, 5)] {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        for screen in screens() {
+            let mut app = app_on(screen);
+            terminal
+                .draw(|f| match app.screen {
+                    Screen::Welcome => ui::welcome::render_welcome(f, &app),
+                    Screen::Scanning => ui::scanning::render_scanning(f, &app),
+                    Screen::List | Screen::Confirming => ui::list::render_list(f, &mut app),
+                    Screen::SensitiveReview => ui::review::render_review(f, &app),
+                    Screen::Countdown => ui::countdown::render_countdown(f, &app),
+                    Screen::Breakdown => ui::breakdown::render_breakdown(f, &app),
+                    Screen::Detail => ui::detail::render_detail(f, &app),
+                    Screen::Deleting => ui::deleting::render_deleting(f, &app),
+                    Screen::Done => ui::done::render_done(f, &mut app),
+                })
+                .unwrap();
+        }
+    }
+}
+
+#[test]
+fn the_scanning_screen_shows_the_largest_found_so_far() {
+    let mut app = app_on(Screen::Scanning);
+    app.entries = vec![
+        NodeModuleEntry::new("/p/big/node_modules".into(), 2_000_000, None, None, false),
+        NodeModuleEntry::new("/p/small/node_modules".into(), 3_000, None, None, false),
+    ];
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let rows: Vec<String> = (0..24)
+        .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect();
+
+    let at = |needle: &str| rows.iter().position(|r| r.contains(needle));
+    assert!(at("Largest so far:").is_some());
+    assert!(
+        rows.iter()
+            .any(|r| r.contains("  2 MB  /p/big/node_modules"))
+    );
+    assert!(at("/p/big/node_modules") < at("/p/small/node_modules"));
+}
+
+#[test]
+fn the_status_shows_on_every_screen_in_the_bottom_border() {
+    for screen in screens() {
+        let mut app = app_on(screen);
+        app.set_status("Opened /work/app");
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let bottom: String = (0..100).map(|x| buffer[(x, 29)].symbol()).collect();
+        assert!(bottom.starts_with("└ Opened /work/app ─"), "{bottom}");
+    }
+
+    // Too long for the frame: cut off, leaving the corners alone.
+    let mut app = app_on(Screen::Done);
+    app.set_status("x".repeat(200));
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    assert_eq!(buffer[(39, 11)].symbol(), "┘");
+}
+
+#[test]
+fn the_compact_list_drops_the_modified_column_for_the_path() {
+    let mut app = app_on(Screen::List);
+    app.entries[0].selected = true;
+    let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+    assert!(text.contains("MODIFIED") && text.contains("[✓]"));
+    assert!(!text.contains("/tmp/projects/.config/b/node_modules"));
+
+    app.toggle_compact();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+    assert!(!text.contains("MODIFIED") && !text.contains("[✓]"));
+    assert!(text.contains(" ✓ PATH"));
+    assert!(text.contains(" ✓ /tmp/projects/a/node_modules"));
+    assert!(text.contains(" · ⚠"));
+    assert!(text.contains("/tmp/projects/.config/b/node_modules"));
+}
+
+#[test]
+fn small_terminal_shows_a_notice() {
+    let mut app = app_on(Screen::List);
+    let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let first_row: String = (0..20)
+        .map(|x| terminal.backend().buffer()[(x, 0)].symbol().to_string())
+        .collect();
+    assert!(first_row.starts_with("Terminal too small"));
+}
+
+#[test]
+fn mono_theme_draws_without_colour() {
+    for (i, screen) in screens().into_iter().enumerate() {
+        let mut app = app_on(screen);
+        app.theme = ui::Theme::named(ThemeName::Mono);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        assert!(
+            buffer
+                .content()
+                .iter()
+                .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset),
+            "screen #{i} used a colour"
+        );
+    }
+}
+
+#[test]
+fn breakdown_lists_the_largest_children_and_sums_the_rest() {
+    let mut app = app_on(Screen::Breakdown);
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("pkg-0"));
+    assert!(text.contains("pkg-9"));
+    assert!(!text.contains("pkg-10"));
+    assert!(text.contains("+ 2 more"));
+}
+
+#[test]
+fn detail_shows_the_full_path_and_why_it_is_sensitive() {
+    let mut app = app_on(Screen::Detail);
+    app.list_state.select(Some(1));
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("/tmp/projects/.config/b/node_modules"));
+    assert!(text.contains("Project    b"));
+    assert!(text.contains("yes — app configuration in ~/.config"));
+}
+
+#[test]
+fn confirmation_names_the_sensitive_reason() {
+    let mut app = app_on(Screen::Confirming);
+    app.entries[1].selected = true;
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("sensitive — app configuration in ~/.config"));
+}
+
+#[test]
+fn confirmation_splits_a_mixed_count_into_safe_and_sensitive() {
+    let mut app = app_on(Screen::Confirming);
+    app.entries[0].selected = true;
+    app.entries[1].selected = true;
+    let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let text: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect();
+    assert!(text.contains("Delete  2 directories (1 safe + 1 sensitive)"));
+
+    // Nothing to split when it's all one kind.
+    app.entries[1].selected = false;
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let text: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect();
+    assert!(text.contains("Delete  1 directory") && !text.contains("safe +"));
+}
+
+#[test]
+fn a_sensitive_confirmation_asks_for_the_word() {
+    let mut app = app_on(Screen::Confirming);
+    app.entries[1].selected = true;
+    app.confirm_typed = "del".to_string();
+    app.confirm_mismatch = true;
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("Type delete to confirm: del"));
+    assert!(text.contains("That wasn't \"delete\""));
+    assert!(text.contains(" delete + Enter  Confirm"));
+}
+
+#[test]
+fn the_countdown_shows_the_seconds_in_block_digits() {
+    assert_eq!(
+        ui::countdown::big_number(3),
+        vec!["████", "   █", "████", "   █", "████"]
+    );
+    assert_eq!(ui::countdown::big_number(10)[0], "   █ ████");
+
+    let mut app = app_on(Screen::Confirming);
+    app.entries[1].selected = true;
+    app.begin_delete_after_countdown();
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("Deleting sensitive directories"));
+    assert!(text.contains("████"));
+    assert!(text.contains("1 sensitive directory queued"));
+}
+
+#[test]
+fn the_deleting_screen_estimates_the_time_left() {
+    use std::time::{Duration, Instant};
+    let mut app = app_on(Screen::Deleting);
+    app.delete_total = 6;
+    app.delete_done = 0;
+    app.delete_started_at = Some(Instant::now());
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+    assert!(text.contains("Remaining:    estimating…"));
+
+    // Three done in 90 seconds leaves another 90 for the other three.
+    app.delete_done = 3;
+    app.delete_started_at = Instant::now().checked_sub(Duration::from_secs(90));
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+    assert!(text.contains("Remaining:    ~1 min 31 s"));
+}
+
+#[test]
+fn dates_are_shown_in_utc() {
+    assert_eq!(ui::detail::format_date(0), "1970-01-01 00:00 UTC");
+    assert_eq!(
+        ui::detail::format_date(1_760_000_000),
+        "2025-10-09 08:53 UTC"
+    );
+    assert_eq!(ui::detail::format_date(951_782_400), "2000-02-29 00:00 UTC");
+}
+
+#[test]
+fn the_slowest_removal_is_flagged_on_the_summary() {
+    use std::time::Duration;
+    assert_eq!(ui::format_duration(Duration::from_millis(850)), "850 ms");
+    assert_eq!(ui::format_duration(Duration::from_millis(4240)), "4.2 s");
+    assert_eq!(ui::format_duration(Duration::from_secs(125)), "2 min 05 s");
+
+    let mut app = app_on(Screen::Done);
+    app.delete_slowest = Some((
+        "/tmp/projects/a/node_modules".to_string(),
+        Duration::from_secs(31),
+    ));
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("Slowest  31.0 s  /tmp/projects/a/node_modules"));
+    assert!(text.contains("a network mount or antivirus?"));
+}
+
+#[test]
+fn the_summary_says_how_long_the_scan_took() {
+    use std::time::{Duration, Instant};
+    let mut app = app_on(Screen::Done);
+    let now = Instant::now();
+    app.scan_started_at = Some(now);
+    app.scan_finished_at = Some(now + Duration::from_millis(2_500));
+    app.scan_found = 10;
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+
+    assert!(text.contains("Scanned  2.5 s  (10 found, 4.0 per second)"));
+}
+
+#[test]
+fn free_space_is_shown_before_and_after() {
+    use killnode::diskspace::DiskSpace;
+    let space = DiskSpace {
+        available: 48_400_000_000,
+        total: 512_000_000_000,
+        filesystems: 2,
+    };
+    let text = |app: &mut App| {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal.draw(|f| ui::ui(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>()
+    };
+
+    let mut app = app_on(Screen::Welcome);
+    app.disk_space = Some(space);
+    let welcome = text(&mut app);
+    assert!(welcome.contains("Free space  48.40 GB of 512 GB  (across 2 filesystems)"));
+
+    let mut app = app_on(Screen::Done);
+    app.disk_space = Some(space);
+    app.disk_free_before = Some(46_100_000_000);
+    assert!(text(&mut app).contains("Free space  46.10 GB → 48.40 GB"));
+}
+
+#[test]
+fn help_overlay_lists_every_key_of_the_current_screen() {
+    for screen in screens() {
+        let mut app = app_on(screen);
+        app.show_help = true;
+        let hints = ui::help::screen_hints(&app);
+        assert!(!hints.is_empty());
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        for (_, desc) in &hints {
+            assert!(text.contains(desc.as_str()), "{desc:?} missing");
+        }
+        for (width, height) in [(1, 1), (29, 7)] {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        }
+    }
+
+    let app = app_on(Screen::List);
+    let keys: Vec<String> = ui::help::screen_hints(&app)
+        .into_iter()
+        .map(|(k, _)| k)
+        .collect();
+    assert!(keys.contains(&"Home / End".to_string()));
+}
+
+#[test]
+fn counts_are_grouped_and_nouns_agree() {
+    assert_eq!(ui::group_digits(0, ','), "0");
+    assert_eq!(ui::group_digits(999, ','), "999");
+    assert_eq!(ui::group_digits(12_403, ','), "12,403");
+    assert_eq!(ui::group_digits(1_234_567, '.'), "1.234.567");
+    assert!(ui::count_noun(1, "directory", "directories").ends_with(" directory"));
+    assert!(ui::count_noun(2, "directory", "directories").ends_with(" directories"));
+}
+
+#[test]
+fn sizes_are_formatted_in_decimal_units() {
+    assert_eq!(ui::format_bytes(0), "0 B");
+    assert_eq!(ui::format_bytes(48_213), "48.21 kB");
+    assert_eq!(ui::format_bytes(3_200_000_000), "3.20 GB");
+}
+
+#[test]
+fn an_overflowing_list_says_how_much_is_out_of_view() {
+    let mut app = app_on(Screen::List);
+    app.status = None;
+    app.entries = (0..40)
+        .map(|i| NodeModuleEntry::new(format!("/p/{i}/node_modules"), 1, None, None, false))
+        .collect();
+    app.list_state.select(Some(20));
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let text: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect();
+
+    // The exact split depends on where ratatui scrolls to; the total doesn't.
+    let hidden = |marker: &str| -> usize {
+        text.split(marker)
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .map_or(0, |n| n.parse().unwrap())
+    };
+    let visible = 24 - 2 - 1 - 1 - 3;
+    assert!(hidden("▲ ") > 0);
+    assert_eq!(hidden("▲ ") + hidden("▼ ") + visible, 40);
+}
+
+#[test]
+fn a_scrollbar_appears_only_when_the_list_overflows() {
+    let mut app = app_on(Screen::List);
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    let right_edge = |terminal: &Terminal<TestBackend>| -> String {
+        let buffer = terminal.backend().buffer();
+        (0..24).map(|y| buffer[(98, y)].symbol()).collect()
+    };
+
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    assert!(!right_edge(&terminal).contains('█'));
+
+    app.entries = (0..40)
+        .map(|i| NodeModuleEntry::new(format!("/p/{i}/node_modules"), 1, None, None, false))
+        .collect();
+    app.list_state.select(Some(39));
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let edge = right_edge(&terminal);
+    assert!(edge.contains('█'));
+    assert!(edge.contains('║'));
+}
+
+#[test]
+fn a_marked_range_is_drawn_down_the_gutter() {
+    let mut app = app_on(Screen::List);
+    app.entries = (0..5)
+        .map(|i| NodeModuleEntry::new(format!("/p/{i}/node_modules"), 1, None, None, false))
+        .collect();
+    app.list_state.select(Some(1));
+    app.mark_range();
+    app.list_state.select(Some(3));
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let gutter: String = (0..24)
+        .map(|y| buffer[(1, y)].symbol())
+        .filter(|s| *s == "▸" || *s == "┃")
+        .collect();
+
+    assert_eq!(gutter, "▸┃┃");
+}
+
+#[test]
+fn a_grouped_list_draws_project_headers_with_totals() {
+    let mut app = app_on(Screen::List);
+    app.status = None;
+    app.entries = (0..3)
+        .map(|i| {
+            NodeModuleEntry::new(
+                format!("/r/p{i}/node_modules"),
+                1_000_000,
+                None,
+                None,
+                false,
+            )
+        })
+        .collect();
+    for entry in &app.entries {
+        app.projects.insert(entry.path.clone(), "/r".to_string());
+    }
+    app.toggle_grouping();
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let rows: Vec<String> = (0..24)
+        .map(|y| (0..100).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect();
+
+    let header = rows.iter().find(|r| r.contains("▾ /r")).unwrap();
+    assert!(header.contains("(3)") && header.contains("3 MB"));
+    assert!(rows.iter().any(|r| r.contains("  p1/node_modules")));
+}
+
+#[test]
+fn the_window_title_follows_the_screen() {
+    let mut app = app_on(Screen::Welcome);
+    assert_eq!(ui::window_title(&app), "killnode");
+    app.screen = Screen::List;
+    assert_eq!(ui::window_title(&app), "killnode — 2 found");
+    app.screen = Screen::Deleting;
+    assert_eq!(ui::window_title(&app), "killnode — deleting 0 / 2");
+}
+
+#[test]
+fn the_outer_border_colour_follows_the_screen() {
+    let theme = ui::Theme::DEFAULT;
+    for (i, (screen, expected)) in [
+        (Screen::Welcome, theme.text),
+        (Screen::Scanning, theme.warning),
+        (Screen::List, theme.text),
+        (Screen::Deleting, theme.danger),
+        (Screen::Done, theme.success),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut app = app_on(screen);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+        let corner = &terminal.backend().buffer()[(0, 0)];
+        assert_eq!(corner.symbol(), "┌");
+        assert_eq!(corner.fg, expected, "screen #{i}");
+    }
+}
+
+#[test]
+fn a_filtered_list_draws_only_matching_rows() {
+    let mut app = app_on(Screen::List);
+    app.list_filter = "config".to_string();
+    app.list_state.select(Some(0));
+    let mut terminal = Terminal::new(TestBackend::new(160, 24)).unwrap();
+    terminal.draw(|f| ui::ui(f, &mut app)).unwrap();
+    let screen: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect();
+    assert!(screen.contains("(1 shown)"));
+    assert!(!screen.contains(&app.entries[0].path));
+}